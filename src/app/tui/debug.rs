@@ -19,15 +19,17 @@
 use std::{
     sync::mpsc::{self, Receiver},
     thread,
-    time::{Duration, SystemTime, UNIX_EPOCH},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
 use crate::{
-    rows::{seed_logs_from_previous, sort_rows, status_dot},
+    cli::Config,
+    rows::{seed_from_previous, sort_rows, status_dot},
     systemd::{action_for_start_stop_states, action_for_unit_file_state},
+    theme::Theme,
     types::{
-        ActionResolutionRequest, ConfirmationState, DetailLogEntry, SortMode, UnitAction, UnitRow,
-        WorkerMsg,
+        ActionResolutionRequest, Boot, ConfirmationState, DetailLogEntry, JournalEntry, LogPreview,
+        Scope, UnitAction, UnitRow, WorkerMsg,
     },
 };
 
@@ -208,7 +210,7 @@ fn shuffle<T>(items: &mut [T], state: &mut u64) {
     }
 }
 
-fn build_debug_rows() -> Vec<UnitRow> {
+fn build_debug_rows(theme: &Theme) -> Vec<UnitRow> {
     let mut state = time_seed().max(1);
     let mut templates = DEBUG_UNIT_TEMPLATES;
     shuffle(&mut templates, &mut state);
@@ -217,32 +219,43 @@ fn build_debug_rows() -> Vec<UnitRow> {
         .into_iter()
         .take(MAX_DEBUG_UNITS)
         .map(|template| {
-            let (dot, dot_style) = status_dot(template.active, template.sub);
+            let (dot, dot_style) = status_dot(theme, template.active, template.sub);
             let variant = (next_random(&mut state) % 900) + 100;
             UnitRow {
                 dot,
                 dot_style,
                 unit: debug_unit_name(template),
+                scope: Scope::System,
                 load: template.load.to_string(),
                 active: template.active.to_string(),
                 sub: template.sub.to_string(),
                 description: format!("{} [{variant}]", template.description),
                 last_log: String::new(),
+                last_log_usec: None,
+                changed_at: None,
+                changed_since_launch: false,
+                marked: false,
+                props: Default::default(),
             }
         })
         .collect()
 }
 
-fn debug_preview(row: &UnitRow, ordinal: usize) -> String {
+fn debug_preview(row: &UnitRow, ordinal: usize) -> LogPreview {
     let template = template_for_unit(&row.unit).expect("debug unit should map to a template");
-    format!(
-        "#{:02} {} | {} / {} / {}",
-        ordinal + 1,
-        template.preview,
-        row.load,
-        row.active,
-        row.sub
-    )
+    // Spread fake log times over the last ten minutes so log-time sorting has an effect.
+    let age_usec = (ordinal as u64 * 37 % 600) * 1_000_000;
+    LogPreview {
+        message: format!(
+            "#{:02} {} | {} / {} / {}",
+            ordinal + 1,
+            template.preview,
+            row.load,
+            row.active,
+            row.sub
+        ),
+        usec: Some((time_seed() / 1000).saturating_sub(age_usec)),
+    }
 }
 
 fn template_for_unit(unit: &str) -> Option<DebugUnitTemplate> {
@@ -274,6 +287,13 @@ fn build_detail_logs(unit: &str) -> Vec<DetailLogEntry> {
                     template.active,
                     template.sub
                 ),
+                // The first fake run belongs to the previous fake boot.
+                boot: Some(debug_boot_id(if idx < 4 { -1 } else { 0 })),
+                // Four entries per fake run; every run but the first ends in a crash.
+                invocation: Some(format!("debug-run-{}", idx / 4)),
+                exit: (idx % 4 == 3 && idx > 3).then(|| "result=exit-code".to_string()),
+                unit: None,
+                cursor: None,
             }
         })
         .collect()
@@ -293,7 +313,8 @@ fn resolve_debug_action_confirmation(
                 UnitAction::Restart
                 | UnitAction::Enable
                 | UnitAction::Disable
-                | UnitAction::DisableRuntime => unreachable!(),
+                | UnitAction::DisableRuntime
+                | UnitAction::Preset => unreachable!(),
             })
         }
         ActionResolutionRequest::EnableDisable { unit } => {
@@ -304,6 +325,14 @@ fn resolve_debug_action_confirmation(
                 unit,
             ))
         }
+        ActionResolutionRequest::Preset { unit } => {
+            template_for_unit(&unit).ok_or_else(|| anyhow::anyhow!("unknown debug unit"))?;
+            Ok(ConfirmationState::confirm_action(UnitAction::Preset, unit))
+        }
+        ActionResolutionRequest::Named { action, unit } => {
+            template_for_unit(&unit).ok_or_else(|| anyhow::anyhow!("unknown debug unit"))?;
+            Ok(ConfirmationState::confirm_action(action, unit))
+        }
     }
 }
 
@@ -323,12 +352,18 @@ pub(super) fn run_debug_unit_action(
 }
 
 /// Spawn a background worker that emits fake rows and fake preview logs.
-pub(super) fn spawn_debug_refresh_worker(previous_rows: Vec<UnitRow>) -> Receiver<WorkerMsg> {
+pub(super) fn spawn_debug_refresh_worker(
+    previous_rows: Vec<UnitRow>,
+    same_query: bool,
+    config: &Config,
+) -> Receiver<WorkerMsg> {
     let (tx, rx) = mpsc::channel();
+    let theme = config.theme.clone();
+    let (sort_mode, sort_reverse) = (config.sort_mode, config.sort_reverse);
     thread::spawn(move || {
-        let mut rows = build_debug_rows();
-        seed_logs_from_previous(&mut rows, &previous_rows);
-        sort_rows(&mut rows, SortMode::Status);
+        let mut rows = build_debug_rows(&theme);
+        seed_from_previous(&mut rows, &previous_rows, same_query, Instant::now());
+        sort_rows(&mut rows, sort_mode, sort_reverse);
         let total = rows.len();
 
         if tx.send(WorkerMsg::UnitsLoaded(rows.clone())).is_err() {
@@ -342,7 +377,7 @@ pub(super) fn spawn_debug_refresh_worker(previous_rows: Vec<UnitRow>) -> Receive
                 .enumerate()
                 .map(|(offset, row)| {
                     (
-                        row.unit.clone(),
+                        row.key(),
                         debug_preview(row, batch_idx * LOG_BATCH_SIZE + offset),
                     )
                 })
@@ -373,6 +408,36 @@ pub(super) fn spawn_debug_detail_worker(unit: String, request_id: u64) -> Receiv
     rx
 }
 
+/// Spawn a debug worker that interleaves the fake logs of several units by time.
+pub(super) fn spawn_debug_timeline_worker(
+    units: Vec<String>,
+    request_id: u64,
+) -> Receiver<WorkerMsg> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let mut logs: Vec<DetailLogEntry> = units
+            .iter()
+            .flat_map(|unit| {
+                build_detail_logs(unit)
+                    .into_iter()
+                    .map(|entry| DetailLogEntry {
+                        unit: Some(unit.clone()),
+                        invocation: None,
+                        exit: None,
+                        ..entry
+                    })
+            })
+            .collect();
+        logs.sort_by(|a, b| b.time.cmp(&a.time));
+        let _ = tx.send(WorkerMsg::DetailLogsLoaded {
+            unit: String::new(),
+            request_id,
+            logs,
+        });
+    });
+    rx
+}
+
 /// Spawn a debug worker that resolves a synthetic confirmation prompt.
 pub(super) fn spawn_debug_action_resolution_worker(
     request: ActionResolutionRequest,
@@ -395,10 +460,64 @@ pub(super) fn spawn_debug_action_resolution_worker(
     rx
 }
 
+/// Spawn a debug worker that returns fake journal fields for an inspected entry.
+pub(super) fn spawn_debug_entry_worker(entry: DetailLogEntry) -> Receiver<WorkerMsg> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let mut fields = vec![
+            ("MESSAGE".to_string(), entry.log),
+            ("PRIORITY".to_string(), "6".to_string()),
+            ("SYSLOG_IDENTIFIER".to_string(), "debug".to_string()),
+            ("_PID".to_string(), "4242".to_string()),
+        ];
+        fields.extend(entry.unit.map(|unit| ("_SYSTEMD_UNIT".to_string(), unit)));
+        fields.sort();
+        let _ = tx.send(WorkerMsg::EntryLoaded(JournalEntry {
+            fields,
+            catalog: None,
+        }));
+    });
+    rx
+}
+
+/// Spawn a debug worker that lists fake machines for the machine picker.
+pub(super) fn spawn_debug_machine_list_worker() -> Receiver<WorkerMsg> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = tx.send(WorkerMsg::MachinesLoaded(vec![
+            "debug-web".to_string(),
+            "debug-db".to_string(),
+        ]));
+    });
+    rx
+}
+
+fn debug_boot_id(index: i64) -> String {
+    format!("{:032x}", index.unsigned_abs() + 1)
+}
+
+/// Spawn a debug worker that lists fake boots for the boot picker.
+pub(super) fn spawn_debug_boot_list_worker() -> Receiver<WorkerMsg> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = tx.send(WorkerMsg::BootsLoaded(
+            [-1, 0]
+                .into_iter()
+                .map(|index: i64| Boot {
+                    index,
+                    id: debug_boot_id(index),
+                    span: format!("debug boot {index}"),
+                })
+                .collect(),
+        ));
+    });
+    rx
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::UnitAction;
+    use crate::types::{SortMode, UnitAction};
     use ratatui::prelude::{Color, Style};
     use std::time::Duration;
 
@@ -412,7 +531,7 @@ mod tests {
 
     #[test]
     fn build_debug_rows_stays_within_limit_and_covers_color_buckets() {
-        let rows = build_debug_rows();
+        let rows = build_debug_rows(&Theme::dark());
         assert!(!rows.is_empty());
         assert!(rows.len() <= MAX_DEBUG_UNITS);
         assert!(
@@ -439,7 +558,7 @@ mod tests {
 
     #[test]
     fn build_debug_rows_uses_distinct_unit_names() {
-        let rows = build_debug_rows();
+        let rows = build_debug_rows(&Theme::dark());
         let unique_units: std::collections::HashSet<String> =
             rows.iter().map(|row| row.unit.clone()).collect();
         assert_eq!(unique_units.len(), rows.len());
@@ -447,8 +566,8 @@ mod tests {
 
     #[test]
     fn debug_rows_use_normal_all_mode_sorting_after_generation() {
-        let mut rows = build_debug_rows();
-        sort_rows(&mut rows, SortMode::Status);
+        let mut rows = build_debug_rows(&Theme::dark());
+        sort_rows(&mut rows, SortMode::Status, false);
 
         for pair in rows.windows(2) {
             let left = &pair[0];
@@ -471,7 +590,8 @@ mod tests {
 
     #[test]
     fn spawn_debug_refresh_worker_emits_units_progress_and_finished() {
-        let rx = spawn_debug_refresh_worker(Vec::new());
+        let config = crate::cli::parse_args(["lsu", "--debug-tui"]).expect("debug config");
+        let rx = spawn_debug_refresh_worker(Vec::new(), true, &config);
         let total = match rx
             .recv_timeout(Duration::from_millis(500))
             .expect("units message")
@@ -507,6 +627,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn spawn_debug_timeline_worker_merges_units_newest_first() {
+        let units = vec![
+            "debug-api-gateway.service".to_string(),
+            "debug-worker.service".to_string(),
+        ];
+        let rx = spawn_debug_timeline_worker(units.clone(), 3);
+        match rx
+            .recv_timeout(Duration::from_millis(500))
+            .expect("timeline msg")
+        {
+            WorkerMsg::DetailLogsLoaded {
+                unit,
+                request_id,
+                logs,
+            } => {
+                assert!(unit.is_empty());
+                assert_eq!(request_id, 3);
+                assert_eq!(logs.len(), 24);
+                assert!(logs.windows(2).all(|pair| pair[0].time >= pair[1].time));
+                assert!(logs.iter().any(|log| log.unit == Some(units[1].clone())));
+            }
+            other => panic!("expected DetailLogsLoaded, got {other:?}"),
+        }
+    }
+
     #[test]
     fn spawn_debug_detail_worker_emits_fake_logs() {
         let rx = spawn_debug_detail_worker("debug-api-gateway.service".to_string(), 4);
@@ -528,6 +674,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn spawn_debug_entry_worker_emits_fake_fields() {
+        let entry = DetailLogEntry {
+            time: "2026-02-24T10:00:00+0000".to_string(),
+            log: "load=0.42".to_string(),
+            unit: Some("debug-api-gateway.service".to_string()),
+            ..DetailLogEntry::default()
+        };
+        let rx = spawn_debug_entry_worker(entry);
+        match rx
+            .recv_timeout(Duration::from_millis(500))
+            .expect("entry message")
+        {
+            WorkerMsg::EntryLoaded(found) => {
+                assert!(
+                    found
+                        .fields
+                        .contains(&("MESSAGE".to_string(), "load=0.42".to_string()))
+                );
+                assert!(found.fields.is_sorted());
+            }
+            other => panic!("expected EntryLoaded, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn build_detail_logs_splits_entries_into_runs() {
+        let logs = build_detail_logs("debug-api-gateway.service");
+        let runs = crate::types::invocation_sections(&logs);
+        assert_eq!(runs.len(), 3);
+        assert_eq!(runs[0].result, None);
+        assert_eq!(runs[2].result.as_deref(), Some("result=exit-code"));
+    }
+
     #[test]
     fn build_detail_logs_emits_valid_times() {
         let logs = build_detail_logs("debug-api-gateway.service");
@@ -547,6 +727,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn spawn_debug_boot_list_worker_emits_fake_boots() {
+        let rx = spawn_debug_boot_list_worker();
+        match rx
+            .recv_timeout(Duration::from_millis(500))
+            .expect("boots msg")
+        {
+            WorkerMsg::BootsLoaded(boots) => assert_eq!(boots.len(), 2),
+            other => panic!("expected BootsLoaded, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn spawn_debug_machine_list_worker_emits_fake_machines() {
+        let rx = spawn_debug_machine_list_worker();
+        match rx
+            .recv_timeout(Duration::from_millis(500))
+            .expect("machines msg")
+        {
+            WorkerMsg::MachinesLoaded(machines) => assert_eq!(machines.len(), 2),
+            other => panic!("expected MachinesLoaded, got {other:?}"),
+        }
+    }
+
     #[test]
     fn spawn_debug_action_resolution_worker_uses_synthetic_state() {
         let rx = spawn_debug_action_resolution_worker(ActionResolutionRequest::StartStop {
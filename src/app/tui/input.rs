@@ -14,11 +14,19 @@
    limitations under the License.
 */
 
-//! Key-event translation for list and detail modes.
+//! Key- and mouse-event translation for list and detail modes.
+//!
+//! Every mode resolves keys through one [`KeyBindings`] table. The defaults can be
+//! remapped per command from the `[keys]` table of the config file; bindings may be
+//! multi-key sequences such as `g g`. Mouse events are mapped against the list
+//! areas of the last drawn frame.
 
-use crossterm::event::KeyCode;
+use anyhow::{Result, anyhow, bail};
+use crossterm::event::{KeyCode, MouseButton, MouseEvent, MouseEventKind};
+use std::time::{Duration, Instant};
 
-use crate::types::{ConfirmationKind, ViewMode};
+use super::render::ListHitAreas;
+use crate::types::{Column, ConfirmationKind, ViewMode};
 
 /// High-level UI command mapped from a key press.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -27,56 +35,953 @@ pub enum UiCommand {
     Refresh,
     MoveDown,
     MoveUp,
+    PageDown,
+    PageUp,
+    HalfPageDown,
+    HalfPageUp,
+    JumpTop,
+    JumpBottom,
     OpenDetail,
     BackToList,
     RefreshDetail,
     RequestStartStop,
     RequestEnableDisable,
+    RequestPreset,
+    CycleSort,
+    ReverseSort,
+    ToggleAutoRefresh,
+    ToggleHelp,
     Confirm,
     Cancel,
     ChooseRestart,
     ChooseStop,
+    OpenMachinePicker,
+    OpenPalette,
+    CycleSplit,
+    ToggleWrap,
+    ScrollLeft,
+    ScrollRight,
+    OpenSearch,
+    NextMatch,
+    PreviousMatch,
+    InspectEntry,
+    NextInvocation,
+    PreviousInvocation,
+    OpenInvocationPicker,
+    OpenBootPicker,
+    ToggleMark,
+    OpenTimeline,
 }
 
-/// Translate a key in the current view mode to a UI command.
-pub fn map_key(view_mode: ViewMode, key: KeyCode) -> Option<UiCommand> {
-    match view_mode {
-        ViewMode::List => match key {
-            KeyCode::Char('q') => Some(UiCommand::Quit),
-            KeyCode::Char('r') => Some(UiCommand::Refresh),
-            KeyCode::Down => Some(UiCommand::MoveDown),
-            KeyCode::Up => Some(UiCommand::MoveUp),
-            KeyCode::Char('l') | KeyCode::Enter => Some(UiCommand::OpenDetail),
-            KeyCode::Char('s') => Some(UiCommand::RequestStartStop),
-            KeyCode::Char('e') => Some(UiCommand::RequestEnableDisable),
-            _ => None,
-        },
-        ViewMode::Detail => match key {
-            KeyCode::Char('q') => Some(UiCommand::Quit),
-            KeyCode::Char('r') => Some(UiCommand::Refresh),
-            KeyCode::Down => Some(UiCommand::MoveDown),
-            KeyCode::Up => Some(UiCommand::MoveUp),
-            KeyCode::Esc | KeyCode::Char('b') => Some(UiCommand::BackToList),
-            KeyCode::Char('l') => Some(UiCommand::RefreshDetail),
-            _ => None,
-        },
+impl UiCommand {
+    const ALL: [UiCommand; 40] = [
+        Self::Quit,
+        Self::Refresh,
+        Self::MoveDown,
+        Self::MoveUp,
+        Self::PageDown,
+        Self::PageUp,
+        Self::HalfPageDown,
+        Self::HalfPageUp,
+        Self::JumpTop,
+        Self::JumpBottom,
+        Self::OpenDetail,
+        Self::BackToList,
+        Self::RefreshDetail,
+        Self::RequestStartStop,
+        Self::RequestEnableDisable,
+        Self::RequestPreset,
+        Self::CycleSort,
+        Self::ReverseSort,
+        Self::ToggleAutoRefresh,
+        Self::ToggleHelp,
+        Self::Confirm,
+        Self::Cancel,
+        Self::ChooseRestart,
+        Self::ChooseStop,
+        Self::OpenMachinePicker,
+        Self::OpenPalette,
+        Self::CycleSplit,
+        Self::ToggleWrap,
+        Self::ScrollLeft,
+        Self::ScrollRight,
+        Self::OpenSearch,
+        Self::NextMatch,
+        Self::PreviousMatch,
+        Self::InspectEntry,
+        Self::NextInvocation,
+        Self::PreviousInvocation,
+        Self::OpenInvocationPicker,
+        Self::OpenBootPicker,
+        Self::ToggleMark,
+        Self::OpenTimeline,
+    ];
+
+    /// Name of the command in the `[keys]` table of the config file.
+    pub fn config_name(self) -> &'static str {
+        match self {
+            Self::Quit => "quit",
+            Self::Refresh => "refresh",
+            Self::MoveDown => "move_down",
+            Self::MoveUp => "move_up",
+            Self::PageDown => "page_down",
+            Self::PageUp => "page_up",
+            Self::HalfPageDown => "half_page_down",
+            Self::HalfPageUp => "half_page_up",
+            Self::JumpTop => "top",
+            Self::JumpBottom => "bottom",
+            Self::OpenDetail => "open_detail",
+            Self::BackToList => "back_to_list",
+            Self::RefreshDetail => "refresh_detail",
+            Self::RequestStartStop => "start_stop",
+            Self::RequestEnableDisable => "enable_disable",
+            Self::RequestPreset => "preset",
+            Self::CycleSort => "sort",
+            Self::ReverseSort => "reverse_sort",
+            Self::ToggleAutoRefresh => "auto_refresh",
+            Self::ToggleHelp => "help",
+            Self::Confirm => "confirm",
+            Self::Cancel => "cancel",
+            Self::ChooseRestart => "restart",
+            Self::ChooseStop => "stop",
+            Self::OpenMachinePicker => "machine_picker",
+            Self::OpenPalette => "command_palette",
+            Self::CycleSplit => "split",
+            Self::ToggleWrap => "wrap",
+            Self::ScrollLeft => "scroll_left",
+            Self::ScrollRight => "scroll_right",
+            Self::OpenSearch => "search",
+            Self::NextMatch => "next_match",
+            Self::PreviousMatch => "previous_match",
+            Self::InspectEntry => "inspect_entry",
+            Self::NextInvocation => "next_invocation",
+            Self::PreviousInvocation => "previous_invocation",
+            Self::OpenInvocationPicker => "invocation_picker",
+            Self::OpenBootPicker => "boot_picker",
+            Self::ToggleMark => "toggle_mark",
+            Self::OpenTimeline => "timeline",
+        }
+    }
+
+    /// Short description shown in the help overlay.
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Quit => "quit",
+            Self::Refresh => "refresh now",
+            Self::MoveDown => "move down",
+            Self::MoveUp => "move up",
+            Self::PageDown => "page down",
+            Self::PageUp => "page up",
+            Self::HalfPageDown => "half a page down",
+            Self::HalfPageUp => "half a page up",
+            Self::JumpTop => "jump to the top",
+            Self::JumpBottom => "jump to the bottom",
+            Self::OpenDetail => "open logs of the selected unit",
+            Self::BackToList => "back to the unit list",
+            Self::RefreshDetail => "reload logs",
+            Self::RequestStartStop => "start, restart, or stop the unit",
+            Self::RequestEnableDisable => "enable or disable the unit",
+            Self::RequestPreset => "reset enablement to the vendor preset",
+            Self::CycleSort => "sort by the next column",
+            Self::ReverseSort => "reverse the sort order",
+            Self::ToggleAutoRefresh => "switch auto refresh on or off",
+            Self::ToggleHelp => "show this help",
+            Self::Confirm => "confirm",
+            Self::Cancel => "cancel",
+            Self::ChooseRestart => "restart the unit",
+            Self::ChooseStop => "stop the unit",
+            Self::OpenMachinePicker => "pick a container or VM",
+            Self::OpenPalette => "type a command (filter, sort, goto, ...)",
+            Self::CycleSplit => "show logs below, beside, or apart from the list",
+            Self::ToggleWrap => "wrap long log messages",
+            Self::ScrollLeft => "scroll log messages left",
+            Self::ScrollRight => "scroll log messages right",
+            Self::OpenSearch => "search the loaded logs",
+            Self::NextMatch => "jump to the next match",
+            Self::PreviousMatch => "jump to the previous match",
+            Self::InspectEntry => "show every journal field of the highlighted entry",
+            Self::NextInvocation => "jump to the next (older) run of the unit",
+            Self::PreviousInvocation => "jump to the previous (newer) run of the unit",
+            Self::OpenInvocationPicker => "pick a run of the unit by start time",
+            Self::OpenBootPicker => "pick the boot whose logs are shown",
+            Self::ToggleMark => "mark the unit for a merged timeline",
+            Self::OpenTimeline => "merge logs of marked (or all listed) units",
+        }
+    }
+
+    fn from_config_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|cmd| cmd.config_name() == name)
+    }
+}
+
+/// Input mode a key binding applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyContext {
+    /// Unit list.
+    List,
+    /// Detail log view.
+    Detail,
+    /// Yes/no prompt before an action runs.
+    ConfirmAction,
+    /// Restart-or-stop prompt for running units.
+    RestartOrStop,
+    /// Machine picker overlay.
+    MachinePicker,
+    /// Journal entry inspector popup.
+    Inspector,
+    /// Invocation picker overlay.
+    InvocationPicker,
+    /// Boot picker overlay.
+    BootPicker,
+    /// Help overlay.
+    Help,
+}
+
+impl KeyContext {
+    /// Every context, in the order the help overlay lists them.
+    pub const ALL: [KeyContext; 9] = [
+        Self::List,
+        Self::Detail,
+        Self::ConfirmAction,
+        Self::RestartOrStop,
+        Self::MachinePicker,
+        Self::Inspector,
+        Self::InvocationPicker,
+        Self::BootPicker,
+        Self::Help,
+    ];
+
+    /// Name of the context as shown in messages and the help overlay.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::List => "list",
+            Self::Detail => "detail",
+            Self::ConfirmAction => "confirmation",
+            Self::RestartOrStop => "restart/stop prompt",
+            Self::MachinePicker => "machine picker",
+            Self::Inspector => "entry inspector",
+            Self::InvocationPicker => "invocation picker",
+            Self::BootPicker => "boot picker",
+            Self::Help => "help",
+        }
+    }
+}
+
+impl From<ViewMode> for KeyContext {
+    fn from(view_mode: ViewMode) -> Self {
+        match view_mode {
+            ViewMode::List => Self::List,
+            ViewMode::Detail => Self::Detail,
+        }
+    }
+}
+
+impl From<ConfirmationKind> for KeyContext {
+    fn from(kind: ConfirmationKind) -> Self {
+        match kind {
+            ConfirmationKind::ConfirmAction(_) => Self::ConfirmAction,
+            ConfirmationKind::RestartOrStop => Self::RestartOrStop,
+        }
+    }
+}
+
+const DEFAULT_BINDINGS: &[(KeyContext, UiCommand, &[KeyCode])] = &[
+    (KeyContext::List, UiCommand::Quit, &[KeyCode::Char('q')]),
+    (KeyContext::List, UiCommand::Refresh, &[KeyCode::Char('r')]),
+    (KeyContext::List, UiCommand::MoveDown, &[KeyCode::Down]),
+    (KeyContext::List, UiCommand::MoveUp, &[KeyCode::Up]),
+    (KeyContext::List, UiCommand::PageDown, &[KeyCode::PageDown]),
+    (KeyContext::List, UiCommand::PageUp, &[KeyCode::PageUp]),
+    (
+        KeyContext::List,
+        UiCommand::HalfPageDown,
+        &[KeyCode::Char('d')],
+    ),
+    (
+        KeyContext::List,
+        UiCommand::HalfPageUp,
+        &[KeyCode::Char('u')],
+    ),
+    (KeyContext::List, UiCommand::JumpTop, &[KeyCode::Home]),
+    (KeyContext::List, UiCommand::JumpTop, &[KeyCode::Char('g')]),
+    (KeyContext::List, UiCommand::JumpBottom, &[KeyCode::End]),
+    (
+        KeyContext::List,
+        UiCommand::JumpBottom,
+        &[KeyCode::Char('G')],
+    ),
+    (
+        KeyContext::List,
+        UiCommand::OpenDetail,
+        &[KeyCode::Char('l')],
+    ),
+    (KeyContext::List, UiCommand::OpenDetail, &[KeyCode::Enter]),
+    (
+        KeyContext::List,
+        UiCommand::RequestStartStop,
+        &[KeyCode::Char('s')],
+    ),
+    (
+        KeyContext::List,
+        UiCommand::RequestEnableDisable,
+        &[KeyCode::Char('e')],
+    ),
+    (
+        KeyContext::List,
+        UiCommand::RequestPreset,
+        &[KeyCode::Char('p')],
+    ),
+    (
+        KeyContext::List,
+        UiCommand::CycleSort,
+        &[KeyCode::Char('o')],
+    ),
+    (
+        KeyContext::List,
+        UiCommand::ReverseSort,
+        &[KeyCode::Char('O')],
+    ),
+    (
+        KeyContext::List,
+        UiCommand::ToggleAutoRefresh,
+        &[KeyCode::Char('a')],
+    ),
+    (
+        KeyContext::List,
+        UiCommand::OpenMachinePicker,
+        &[KeyCode::Char('m')],
+    ),
+    (
+        KeyContext::List,
+        UiCommand::OpenBootPicker,
+        &[KeyCode::Char('B')],
+    ),
+    (
+        KeyContext::List,
+        UiCommand::ToggleMark,
+        &[KeyCode::Char(' ')],
+    ),
+    (
+        KeyContext::List,
+        UiCommand::OpenTimeline,
+        &[KeyCode::Char('T')],
+    ),
+    (
+        KeyContext::List,
+        UiCommand::ToggleHelp,
+        &[KeyCode::Char('?')],
+    ),
+    (
+        KeyContext::List,
+        UiCommand::OpenPalette,
+        &[KeyCode::Char(':')],
+    ),
+    (
+        KeyContext::List,
+        UiCommand::CycleSplit,
+        &[KeyCode::Char('v')],
+    ),
+    (KeyContext::Detail, UiCommand::Quit, &[KeyCode::Char('q')]),
+    (
+        KeyContext::Detail,
+        UiCommand::Refresh,
+        &[KeyCode::Char('r')],
+    ),
+    (KeyContext::Detail, UiCommand::MoveDown, &[KeyCode::Down]),
+    (KeyContext::Detail, UiCommand::MoveUp, &[KeyCode::Up]),
+    (
+        KeyContext::Detail,
+        UiCommand::PageDown,
+        &[KeyCode::PageDown],
+    ),
+    (KeyContext::Detail, UiCommand::PageUp, &[KeyCode::PageUp]),
+    (
+        KeyContext::Detail,
+        UiCommand::HalfPageDown,
+        &[KeyCode::Char('d')],
+    ),
+    (
+        KeyContext::Detail,
+        UiCommand::HalfPageUp,
+        &[KeyCode::Char('u')],
+    ),
+    (KeyContext::Detail, UiCommand::JumpTop, &[KeyCode::Home]),
+    (
+        KeyContext::Detail,
+        UiCommand::JumpTop,
+        &[KeyCode::Char('g')],
+    ),
+    (KeyContext::Detail, UiCommand::JumpBottom, &[KeyCode::End]),
+    (
+        KeyContext::Detail,
+        UiCommand::JumpBottom,
+        &[KeyCode::Char('G')],
+    ),
+    (
+        KeyContext::Detail,
+        UiCommand::BackToList,
+        &[KeyCode::Char('b')],
+    ),
+    (KeyContext::Detail, UiCommand::BackToList, &[KeyCode::Esc]),
+    (
+        KeyContext::Detail,
+        UiCommand::RefreshDetail,
+        &[KeyCode::Char('l')],
+    ),
+    (
+        KeyContext::Detail,
+        UiCommand::ToggleAutoRefresh,
+        &[KeyCode::Char('a')],
+    ),
+    (
+        KeyContext::Detail,
+        UiCommand::ToggleHelp,
+        &[KeyCode::Char('?')],
+    ),
+    (
+        KeyContext::Detail,
+        UiCommand::OpenPalette,
+        &[KeyCode::Char(':')],
+    ),
+    (
+        KeyContext::Detail,
+        UiCommand::ToggleWrap,
+        &[KeyCode::Char('w')],
+    ),
+    (KeyContext::Detail, UiCommand::ScrollLeft, &[KeyCode::Left]),
+    (
+        KeyContext::Detail,
+        UiCommand::ScrollRight,
+        &[KeyCode::Right],
+    ),
+    (
+        KeyContext::Detail,
+        UiCommand::OpenSearch,
+        &[KeyCode::Char('/')],
+    ),
+    (
+        KeyContext::Detail,
+        UiCommand::NextMatch,
+        &[KeyCode::Char('n')],
+    ),
+    (
+        KeyContext::Detail,
+        UiCommand::PreviousMatch,
+        &[KeyCode::Char('N')],
+    ),
+    (
+        KeyContext::Detail,
+        UiCommand::InspectEntry,
+        &[KeyCode::Enter],
+    ),
+    (
+        KeyContext::Detail,
+        UiCommand::NextInvocation,
+        &[KeyCode::Char(']')],
+    ),
+    (
+        KeyContext::Detail,
+        UiCommand::PreviousInvocation,
+        &[KeyCode::Char('[')],
+    ),
+    (
+        KeyContext::Detail,
+        UiCommand::OpenInvocationPicker,
+        &[KeyCode::Char('i')],
+    ),
+    (
+        KeyContext::Detail,
+        UiCommand::OpenBootPicker,
+        &[KeyCode::Char('B')],
+    ),
+    (
+        KeyContext::ConfirmAction,
+        UiCommand::Confirm,
+        &[KeyCode::Char('y')],
+    ),
+    (
+        KeyContext::ConfirmAction,
+        UiCommand::Confirm,
+        &[KeyCode::Enter],
+    ),
+    (
+        KeyContext::ConfirmAction,
+        UiCommand::Cancel,
+        &[KeyCode::Char('n')],
+    ),
+    (
+        KeyContext::ConfirmAction,
+        UiCommand::Cancel,
+        &[KeyCode::Esc],
+    ),
+    (
+        KeyContext::RestartOrStop,
+        UiCommand::ChooseRestart,
+        &[KeyCode::Char('r')],
+    ),
+    (
+        KeyContext::RestartOrStop,
+        UiCommand::ChooseStop,
+        &[KeyCode::Char('s')],
+    ),
+    (
+        KeyContext::RestartOrStop,
+        UiCommand::Cancel,
+        &[KeyCode::Esc],
+    ),
+    (
+        KeyContext::MachinePicker,
+        UiCommand::MoveDown,
+        &[KeyCode::Down],
+    ),
+    (KeyContext::MachinePicker, UiCommand::MoveUp, &[KeyCode::Up]),
+    (
+        KeyContext::MachinePicker,
+        UiCommand::Confirm,
+        &[KeyCode::Enter],
+    ),
+    (
+        KeyContext::MachinePicker,
+        UiCommand::Cancel,
+        &[KeyCode::Esc],
+    ),
+    (
+        KeyContext::MachinePicker,
+        UiCommand::Cancel,
+        &[KeyCode::Char('m')],
+    ),
+    (KeyContext::Inspector, UiCommand::MoveDown, &[KeyCode::Down]),
+    (KeyContext::Inspector, UiCommand::MoveUp, &[KeyCode::Up]),
+    (KeyContext::Inspector, UiCommand::Cancel, &[KeyCode::Esc]),
+    (KeyContext::Inspector, UiCommand::Cancel, &[KeyCode::Enter]),
+    (
+        KeyContext::InvocationPicker,
+        UiCommand::MoveDown,
+        &[KeyCode::Down],
+    ),
+    (
+        KeyContext::InvocationPicker,
+        UiCommand::MoveUp,
+        &[KeyCode::Up],
+    ),
+    (
+        KeyContext::InvocationPicker,
+        UiCommand::Confirm,
+        &[KeyCode::Enter],
+    ),
+    (
+        KeyContext::InvocationPicker,
+        UiCommand::Cancel,
+        &[KeyCode::Esc],
+    ),
+    (
+        KeyContext::InvocationPicker,
+        UiCommand::Cancel,
+        &[KeyCode::Char('i')],
+    ),
+    (
+        KeyContext::BootPicker,
+        UiCommand::MoveDown,
+        &[KeyCode::Down],
+    ),
+    (KeyContext::BootPicker, UiCommand::MoveUp, &[KeyCode::Up]),
+    (
+        KeyContext::BootPicker,
+        UiCommand::Confirm,
+        &[KeyCode::Enter],
+    ),
+    (KeyContext::BootPicker, UiCommand::Cancel, &[KeyCode::Esc]),
+    (
+        KeyContext::BootPicker,
+        UiCommand::Cancel,
+        &[KeyCode::Char('B')],
+    ),
+    (KeyContext::Help, UiCommand::MoveDown, &[KeyCode::Down]),
+    (KeyContext::Help, UiCommand::MoveUp, &[KeyCode::Up]),
+    (KeyContext::Help, UiCommand::Cancel, &[KeyCode::Esc]),
+    (KeyContext::Help, UiCommand::Cancel, &[KeyCode::Char('?')]),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct KeyBinding {
+    context: KeyContext,
+    command: UiCommand,
+    keys: Vec<KeyCode>,
+}
+
+enum Lookup {
+    Command(UiCommand),
+    Prefix,
+    NoMatch,
+}
+
+/// Active key bindings for every input mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyBindings {
+    bindings: Vec<KeyBinding>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            bindings: DEFAULT_BINDINGS
+                .iter()
+                .map(|(context, command, keys)| KeyBinding {
+                    context: *context,
+                    command: *command,
+                    keys: keys.to_vec(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Build bindings from the defaults with per-command replacements.
+    ///
+    /// Each entry maps a command name (see [`UiCommand::config_name`]) to key
+    /// sequences that replace its default keys in every mode the command is
+    /// available in; an empty list unbinds the command. Conflicting bindings are
+    /// rejected.
+    pub fn with_overrides<'a, I>(overrides: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (&'a String, &'a Vec<String>)>,
+    {
+        let mut bindings = Self::default();
+        for (name, specs) in overrides {
+            let command = UiCommand::from_config_name(name)
+                .ok_or_else(|| anyhow!("unknown command in [keys]: {name}"))?;
+            let sequences = specs
+                .iter()
+                .map(|spec| parse_key_sequence(spec))
+                .collect::<Result<Vec<_>>>()?;
+            let mut contexts: Vec<KeyContext> = Vec::new();
+            for binding in &bindings.bindings {
+                if binding.command == command && !contexts.contains(&binding.context) {
+                    contexts.push(binding.context);
+                }
+            }
+            bindings
+                .bindings
+                .retain(|binding| binding.command != command);
+            for context in contexts {
+                for keys in &sequences {
+                    bindings.bindings.push(KeyBinding {
+                        context,
+                        command,
+                        keys: keys.clone(),
+                    });
+                }
+            }
+        }
+        bindings.validate()?;
+        Ok(bindings)
+    }
+
+    fn validate(&self) -> Result<()> {
+        for (idx, a) in self.bindings.iter().enumerate() {
+            for b in &self.bindings[idx + 1..] {
+                if a.context != b.context || (a.command == b.command && a.keys == b.keys) {
+                    continue;
+                }
+                if a.keys.starts_with(&b.keys) || b.keys.starts_with(&a.keys) {
+                    bail!(
+                        "key binding conflict in {} mode: {} ({}) and {} ({})",
+                        a.context.label(),
+                        key_sequence_label(&a.keys),
+                        a.command.config_name(),
+                        key_sequence_label(&b.keys),
+                        b.command.config_name()
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Return the commands bound in `context` with their key sequences, in binding order.
+    pub fn commands_in(&self, context: KeyContext) -> Vec<(UiCommand, Vec<&[KeyCode]>)> {
+        let mut commands: Vec<(UiCommand, Vec<&[KeyCode]>)> = Vec::new();
+        for binding in self.bindings.iter().filter(|b| b.context == context) {
+            match commands.iter_mut().find(|(cmd, _)| *cmd == binding.command) {
+                Some((_, keys)) => keys.push(&binding.keys),
+                None => commands.push((binding.command, vec![binding.keys.as_slice()])),
+            }
+        }
+        commands
+    }
+
+    /// Return the key sequences bound to `command` in `context`, in binding order.
+    pub fn keys_for(&self, context: KeyContext, command: UiCommand) -> Vec<&[KeyCode]> {
+        self.bindings
+            .iter()
+            .filter(|binding| binding.context == context && binding.command == command)
+            .map(|binding| binding.keys.as_slice())
+            .collect()
+    }
+
+    fn lookup(&self, context: KeyContext, keys: &[KeyCode]) -> Lookup {
+        let mut prefix = false;
+        for binding in self.bindings.iter().filter(|b| b.context == context) {
+            if binding.keys == keys {
+                return Lookup::Command(binding.command);
+            }
+            prefix |= binding.keys.starts_with(keys);
+        }
+        if prefix {
+            Lookup::Prefix
+        } else {
+            Lookup::NoMatch
+        }
+    }
+
+    /// Feed one key press and return the command once a full sequence matched.
+    pub fn map(
+        &self,
+        context: KeyContext,
+        pending: &mut PendingKeys,
+        key: KeyCode,
+    ) -> Option<UiCommand> {
+        if pending.context != Some(context) {
+            pending.context = Some(context);
+            pending.keys.clear();
+        }
+        pending.keys.push(key);
+        match self.lookup(context, &pending.keys) {
+            Lookup::Command(command) => {
+                pending.keys.clear();
+                Some(command)
+            }
+            Lookup::Prefix => None,
+            Lookup::NoMatch => {
+                // An abandoned sequence should not swallow the key that broke it.
+                let retry = pending.keys.len() > 1;
+                pending.keys.clear();
+                if retry {
+                    self.map(context, pending, key)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+/// Keys typed so far towards a multi-key binding.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PendingKeys {
+    context: Option<KeyContext>,
+    keys: Vec<KeyCode>,
+}
+
+/// Parse a binding such as `j`, `enter`, or `g g` (keys separated by spaces).
+pub fn parse_key_sequence(spec: &str) -> Result<Vec<KeyCode>> {
+    let keys = spec
+        .split_whitespace()
+        .map(parse_key)
+        .collect::<Result<Vec<_>>>()?;
+    if keys.is_empty() {
+        bail!("empty key binding");
+    }
+    Ok(keys)
+}
+
+fn parse_key(token: &str) -> Result<KeyCode> {
+    let key = match token {
+        "enter" => KeyCode::Enter,
+        "esc" => KeyCode::Esc,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "tab" => KeyCode::Tab,
+        "backspace" => KeyCode::Backspace,
+        "delete" => KeyCode::Delete,
+        "space" => KeyCode::Char(' '),
+        _ => {
+            if let Some(n) = token
+                .strip_prefix('f')
+                .and_then(|n| n.parse::<u8>().ok())
+                .filter(|n| (1..=12).contains(n))
+            {
+                return Ok(KeyCode::F(n));
+            }
+            let mut chars = token.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => KeyCode::Char(c),
+                _ => bail!("unknown key in binding: {token}"),
+            }
+        }
+    };
+    Ok(key)
+}
+
+/// Short label for one key, as shown in footer hints.
+pub fn key_label(key: KeyCode) -> String {
+    match key {
+        KeyCode::Up => "↑".to_string(),
+        KeyCode::Down => "↓".to_string(),
+        KeyCode::Left => "←".to_string(),
+        KeyCode::Right => "→".to_string(),
+        KeyCode::Enter => "enter".to_string(),
+        KeyCode::Esc => "esc".to_string(),
+        KeyCode::PageUp => "pgup".to_string(),
+        KeyCode::PageDown => "pgdn".to_string(),
+        KeyCode::Home => "home".to_string(),
+        KeyCode::End => "end".to_string(),
+        KeyCode::Tab => "tab".to_string(),
+        KeyCode::Backspace => "backspace".to_string(),
+        KeyCode::Delete => "del".to_string(),
+        KeyCode::Char(' ') => "space".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::F(n) => format!("f{n}"),
+        other => format!("{other:?}").to_lowercase(),
+    }
+}
+
+/// Label for a key sequence; plain character sequences are written together (`gg`).
+pub fn key_sequence_label(keys: &[KeyCode]) -> String {
+    let labels: Vec<String> = keys.iter().map(|key| key_label(*key)).collect();
+    if keys
+        .iter()
+        .all(|key| matches!(key, KeyCode::Char(c) if *c != ' '))
+    {
+        labels.concat()
+    } else {
+        labels.join(" ")
     }
 }
 
+/// Translate a key in the current view mode to a UI command.
+pub fn map_key(
+    bindings: &KeyBindings,
+    pending: &mut PendingKeys,
+    view_mode: ViewMode,
+    key: KeyCode,
+) -> Option<UiCommand> {
+    bindings.map(view_mode.into(), pending, key)
+}
+
 /// Translate a key while a confirmation prompt is active.
-pub fn map_confirmation_key(kind: ConfirmationKind, key: KeyCode) -> Option<UiCommand> {
-    match kind {
-        ConfirmationKind::ConfirmAction(_) => match key {
-            KeyCode::Char('y') | KeyCode::Enter => Some(UiCommand::Confirm),
-            KeyCode::Char('n') | KeyCode::Esc => Some(UiCommand::Cancel),
-            _ => None,
-        },
-        ConfirmationKind::RestartOrStop => match key {
-            KeyCode::Char('r') => Some(UiCommand::ChooseRestart),
-            KeyCode::Char('s') => Some(UiCommand::ChooseStop),
-            KeyCode::Esc => Some(UiCommand::Cancel),
-            _ => None,
-        },
+pub fn map_confirmation_key(
+    bindings: &KeyBindings,
+    pending: &mut PendingKeys,
+    kind: ConfirmationKind,
+    key: KeyCode,
+) -> Option<UiCommand> {
+    bindings.map(kind.into(), pending, key)
+}
+
+/// Translate a key while the help overlay is open.
+pub fn map_help_key(
+    bindings: &KeyBindings,
+    pending: &mut PendingKeys,
+    key: KeyCode,
+) -> Option<UiCommand> {
+    bindings.map(KeyContext::Help, pending, key)
+}
+
+/// Translate a key while the machine picker is open.
+pub fn map_machine_picker_key(
+    bindings: &KeyBindings,
+    pending: &mut PendingKeys,
+    key: KeyCode,
+) -> Option<UiCommand> {
+    bindings.map(KeyContext::MachinePicker, pending, key)
+}
+
+/// Translate a key while the journal entry inspector is open.
+pub fn map_inspector_key(
+    bindings: &KeyBindings,
+    pending: &mut PendingKeys,
+    key: KeyCode,
+) -> Option<UiCommand> {
+    bindings.map(KeyContext::Inspector, pending, key)
+}
+
+/// Translate a key while the invocation picker is open.
+pub fn map_invocation_picker_key(
+    bindings: &KeyBindings,
+    pending: &mut PendingKeys,
+    key: KeyCode,
+) -> Option<UiCommand> {
+    bindings.map(KeyContext::InvocationPicker, pending, key)
+}
+
+/// Translate a key while the boot picker is open.
+pub fn map_boot_picker_key(
+    bindings: &KeyBindings,
+    pending: &mut PendingKeys,
+    key: KeyCode,
+) -> Option<UiCommand> {
+    bindings.map(KeyContext::BootPicker, pending, key)
+}
+
+/// Longest gap between two clicks on the same row that opens its detail view.
+pub const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+/// UI action mapped from a mouse event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MouseCommand {
+    /// Select the list row at this index.
+    Select(usize),
+    /// Select the list row at this index and open its detail view.
+    Open(usize),
+    /// Sort the list by this column, or reverse it when already sorted by it.
+    SortBy(Column),
+    /// Move the list selection or detail logs down by one.
+    ScrollDown,
+    /// Move the list selection or detail logs up by one.
+    ScrollUp,
+}
+
+/// Previous left click, used to detect double-clicks.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ClickTracker {
+    last: Option<(Instant, usize)>,
+}
+
+/// Translate a mouse event to a UI action.
+///
+/// `offset` is the index of the first visible list row and `rows_len` the number
+/// of rows; clicks below the last row are ignored.
+pub fn map_mouse(
+    event: MouseEvent,
+    view_mode: ViewMode,
+    areas: &ListHitAreas,
+    offset: usize,
+    rows_len: usize,
+    clicks: &mut ClickTracker,
+    now: Instant,
+) -> Option<MouseCommand> {
+    match event.kind {
+        MouseEventKind::ScrollDown => Some(MouseCommand::ScrollDown),
+        MouseEventKind::ScrollUp => Some(MouseCommand::ScrollUp),
+        MouseEventKind::Down(MouseButton::Left) if matches!(view_mode, ViewMode::List) => {
+            if let Some(column) = areas.header_column_at(event.column, event.row) {
+                clicks.last = None;
+                return Some(MouseCommand::SortBy(column));
+            }
+            let idx = offset + areas.row_at(event.column, event.row)?;
+            if idx >= rows_len {
+                return None;
+            }
+            let double = clicks
+                .last
+                .is_some_and(|(at, last)| last == idx && now.duration_since(at) <= DOUBLE_CLICK);
+            if double {
+                clicks.last = None;
+                Some(MouseCommand::Open(idx))
+            } else {
+                clicks.last = Some((now, idx));
+                Some(MouseCommand::Select(idx))
+            }
+        }
+        _ => None,
     }
 }
 
@@ -84,46 +989,122 @@ pub fn map_confirmation_key(kind: ConfirmationKind, key: KeyCode) -> Option<UiCo
 mod tests {
     use super::*;
 
+    fn default_key(view_mode: ViewMode, key: KeyCode) -> Option<UiCommand> {
+        map_key(
+            &KeyBindings::default(),
+            &mut PendingKeys::default(),
+            view_mode,
+            key,
+        )
+    }
+
+    fn default_confirmation_key(kind: ConfirmationKind, key: KeyCode) -> Option<UiCommand> {
+        map_confirmation_key(
+            &KeyBindings::default(),
+            &mut PendingKeys::default(),
+            kind,
+            key,
+        )
+    }
+
+    fn default_machine_picker_key(key: KeyCode) -> Option<UiCommand> {
+        map_machine_picker_key(&KeyBindings::default(), &mut PendingKeys::default(), key)
+    }
+
+    fn overrides(entries: &[(&str, &[&str])]) -> Vec<(String, Vec<String>)> {
+        entries
+            .iter()
+            .map(|(name, keys)| {
+                (
+                    name.to_string(),
+                    keys.iter().map(|k| k.to_string()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    fn bindings_from(entries: &[(&str, &[&str])]) -> Result<KeyBindings> {
+        let entries = overrides(entries);
+        KeyBindings::with_overrides(entries.iter().map(|(name, keys)| (name, keys)))
+    }
+
     #[test]
     fn map_key_list_mode_maps_navigation_and_opening() {
         assert_eq!(
-            map_key(ViewMode::List, KeyCode::Enter),
+            default_key(ViewMode::List, KeyCode::Enter),
             Some(UiCommand::OpenDetail)
         );
         assert_eq!(
-            map_key(ViewMode::List, KeyCode::Char('l')),
+            default_key(ViewMode::List, KeyCode::Char('l')),
             Some(UiCommand::OpenDetail)
         );
         assert_eq!(
-            map_key(ViewMode::List, KeyCode::Down),
+            default_key(ViewMode::List, KeyCode::Down),
             Some(UiCommand::MoveDown)
         );
         assert_eq!(
-            map_key(ViewMode::List, KeyCode::Up),
+            default_key(ViewMode::List, KeyCode::Up),
             Some(UiCommand::MoveUp)
         );
         assert_eq!(
-            map_key(ViewMode::List, KeyCode::Char('s')),
+            default_key(ViewMode::List, KeyCode::Char('s')),
             Some(UiCommand::RequestStartStop)
         );
         assert_eq!(
-            map_key(ViewMode::List, KeyCode::Char('e')),
+            default_key(ViewMode::List, KeyCode::Char('e')),
             Some(UiCommand::RequestEnableDisable)
         );
+        assert_eq!(
+            default_key(ViewMode::List, KeyCode::Char('p')),
+            Some(UiCommand::RequestPreset)
+        );
+        assert_eq!(
+            default_key(ViewMode::List, KeyCode::Char('o')),
+            Some(UiCommand::CycleSort)
+        );
+        assert_eq!(
+            default_key(ViewMode::List, KeyCode::Char('O')),
+            Some(UiCommand::ReverseSort)
+        );
+        assert_eq!(
+            default_key(ViewMode::List, KeyCode::Char('m')),
+            Some(UiCommand::OpenMachinePicker)
+        );
+    }
+
+    #[test]
+    fn map_machine_picker_key_maps_navigation_select_and_cancel() {
+        assert_eq!(
+            default_machine_picker_key(KeyCode::Down),
+            Some(UiCommand::MoveDown)
+        );
+        assert_eq!(
+            default_machine_picker_key(KeyCode::Up),
+            Some(UiCommand::MoveUp)
+        );
+        assert_eq!(
+            default_machine_picker_key(KeyCode::Enter),
+            Some(UiCommand::Confirm)
+        );
+        assert_eq!(
+            default_machine_picker_key(KeyCode::Esc),
+            Some(UiCommand::Cancel)
+        );
+        assert_eq!(default_machine_picker_key(KeyCode::Char('q')), None);
     }
 
     #[test]
     fn map_key_detail_mode_maps_back_and_refresh_detail() {
         assert_eq!(
-            map_key(ViewMode::Detail, KeyCode::Esc),
+            default_key(ViewMode::Detail, KeyCode::Esc),
             Some(UiCommand::BackToList)
         );
         assert_eq!(
-            map_key(ViewMode::Detail, KeyCode::Char('b')),
+            default_key(ViewMode::Detail, KeyCode::Char('b')),
             Some(UiCommand::BackToList)
         );
         assert_eq!(
-            map_key(ViewMode::Detail, KeyCode::Char('l')),
+            default_key(ViewMode::Detail, KeyCode::Char('l')),
             Some(UiCommand::RefreshDetail)
         );
     }
@@ -131,53 +1112,56 @@ mod tests {
     #[test]
     fn map_key_maps_quit_refresh_and_unknown_keys() {
         assert_eq!(
-            map_key(ViewMode::List, KeyCode::Char('q')),
+            default_key(ViewMode::List, KeyCode::Char('q')),
             Some(UiCommand::Quit)
         );
         assert_eq!(
-            map_key(ViewMode::List, KeyCode::Char('r')),
+            default_key(ViewMode::List, KeyCode::Char('r')),
             Some(UiCommand::Refresh)
         );
         assert_eq!(
-            map_key(ViewMode::Detail, KeyCode::Char('r')),
+            default_key(ViewMode::Detail, KeyCode::Char('r')),
             Some(UiCommand::Refresh)
         );
-        assert_eq!(map_key(ViewMode::Detail, KeyCode::Enter), None);
-        assert_eq!(map_key(ViewMode::Detail, KeyCode::Char('s')), None);
+        assert_eq!(
+            default_key(ViewMode::Detail, KeyCode::Enter),
+            Some(UiCommand::InspectEntry)
+        );
+        assert_eq!(default_key(ViewMode::Detail, KeyCode::Char('s')), None);
     }
 
     #[test]
     fn map_confirmation_key_maps_accept_and_decline() {
         assert_eq!(
-            map_confirmation_key(
+            default_confirmation_key(
                 ConfirmationKind::ConfirmAction(crate::types::UnitAction::Start),
                 KeyCode::Char('y')
             ),
             Some(UiCommand::Confirm)
         );
         assert_eq!(
-            map_confirmation_key(
+            default_confirmation_key(
                 ConfirmationKind::ConfirmAction(crate::types::UnitAction::Start),
                 KeyCode::Enter
             ),
             Some(UiCommand::Confirm)
         );
         assert_eq!(
-            map_confirmation_key(
+            default_confirmation_key(
                 ConfirmationKind::ConfirmAction(crate::types::UnitAction::Start),
                 KeyCode::Char('n')
             ),
             Some(UiCommand::Cancel)
         );
         assert_eq!(
-            map_confirmation_key(
+            default_confirmation_key(
                 ConfirmationKind::ConfirmAction(crate::types::UnitAction::Start),
                 KeyCode::Esc
             ),
             Some(UiCommand::Cancel)
         );
         assert_eq!(
-            map_confirmation_key(
+            default_confirmation_key(
                 ConfirmationKind::ConfirmAction(crate::types::UnitAction::Start),
                 KeyCode::Char('x')
             ),
@@ -188,20 +1172,335 @@ mod tests {
     #[test]
     fn map_confirmation_key_maps_restart_or_stop_prompt() {
         assert_eq!(
-            map_confirmation_key(ConfirmationKind::RestartOrStop, KeyCode::Char('r')),
+            default_confirmation_key(ConfirmationKind::RestartOrStop, KeyCode::Char('r')),
             Some(UiCommand::ChooseRestart)
         );
         assert_eq!(
-            map_confirmation_key(ConfirmationKind::RestartOrStop, KeyCode::Char('s')),
+            default_confirmation_key(ConfirmationKind::RestartOrStop, KeyCode::Char('s')),
             Some(UiCommand::ChooseStop)
         );
         assert_eq!(
-            map_confirmation_key(ConfirmationKind::RestartOrStop, KeyCode::Esc),
+            default_confirmation_key(ConfirmationKind::RestartOrStop, KeyCode::Esc),
             Some(UiCommand::Cancel)
         );
         assert_eq!(
-            map_confirmation_key(ConfirmationKind::RestartOrStop, KeyCode::Enter),
+            default_confirmation_key(ConfirmationKind::RestartOrStop, KeyCode::Enter),
+            None
+        );
+    }
+
+    #[test]
+    fn overrides_replace_default_keys_in_every_mode() {
+        let bindings = bindings_from(&[("move_down", &["j", "down"]), ("move_up", &["k"])])
+            .expect("vim keys should be valid");
+        let mut pending = PendingKeys::default();
+        for context in [
+            KeyContext::List,
+            KeyContext::Detail,
+            KeyContext::MachinePicker,
+        ] {
+            assert_eq!(
+                bindings.map(context, &mut pending, KeyCode::Char('j')),
+                Some(UiCommand::MoveDown)
+            );
+            assert_eq!(
+                bindings.map(context, &mut pending, KeyCode::Down),
+                Some(UiCommand::MoveDown)
+            );
+            assert_eq!(
+                bindings.map(context, &mut pending, KeyCode::Char('k')),
+                Some(UiCommand::MoveUp)
+            );
+            assert_eq!(bindings.map(context, &mut pending, KeyCode::Up), None);
+        }
+    }
+
+    #[test]
+    fn multi_key_sequences_wait_for_completion_and_replay_broken_prefixes() {
+        let bindings = bindings_from(&[
+            ("refresh", &["g r"]),
+            ("quit", &["Z Z"]),
+            ("top", &["home"]),
+        ])
+        .expect("sequences");
+        let mut pending = PendingKeys::default();
+        assert_eq!(
+            map_key(&bindings, &mut pending, ViewMode::List, KeyCode::Char('g')),
+            None
+        );
+        assert_eq!(
+            map_key(&bindings, &mut pending, ViewMode::List, KeyCode::Char('r')),
+            Some(UiCommand::Refresh)
+        );
+        assert_eq!(
+            map_key(&bindings, &mut pending, ViewMode::List, KeyCode::Char('g')),
+            None
+        );
+        assert_eq!(
+            map_key(&bindings, &mut pending, ViewMode::List, KeyCode::Char('s')),
+            Some(UiCommand::RequestStartStop)
+        );
+        assert_eq!(
+            map_key(&bindings, &mut pending, ViewMode::List, KeyCode::Char('Z')),
+            None
+        );
+        assert_eq!(
+            map_key(
+                &bindings,
+                &mut pending,
+                ViewMode::Detail,
+                KeyCode::Char('Z')
+            ),
             None
         );
+        assert_eq!(
+            map_key(
+                &bindings,
+                &mut pending,
+                ViewMode::Detail,
+                KeyCode::Char('Z')
+            ),
+            Some(UiCommand::Quit)
+        );
+    }
+
+    #[test]
+    fn overrides_reject_conflicts_unknown_commands_and_keys() {
+        let err = bindings_from(&[("refresh", &["l"])]).expect_err("l opens details");
+        assert_eq!(
+            err.to_string(),
+            "key binding conflict in list mode: l (open_detail) and l (refresh)"
+        );
+        let err = bindings_from(&[("move_down", &["g g"]), ("move_up", &["g"]), ("top", &[])])
+            .expect_err("prefix conflict");
+        let err_g = bindings_from(&[("refresh", &["g r"])]).expect_err("g jumps to the top");
+        assert_eq!(
+            err_g.to_string(),
+            "key binding conflict in list mode: g (top) and gr (refresh)"
+        );
+        assert!(err.to_string().contains("gg (move_down) and g (move_up)"));
+        let err = bindings_from(&[("jump", &["j"])]).expect_err("unknown command");
+        assert_eq!(err.to_string(), "unknown command in [keys]: jump");
+        let err = bindings_from(&[("quit", &["ctrl-q"])]).expect_err("unknown key");
+        assert_eq!(err.to_string(), "unknown key in binding: ctrl-q");
+        assert!(bindings_from(&[("quit", &[" "])]).is_err());
+        let unbound = bindings_from(&[("machine_picker", &[])]).expect("unbind");
+        assert!(
+            unbound
+                .keys_for(KeyContext::List, UiCommand::OpenMachinePicker)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn parse_key_sequence_and_labels_round_trip_named_keys() {
+        assert_eq!(
+            parse_key_sequence("g g").expect("gg"),
+            vec![KeyCode::Char('g'), KeyCode::Char('g')]
+        );
+        assert_eq!(
+            parse_key_sequence("pagedown f5 space").expect("named"),
+            vec![KeyCode::PageDown, KeyCode::F(5), KeyCode::Char(' ')]
+        );
+        assert_eq!(
+            key_sequence_label(&[KeyCode::Char('g'), KeyCode::Char('g')]),
+            "gg"
+        );
+        assert_eq!(
+            key_sequence_label(&[KeyCode::Char('g'), KeyCode::Enter]),
+            "g enter"
+        );
+        assert_eq!(key_label(KeyCode::Down), "↓");
+    }
+
+    #[test]
+    fn map_mouse_selects_opens_sorts_and_scrolls() {
+        use crossterm::event::KeyModifiers;
+        use ratatui::layout::Rect;
+
+        let areas = ListHitAreas {
+            header: Rect::new(1, 1, 60, 1),
+            rows: Rect::new(1, 2, 60, 5),
+            row_height: 1,
+            columns: vec![(Column::Unit, 4, 20), (Column::Active, 25, 8)],
+        };
+        let event = |kind, column, row| MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        };
+        let click = |column, row| event(MouseEventKind::Down(MouseButton::Left), column, row);
+        let mut clicks = ClickTracker::default();
+        let now = Instant::now();
+        let map = |ev, view_mode, clicks: &mut ClickTracker, at| {
+            map_mouse(ev, view_mode, &areas, 10, 12, clicks, at)
+        };
+
+        assert_eq!(
+            map(click(26, 1), ViewMode::List, &mut clicks, now),
+            Some(MouseCommand::SortBy(Column::Active))
+        );
+        assert_eq!(map(click(2, 1), ViewMode::List, &mut clicks, now), None);
+        assert_eq!(
+            map(click(5, 3), ViewMode::List, &mut clicks, now),
+            Some(MouseCommand::Select(11))
+        );
+        assert_eq!(
+            map(click(9, 3), ViewMode::List, &mut clicks, now + DOUBLE_CLICK),
+            Some(MouseCommand::Open(11))
+        );
+        let later = now + DOUBLE_CLICK * 3;
+        assert_eq!(
+            map(click(5, 2), ViewMode::List, &mut clicks, later),
+            Some(MouseCommand::Select(10))
+        );
+        assert_eq!(
+            map(click(5, 3), ViewMode::List, &mut clicks, later),
+            Some(MouseCommand::Select(11))
+        );
+        // Only 12 rows exist; the third visible line is past the end.
+        assert_eq!(map(click(5, 4), ViewMode::List, &mut clicks, later), None);
+        assert_eq!(map(click(5, 3), ViewMode::Detail, &mut clicks, later), None);
+        assert_eq!(
+            map(
+                event(MouseEventKind::ScrollDown, 0, 0),
+                ViewMode::Detail,
+                &mut clicks,
+                later
+            ),
+            Some(MouseCommand::ScrollDown)
+        );
+        assert_eq!(
+            map(
+                event(MouseEventKind::ScrollUp, 0, 0),
+                ViewMode::List,
+                &mut clicks,
+                later
+            ),
+            Some(MouseCommand::ScrollUp)
+        );
+    }
+
+    #[test]
+    fn help_context_scrolls_and_closes() {
+        let bindings = KeyBindings::default();
+        let mut pending = PendingKeys::default();
+        let mut help = |key| map_help_key(&bindings, &mut pending, key);
+        assert_eq!(help(KeyCode::Down), Some(UiCommand::MoveDown));
+        assert_eq!(help(KeyCode::Char('?')), Some(UiCommand::Cancel));
+        assert_eq!(help(KeyCode::Esc), Some(UiCommand::Cancel));
+        assert_eq!(help(KeyCode::Char('q')), None);
+        assert_eq!(
+            default_key(ViewMode::Detail, KeyCode::Char('?')),
+            Some(UiCommand::ToggleHelp)
+        );
+
+        let list = bindings.commands_in(KeyContext::List);
+        let (_, top_keys) = list
+            .iter()
+            .find(|(cmd, _)| *cmd == UiCommand::JumpTop)
+            .expect("top is bound");
+        assert_eq!(top_keys, &[&[KeyCode::Home][..], &[KeyCode::Char('g')][..]]);
+        assert_eq!(list.first().map(|(cmd, _)| *cmd), Some(UiCommand::Quit));
+    }
+
+    #[test]
+    fn colon_opens_the_command_palette_in_both_views() {
+        assert_eq!(
+            default_key(ViewMode::List, KeyCode::Char(':')),
+            Some(UiCommand::OpenPalette)
+        );
+        assert_eq!(
+            default_key(ViewMode::Detail, KeyCode::Char(':')),
+            Some(UiCommand::OpenPalette)
+        );
+        assert_eq!(
+            UiCommand::from_config_name("command_palette"),
+            Some(UiCommand::OpenPalette)
+        );
+    }
+
+    #[test]
+    fn slash_and_n_search_only_in_the_detail_view() {
+        for (key, command) in [
+            ('/', UiCommand::OpenSearch),
+            ('n', UiCommand::NextMatch),
+            ('N', UiCommand::PreviousMatch),
+        ] {
+            assert_eq!(
+                default_key(ViewMode::Detail, KeyCode::Char(key)),
+                Some(command)
+            );
+            assert_eq!(default_key(ViewMode::List, KeyCode::Char(key)), None);
+        }
+    }
+
+    #[test]
+    fn enter_inspects_entries_and_closes_the_inspector() {
+        assert_eq!(
+            default_key(ViewMode::List, KeyCode::Enter),
+            Some(UiCommand::OpenDetail)
+        );
+        let bindings = KeyBindings::default();
+        let mut pending = PendingKeys::default();
+        let mut inspector = |key| map_inspector_key(&bindings, &mut pending, key);
+        assert_eq!(inspector(KeyCode::Down), Some(UiCommand::MoveDown));
+        assert_eq!(inspector(KeyCode::Up), Some(UiCommand::MoveUp));
+        assert_eq!(inspector(KeyCode::Enter), Some(UiCommand::Cancel));
+        assert_eq!(inspector(KeyCode::Esc), Some(UiCommand::Cancel));
+        assert_eq!(inspector(KeyCode::Char('q')), None);
+    }
+
+    #[test]
+    fn brackets_and_i_navigate_invocations_in_the_detail_view() {
+        for (key, command) in [
+            (']', UiCommand::NextInvocation),
+            ('[', UiCommand::PreviousInvocation),
+            ('i', UiCommand::OpenInvocationPicker),
+        ] {
+            assert_eq!(
+                default_key(ViewMode::Detail, KeyCode::Char(key)),
+                Some(command)
+            );
+            assert_eq!(default_key(ViewMode::List, KeyCode::Char(key)), None);
+        }
+        let bindings = KeyBindings::default();
+        let mut pending = PendingKeys::default();
+        let mut picker = |key| map_invocation_picker_key(&bindings, &mut pending, key);
+        assert_eq!(picker(KeyCode::Down), Some(UiCommand::MoveDown));
+        assert_eq!(picker(KeyCode::Enter), Some(UiCommand::Confirm));
+        assert_eq!(picker(KeyCode::Char('i')), Some(UiCommand::Cancel));
+        assert_eq!(picker(KeyCode::Esc), Some(UiCommand::Cancel));
+    }
+
+    #[test]
+    fn capital_b_opens_and_closes_the_boot_picker() {
+        for view_mode in [ViewMode::List, ViewMode::Detail] {
+            assert_eq!(
+                default_key(view_mode, KeyCode::Char('B')),
+                Some(UiCommand::OpenBootPicker)
+            );
+        }
+        let bindings = KeyBindings::default();
+        let mut pending = PendingKeys::default();
+        let mut picker = |key| map_boot_picker_key(&bindings, &mut pending, key);
+        assert_eq!(picker(KeyCode::Up), Some(UiCommand::MoveUp));
+        assert_eq!(picker(KeyCode::Enter), Some(UiCommand::Confirm));
+        assert_eq!(picker(KeyCode::Char('B')), Some(UiCommand::Cancel));
+        assert_eq!(picker(KeyCode::Esc), Some(UiCommand::Cancel));
+    }
+
+    #[test]
+    fn space_marks_units_and_capital_t_opens_the_timeline() {
+        assert_eq!(
+            default_key(ViewMode::List, KeyCode::Char(' ')),
+            Some(UiCommand::ToggleMark)
+        );
+        assert_eq!(
+            default_key(ViewMode::List, KeyCode::Char('T')),
+            Some(UiCommand::OpenTimeline)
+        );
+        assert_eq!(default_key(ViewMode::Detail, KeyCode::Char(' ')), None);
     }
 }
@@ -20,11 +20,13 @@
 //! - `workers`: background loading for list/detail data
 //! - `render`: frame rendering for list/detail views
 //! - `input`: key translation into view-independent commands
+//! - `palette`: parsing and completion of `:` commands
 //! - `state`: pure status text helpers
 
 #[cfg(feature = "debug_tui")]
 mod debug;
 mod input;
+mod palette;
 mod render;
 mod state;
 mod workers;
@@ -34,7 +36,7 @@ use anyhow::Context;
 use anyhow::Result;
 #[cfg(not(test))]
 use crossterm::{
-    event::{self, Event, KeyEventKind},
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
@@ -51,30 +53,53 @@ use std::{
 #[cfg(not(test))]
 use crate::{
     cli::{parse_args, usage, version_text},
-    rows::preserve_selection,
+    rows::{apply_log_preview, index_rows, preserve_selection, timeline_units},
     systemd::run_unit_action,
     types::{
-        ActionResolutionRequest, ConfirmationState, DetailState, LoadPhase, UnitAction, UnitRow,
-        ViewMode, WorkerMsg,
+        ActionResolutionRequest, AutoRefreshState, BootPickerState, ConfirmationState, DetailState,
+        InspectorState, InvocationPickerState, LoadPhase, LogPaneFollow, MachinePickerState,
+        PaletteState, UnitAction, UnitKey, ViewMode, WorkerMsg,
     },
 };
+use crate::{
+    rows::resort_rows,
+    types::{Scope, SortMode, UnitRow},
+};
 
 #[cfg(not(test))]
 use self::{
-    input::{UiCommand, map_confirmation_key, map_key},
-    render::draw_frame,
+    input::{
+        ClickTracker, KeyBindings, MouseCommand, PendingKeys, UiCommand, map_boot_picker_key,
+        map_confirmation_key, map_help_key, map_inspector_key, map_invocation_picker_key, map_key,
+        map_machine_picker_key, map_mouse,
+    },
+    palette::{
+        PaletteCommand, complete_palette_input, find_unit, palette_action_request,
+        parse_palette_command,
+    },
+    render::{
+        Overlays, ViewState, draw_frame, list_hit_areas, log_line_heights, log_message_columns,
+        viewport_rows,
+    },
     state::{
-        MODE_LABEL, action_authenticating_status_text, action_resolution_status_text,
-        list_status_text, loading_units_status_text, stale_status_text,
+        KeyHints, MODE_LABEL, action_authenticating_status_text, action_resolution_status_text,
+        auto_refresh_text, detail_cursor_target, detail_scroll_target, inspector_lines,
+        jump_target, list_status_text, list_status_with_scope_error_text,
+        loading_units_status_text, stale_status_text,
+    },
+    workers::{
+        spawn_action_resolution_worker, spawn_boot_list_worker, spawn_detail_refresh_worker,
+        spawn_detail_worker, spawn_entry_worker, spawn_machine_list_worker, spawn_refresh_worker,
+        spawn_timeline_worker,
     },
-    workers::{spawn_action_resolution_worker, spawn_detail_worker, spawn_refresh_worker},
 };
 
 #[cfg(not(test))]
 fn setup_terminal() -> Result<Terminal<CrosstermBackend<io::Stdout>>> {
     enable_raw_mode().context("enable_raw_mode failed")?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen).context("EnterAlternateScreen failed")?;
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)
+        .context("EnterAlternateScreen failed")?;
     let backend = CrosstermBackend::new(stdout);
     Ok(Terminal::new(backend)?)
 }
@@ -82,7 +107,12 @@ fn setup_terminal() -> Result<Terminal<CrosstermBackend<io::Stdout>>> {
 #[cfg(not(test))]
 fn restore_terminal(mut terminal: Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
     disable_raw_mode().ok();
-    execute!(terminal.backend_mut(), LeaveAlternateScreen).ok();
+    execute!(
+        terminal.backend_mut(),
+        DisableMouseCapture,
+        LeaveAlternateScreen
+    )
+    .ok();
     terminal.show_cursor().ok();
     Ok(())
 }
@@ -91,8 +121,12 @@ fn restore_terminal(mut terminal: Terminal<CrosstermBackend<io::Stdout>>) -> Res
 #[cfg(not(test))]
 fn suspend_terminal(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
     disable_raw_mode().context("disable_raw_mode failed")?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)
-        .context("LeaveAlternateScreen failed")?;
+    execute!(
+        terminal.backend_mut(),
+        DisableMouseCapture,
+        LeaveAlternateScreen
+    )
+    .context("LeaveAlternateScreen failed")?;
     terminal.show_cursor().context("show_cursor failed")?;
     Ok(())
 }
@@ -100,8 +134,12 @@ fn suspend_terminal(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Re
 /// Resume the TUI after a suspension, clearing any output left by external processes.
 #[cfg(not(test))]
 fn resume_terminal(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
-    execute!(terminal.backend_mut(), EnterAlternateScreen)
-        .context("EnterAlternateScreen failed")?;
+    execute!(
+        terminal.backend_mut(),
+        EnterAlternateScreen,
+        EnableMouseCapture
+    )
+    .context("EnterAlternateScreen failed")?;
     enable_raw_mode().context("enable_raw_mode failed")?;
     terminal.clear().context("terminal clear failed")?;
     Ok(())
@@ -117,6 +155,7 @@ fn apply_confirmed_action_result(
     unit: &str,
     action: crate::types::UnitAction,
     rows_len: usize,
+    hints: &self::state::KeyHints,
     status_line: &mut String,
     status_line_overrides_stale: &mut bool,
     refresh_requested: &mut bool,
@@ -129,7 +168,7 @@ fn apply_confirmed_action_result(
             set_status_line(
                 status_line,
                 status_line_overrides_stale,
-                self::state::action_queued_status_text(rows_len, action, unit),
+                self::state::action_queued_status_text(rows_len, action, unit, hints),
                 true,
             );
         }
@@ -137,7 +176,13 @@ fn apply_confirmed_action_result(
             set_status_line(
                 status_line,
                 status_line_overrides_stale,
-                self::state::action_error_status_text(rows_len, action, unit, &e.to_string()),
+                self::state::action_error_status_text(
+                    rows_len,
+                    action,
+                    unit,
+                    &e.to_string(),
+                    hints,
+                ),
                 true,
             );
         }
@@ -161,11 +206,13 @@ fn apply_confirmed_action_result(
 #[allow(clippy::too_many_arguments)]
 fn run_confirmed_action(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
-    scope: crate::types::Scope,
+    target: &crate::types::Target,
+    root: &crate::types::UnitFileRoot,
     unit: &str,
     action: UnitAction,
     debug_tui: bool,
     rows_len: usize,
+    hints: &self::state::KeyHints,
     status_line: &mut String,
     status_line_overrides_stale: &mut bool,
     refresh_requested: &mut bool,
@@ -182,12 +229,12 @@ fn run_confirmed_action(
     let result = if debug_tui {
         self::debug::run_debug_unit_action(unit, action)
     } else {
-        run_unit_action(scope, unit, action)
+        run_unit_action(target, root, unit, action)
     };
     #[cfg(not(feature = "debug_tui"))]
     let result = {
         let _ = debug_tui; // parameter unused without debug_tui feature
-        run_unit_action(scope, unit, action)
+        run_unit_action(target, root, unit, action)
     };
     resume_terminal(terminal)?;
     apply_confirmed_action_result(
@@ -195,6 +242,7 @@ fn run_confirmed_action(
         unit,
         action,
         rows_len,
+        hints,
         status_line,
         status_line_overrides_stale,
         refresh_requested,
@@ -268,6 +316,7 @@ struct ActionResolutionUiState<'a> {
     list_status_line: &'a str,
     list_status_line_overrides_stale: bool,
     rows_len: usize,
+    hints: &'a self::state::KeyHints,
     view_mode: crate::types::ViewMode,
     selected_unit: Option<&'a str>,
 }
@@ -319,7 +368,12 @@ fn apply_action_resolution_msg(
             set_status_line(
                 status_line,
                 status_line_overrides_stale,
-                self::state::action_resolution_error_status_text(ui.rows_len, &unit, &error),
+                self::state::action_resolution_error_status_text(
+                    ui.rows_len,
+                    &unit,
+                    &error,
+                    ui.hints,
+                ),
                 true,
             );
             true
@@ -330,6 +384,9 @@ fn apply_action_resolution_msg(
 
 const UNIT_ACTION_REFRESH_DELAY: Duration = Duration::from_millis(500);
 
+/// Cells one horizontal scroll step moves unwrapped log messages.
+const HSCROLL_STEP: usize = 8;
+
 fn defer_queued_action_refresh(
     refresh_requested: &mut bool,
     queued_action_refresh_deadline: &mut Option<Instant>,
@@ -355,10 +412,94 @@ fn activate_queued_action_refresh(
     }
 }
 
+/// Re-sort the list after the sort order changed, keeping the selection.
+///
+/// Returns `true` when the new order needs unit properties that were not fetched
+/// before, so the list must be reloaded.
+fn apply_sort_change(
+    config: &mut crate::cli::Config,
+    mode: SortMode,
+    reverse: bool,
+    rows: &mut [UnitRow],
+    selected_idx: &mut usize,
+) -> bool {
+    let fetched = config.unit_properties();
+    config.sort_mode = mode;
+    config.sort_reverse = reverse;
+    resort_rows(rows, config.sort_mode, config.sort_reverse, selected_idx);
+    // Memory and restart counts are only fetched while something needs them.
+    config
+        .unit_properties()
+        .iter()
+        .any(|property| !fetched.contains(property))
+}
+
+/// Point the target at the machine chosen in the picker.
+///
+/// Returns `true` when the machine changed and the unit list must be reloaded.
+fn apply_machine_selection(target: &mut crate::types::Target, machine: Option<String>) -> bool {
+    if target.machine == machine {
+        return false;
+    }
+    target.machine = machine;
+    true
+}
+
+/// Read logs of the boot chosen in the picker; `None` reads every boot.
+///
+/// Returns `true` when the boot changed and the logs must be reloaded.
+fn apply_boot_selection(current: &mut Option<String>, boot: Option<String>) -> bool {
+    if *current == boot {
+        return false;
+    }
+    *current = boot;
+    true
+}
+
+/// Replace the list filters with the ones typed into the palette.
+///
+/// Returns `true` when a filter changed and the unit list must be reloaded.
+fn apply_filter_change(
+    config: &mut crate::cli::Config,
+    load: String,
+    active: String,
+    sub: String,
+) -> bool {
+    if config.load_filter == load && config.active_filter == active && config.sub_filter == sub {
+        return false;
+    }
+    config.load_filter = load;
+    config.active_filter = active;
+    config.sub_filter = sub;
+    true
+}
+
+/// List the units of one scope, or of both scopes when `scope` is `None`.
+///
+/// Returns `true` when the listed scopes changed and the unit list must be reloaded; a
+/// scope the target cannot reach, such as a user manager on a remote host, is rejected.
+fn apply_scope_selection(config: &mut crate::cli::Config, scope: Option<Scope>) -> Result<bool> {
+    let before = config.scopes();
+    if let Some(scope) = scope {
+        let target = crate::types::Target {
+            scope,
+            ..config.target.clone()
+        };
+        crate::cli::validate_target(&target, false, &config.journal, &config.root)?;
+        config.target = target;
+        config.both_scopes = false;
+    } else {
+        // Both scopes include the user manager of the target.
+        crate::cli::validate_target(&config.target, true, &config.journal, &config.root)?;
+        config.both_scopes = true;
+    }
+    Ok(config.scopes() != before)
+}
+
 /// Run the interactive terminal UI.
 #[cfg(not(test))]
 pub fn run() -> Result<()> {
-    let config = parse_args(env::args())?;
+    let mut config = parse_args(env::args())?;
     if config.show_version {
         println!("{}", version_text());
         return Ok(());
@@ -367,6 +508,14 @@ pub fn run() -> Result<()> {
         println!("{}", usage());
         return Ok(());
     }
+    if let Some(timeout) = config.command_timeout {
+        crate::command::set_configured_command_timeout(timeout);
+    }
+    let key_bindings = KeyBindings::with_overrides(&config.key_bindings)
+        .context("invalid [keys] table in config file")?;
+    let key_hints = KeyHints::from_bindings(&key_bindings);
+    let mut pending_keys = PendingKeys::default();
+    let mut clicks = ClickTracker::default();
 
     let mut terminal = setup_terminal()?;
 
@@ -375,20 +524,36 @@ pub fn run() -> Result<()> {
     let mut worker_rx: Option<Receiver<WorkerMsg>> = None;
     let mut detail_worker_rx: Option<Receiver<WorkerMsg>> = None;
     let mut action_resolution_worker_rx: Option<Receiver<WorkerMsg>> = None;
+    let mut machine_worker_rx: Option<Receiver<WorkerMsg>> = None;
+    let mut boot_worker_rx: Option<Receiver<WorkerMsg>> = None;
+    let mut entry_worker_rx: Option<Receiver<WorkerMsg>> = None;
     let mut queued_action_refresh_deadline: Option<Instant> = None;
+    let mut auto_refresh = AutoRefreshState::new(config.refresh_interval);
     let mut loaded_once = false;
     let mut last_load_error = false;
     let mut last_load_error_message: Option<String> = None;
+    // Scope that failed while the other listed scope loaded, shown in the list status.
+    let mut scope_error: Option<String> = None;
 
     let mut rows: Vec<UnitRow> = Vec::new();
-    let mut row_index_by_unit: HashMap<String, usize> = HashMap::new();
+    // Query the listed rows answered, and the one the running refresh worker answers.
+    let mut rows_query = config.list_query();
+    let mut worker_query = rows_query.clone();
+    let mut row_index_by_key: HashMap<UnitKey, usize> = HashMap::new();
     let mut selected_idx: usize = 0;
     let mut list_table_state = TableState::default();
     let mut view_mode = ViewMode::List;
     let mut detail = DetailState::default();
+    let mut log_pane = LogPaneFollow::default();
     let mut confirmation: Option<ConfirmationState> = None;
+    let mut machine_picker: Option<MachinePickerState> = None;
+    let mut help_scroll: Option<usize> = None;
+    let mut inspector: Option<InspectorState> = None;
+    let mut invocation_picker: Option<InvocationPickerState> = None;
+    let mut boot_picker: Option<BootPickerState> = None;
+    let mut palette: Option<PaletteState> = None;
     let mut status_line_overrides_stale = false;
-    let mut status_line = list_status_text(0, None);
+    let mut status_line = list_status_text(0, None, &key_hints);
     let mut list_status_line = status_line.clone();
     let mut list_status_line_overrides_stale = false;
 
@@ -399,25 +564,61 @@ pub fn run() -> Result<()> {
                 &mut queued_action_refresh_deadline,
                 Instant::now(),
             );
+            if auto_refresh.is_due(Instant::now()) {
+                auto_refresh.begin_refresh();
+                refresh_requested = true;
+            }
+            // In a split layout the log pane follows the selection once it settles.
+            if config.split.is_split() && matches!(view_mode, ViewMode::List) {
+                let now = Instant::now();
+                log_pane.observe(rows.get(selected_idx).map(UnitRow::key), now);
+                if let Some((scope, unit)) = log_pane.take_due(now) {
+                    let request_id = if detail.scope == scope && detail.unit == unit {
+                        detail.refresh()
+                    } else {
+                        Some(detail.begin_for_unit(scope, unit))
+                    };
+                    if let Some(request_id) = request_id {
+                        detail_worker_rx = Some(spawn_detail_worker(
+                            &config.for_scope(detail.scope),
+                            detail.unit.clone(),
+                            request_id,
+                        ));
+                    }
+                }
+            }
 
+            let auto_refresh_label = auto_refresh_text(&auto_refresh, Instant::now());
             terminal.draw(|f| {
                 draw_frame(
                     f,
-                    view_mode,
-                    MODE_LABEL,
-                    &rows,
-                    selected_idx,
                     &mut list_table_state,
-                    &detail,
-                    phase,
-                    loaded_once,
-                    last_load_error,
-                    last_load_error_message.as_deref(),
-                    refresh_requested,
-                    &status_line,
-                    status_line_overrides_stale,
-                    confirmation.as_ref(),
+                    &ViewState {
+                        view_mode,
+                        mode_label: MODE_LABEL,
+                        rows: &rows,
+                        selected_idx,
+                        detail: &detail,
+                        phase,
+                        loaded_once,
+                        last_load_error,
+                        last_load_error_message: last_load_error_message.as_deref(),
+                        refresh_requested,
+                        status_line: &status_line,
+                        status_line_overrides_stale,
+                        auto_refresh: auto_refresh_label.as_deref(),
+                    },
+                    &Overlays {
+                        confirmation: confirmation.as_ref(),
+                        machine_picker: machine_picker.as_ref(),
+                        help: help_scroll,
+                        inspector: inspector.as_ref(),
+                        invocation_picker: invocation_picker.as_ref(),
+                        boot_picker: boot_picker.as_ref(),
+                        palette: palette.as_ref(),
+                    },
                     &config,
+                    &key_hints,
                 );
             })?;
 
@@ -428,29 +629,34 @@ pub fn run() -> Result<()> {
                     &mut list_status_line_overrides_stale,
                     &mut status_line,
                     &mut status_line_overrides_stale,
-                    loading_units_status_text(),
+                    loading_units_status_text(&key_hints),
                     false,
                 );
                 queued_action_refresh_deadline = None;
                 refresh_requested = false;
-                worker_rx = Some(spawn_refresh_worker(config.clone(), rows.clone()));
+                auto_refresh.begin_refresh();
+                worker_query = config.list_query();
+                scope_error = None;
+                worker_rx = Some(spawn_refresh_worker(
+                    config.clone(),
+                    rows.clone(),
+                    worker_query == rows_query,
+                ));
             }
 
             if let Some(rx) = worker_rx.as_ref() {
                 let mut clear_worker = false;
                 loop {
                     match rx.try_recv() {
+                        Ok(WorkerMsg::ScopeError(e)) => scope_error = Some(e),
                         Ok(WorkerMsg::UnitsLoaded(new_rows)) => {
                             loaded_once = true;
+                            rows_query = worker_query.clone();
                             last_load_error = false;
                             last_load_error_message = None;
-                            let previous_selected = rows.get(selected_idx).map(|r| r.unit.clone());
+                            let previous_selected = rows.get(selected_idx).map(UnitRow::key);
                             rows = new_rows;
-                            row_index_by_unit = rows
-                                .iter()
-                                .enumerate()
-                                .map(|(idx, row)| (row.unit.clone(), idx))
-                                .collect();
+                            row_index_by_key = index_rows(&rows);
                             preserve_selection(previous_selected, &rows, &mut selected_idx);
                             if rows.is_empty() {
                                 set_list_status_line(
@@ -458,7 +664,12 @@ pub fn run() -> Result<()> {
                                     &mut list_status_line_overrides_stale,
                                     &mut status_line,
                                     &mut status_line_overrides_stale,
-                                    list_status_text(0, None),
+                                    list_status_with_scope_error_text(
+                                        0,
+                                        None,
+                                        scope_error.as_deref(),
+                                        &key_hints,
+                                    ),
                                     false,
                                 );
                                 phase = LoadPhase::Idle;
@@ -468,26 +679,45 @@ pub fn run() -> Result<()> {
                                     &mut list_status_line_overrides_stale,
                                     &mut status_line,
                                     &mut status_line_overrides_stale,
-                                    list_status_text(rows.len(), Some((0, rows.len()))),
+                                    list_status_with_scope_error_text(
+                                        rows.len(),
+                                        Some((0, rows.len())),
+                                        scope_error.as_deref(),
+                                        &key_hints,
+                                    ),
                                     false,
                                 );
                                 phase = LoadPhase::FetchingLogs;
                             }
                         }
                         Ok(WorkerMsg::LogsProgress { done, total, logs }) => {
-                            for (unit, log) in logs {
-                                if let Some(idx) = row_index_by_unit.get(&unit).copied()
+                            for (key, log) in logs {
+                                if let Some(idx) = row_index_by_key.get(&key).copied()
                                     && let Some(row) = rows.get_mut(idx)
                                 {
-                                    row.last_log = log;
+                                    apply_log_preview(row, log, Instant::now());
                                 }
                             }
+                            if config.sort_mode == SortMode::LastLog {
+                                resort_rows(
+                                    &mut rows,
+                                    config.sort_mode,
+                                    config.sort_reverse,
+                                    &mut selected_idx,
+                                );
+                                row_index_by_key = index_rows(&rows);
+                            }
                             set_list_status_line(
                                 &mut list_status_line,
                                 &mut list_status_line_overrides_stale,
                                 &mut status_line,
                                 &mut status_line_overrides_stale,
-                                list_status_text(rows.len(), Some((done, total))),
+                                list_status_with_scope_error_text(
+                                    rows.len(),
+                                    Some((done, total)),
+                                    scope_error.as_deref(),
+                                    &key_hints,
+                                ),
                                 false,
                             );
                             phase = LoadPhase::FetchingLogs;
@@ -499,13 +729,21 @@ pub fn run() -> Result<()> {
                                 &mut list_status_line_overrides_stale,
                                 &mut status_line,
                                 &mut status_line_overrides_stale,
-                                list_status_text(rows.len(), None),
+                                list_status_with_scope_error_text(
+                                    rows.len(),
+                                    None,
+                                    scope_error.as_deref(),
+                                    &key_hints,
+                                ),
                                 false,
                             );
+                            auto_refresh.record_success(Instant::now());
+                            log_pane.reload_now(Instant::now());
                             clear_worker = true;
                             break;
                         }
                         Ok(WorkerMsg::Error(e)) => {
+                            auto_refresh.record_error(Instant::now());
                             last_load_error = true;
                             last_load_error_message = Some(e);
                             set_list_status_line(
@@ -513,7 +751,7 @@ pub fn run() -> Result<()> {
                                 &mut list_status_line_overrides_stale,
                                 &mut status_line,
                                 &mut status_line_overrides_stale,
-                                stale_status_text(rows.len()),
+                                stale_status_text(rows.len(), &key_hints),
                                 false,
                             );
                             phase = LoadPhase::Idle;
@@ -524,10 +762,17 @@ pub fn run() -> Result<()> {
                             WorkerMsg::DetailLogsLoaded { .. }
                             | WorkerMsg::DetailLogsError { .. }
                             | WorkerMsg::ActionConfirmationReady { .. }
-                            | WorkerMsg::ActionResolutionError { .. },
+                            | WorkerMsg::ActionResolutionError { .. }
+                            | WorkerMsg::MachinesLoaded(_)
+                            | WorkerMsg::MachinesError(_)
+                            | WorkerMsg::BootsLoaded(_)
+                            | WorkerMsg::BootsError(_)
+                            | WorkerMsg::EntryLoaded(_)
+                            | WorkerMsg::EntryError(_),
                         ) => continue,
                         Err(TryRecvError::Empty) => break,
                         Err(TryRecvError::Disconnected) => {
+                            auto_refresh.record_error(Instant::now());
                             phase = LoadPhase::Idle;
                             clear_worker = true;
                             break;
@@ -587,6 +832,7 @@ pub fn run() -> Result<()> {
                                     list_status_line: &list_status_line,
                                     list_status_line_overrides_stale,
                                     rows_len: rows.len(),
+                                    hints: &key_hints,
                                     view_mode,
                                     selected_unit: rows
                                         .get(selected_idx)
@@ -610,13 +856,389 @@ pub fn run() -> Result<()> {
                 }
             }
 
-            if event::poll(Duration::from_millis(50))?
-                && let Event::Key(k) = event::read()?
-                && k.kind == KeyEventKind::Press
-            {
-                if let Some(cmd) = confirmation
-                    .as_ref()
-                    .and_then(|pending| map_confirmation_key(pending.kind, k.code))
+            if let Some(rx) = machine_worker_rx.as_ref() {
+                match rx.try_recv() {
+                    Ok(msg) => {
+                        if let Some(picker) = machine_picker.as_mut() {
+                            match msg {
+                                WorkerMsg::MachinesLoaded(machines) => {
+                                    picker.apply_loaded(machines)
+                                }
+                                WorkerMsg::MachinesError(error) => picker.apply_error(error),
+                                _ => {}
+                            }
+                        }
+                        machine_worker_rx = None;
+                    }
+                    Err(TryRecvError::Empty) => {}
+                    Err(TryRecvError::Disconnected) => machine_worker_rx = None,
+                }
+            }
+
+            if let Some(rx) = boot_worker_rx.as_ref() {
+                match rx.try_recv() {
+                    Ok(msg) => {
+                        if let Some(picker) = boot_picker.as_mut() {
+                            match msg {
+                                WorkerMsg::BootsLoaded(boots) => picker.apply_loaded(boots),
+                                WorkerMsg::BootsError(error) => picker.apply_error(error),
+                                _ => {}
+                            }
+                        }
+                        boot_worker_rx = None;
+                    }
+                    Err(TryRecvError::Empty) => {}
+                    Err(TryRecvError::Disconnected) => boot_worker_rx = None,
+                }
+            }
+
+            if let Some(rx) = entry_worker_rx.as_ref() {
+                match rx.try_recv() {
+                    Ok(msg) => {
+                        if let Some(inspector) = inspector.as_mut() {
+                            match msg {
+                                WorkerMsg::EntryLoaded(entry) => inspector.apply_loaded(entry),
+                                WorkerMsg::EntryError(error) => inspector.apply_error(error),
+                                _ => {}
+                            }
+                        }
+                        entry_worker_rx = None;
+                    }
+                    Err(TryRecvError::Empty) => {}
+                    Err(TryRecvError::Disconnected) => entry_worker_rx = None,
+                }
+            }
+
+            if event::poll(Duration::from_millis(50))? {
+                let (mut key, mut direct_cmd) = match event::read()? {
+                    Event::Key(k) if k.kind == KeyEventKind::Press => (Some(k.code), None),
+                    Event::Mouse(mouse)
+                        if help_scroll.is_none()
+                            && machine_picker.is_none()
+                            && inspector.is_none()
+                            && invocation_picker.is_none()
+                            && boot_picker.is_none()
+                            && confirmation.is_none()
+                            && palette.is_none() =>
+                    {
+                        let areas = list_hit_areas(terminal.get_frame().area(), &rows, &config);
+                        let mouse_cmd = match map_mouse(
+                            mouse,
+                            view_mode,
+                            &areas,
+                            list_table_state.offset(),
+                            rows.len(),
+                            &mut clicks,
+                            Instant::now(),
+                        ) {
+                            Some(MouseCommand::Select(idx)) => {
+                                if idx != selected_idx {
+                                    cancel_pending_action_resolution(
+                                        &mut action_resolution_worker_rx,
+                                        &list_status_line,
+                                        list_status_line_overrides_stale,
+                                        &mut status_line,
+                                        &mut status_line_overrides_stale,
+                                    );
+                                    selected_idx = idx;
+                                }
+                                None
+                            }
+                            Some(MouseCommand::Open(idx)) => {
+                                selected_idx = idx;
+                                Some(UiCommand::OpenDetail)
+                            }
+                            Some(MouseCommand::SortBy(column)) => {
+                                if let Some(mode) = SortMode::for_column(column) {
+                                    let reverse = mode == config.sort_mode && !config.sort_reverse;
+                                    refresh_requested |= apply_sort_change(
+                                        &mut config,
+                                        mode,
+                                        reverse,
+                                        &mut rows,
+                                        &mut selected_idx,
+                                    );
+                                    row_index_by_key = index_rows(&rows);
+                                }
+                                None
+                            }
+                            Some(MouseCommand::ScrollDown) => Some(UiCommand::MoveDown),
+                            Some(MouseCommand::ScrollUp) => Some(UiCommand::MoveUp),
+                            None => None,
+                        };
+                        (None, mouse_cmd)
+                    }
+                    _ => (None, None),
+                };
+                // The open palette takes every key; its commands that also have a
+                // key binding run through the regular dispatch below.
+                let mut palette_cmd = None;
+                if let Some(code) = key
+                    && let Some(state) = palette.as_mut()
+                {
+                    key = None;
+                    match code {
+                        KeyCode::Esc => palette = None,
+                        KeyCode::Backspace if state.input.is_empty() => palette = None,
+                        KeyCode::Backspace => state.pop(),
+                        KeyCode::Tab if state.search => {
+                            detail.search.regex = !detail.search.regex;
+                            state.message = None;
+                        }
+                        KeyCode::BackTab if state.search => {
+                            detail.search.ignore_case = !detail.search.ignore_case;
+                            state.message = None;
+                        }
+                        KeyCode::Enter if state.search => {
+                            match detail
+                                .search
+                                .start(&state.input, &detail.logs, detail.scroll)
+                            {
+                                Ok(()) => {
+                                    let area = terminal.get_frame().area();
+                                    detail.reveal_search_match(
+                                        &log_line_heights(area, &detail),
+                                        viewport_rows(area, ViewMode::Detail, &config),
+                                        log_message_columns(area, &detail),
+                                    );
+                                    palette = None;
+                                }
+                                Err(err) => state.message = Some(err.to_string()),
+                            }
+                        }
+                        KeyCode::Tab => {
+                            let units: Vec<&str> =
+                                rows.iter().map(|row| row.unit.as_str()).collect();
+                            complete_palette_input(state, &units);
+                        }
+                        KeyCode::Enter => match parse_palette_command(&state.input) {
+                            Ok(command) => palette_cmd = Some(command),
+                            Err(err) => state.message = Some(err.to_string()),
+                        },
+                        KeyCode::Char(c) => state.push(c),
+                        _ => {}
+                    }
+                }
+                if let Some(command) = palette_cmd {
+                    let mut error = None;
+                    match command {
+                        PaletteCommand::Filter { load, active, sub } => {
+                            if apply_filter_change(&mut config, load, active, sub) {
+                                cancel_pending_action_resolution(
+                                    &mut action_resolution_worker_rx,
+                                    &list_status_line,
+                                    list_status_line_overrides_stale,
+                                    &mut status_line,
+                                    &mut status_line_overrides_stale,
+                                );
+                                queued_action_refresh_deadline = None;
+                                refresh_requested = true;
+                            }
+                        }
+                        PaletteCommand::Sort(mode) => {
+                            let (mode, reverse) = match mode {
+                                Some(mode) => (mode, false),
+                                None => (config.sort_mode, !config.sort_reverse),
+                            };
+                            refresh_requested |= apply_sort_change(
+                                &mut config,
+                                mode,
+                                reverse,
+                                &mut rows,
+                                &mut selected_idx,
+                            );
+                            row_index_by_key = index_rows(&rows);
+                        }
+                        PaletteCommand::Scope(scope) => {
+                            match apply_scope_selection(&mut config, scope) {
+                                Ok(true) => {
+                                    cancel_pending_action_resolution(
+                                        &mut action_resolution_worker_rx,
+                                        &list_status_line,
+                                        list_status_line_overrides_stale,
+                                        &mut status_line,
+                                        &mut status_line_overrides_stale,
+                                    );
+                                    // Results from the previous scope are discarded.
+                                    worker_rx = None;
+                                    phase = LoadPhase::Idle;
+                                    rows.clear();
+                                    row_index_by_key.clear();
+                                    selected_idx = 0;
+                                    loaded_once = false;
+                                    last_load_error = false;
+                                    last_load_error_message = None;
+                                    queued_action_refresh_deadline = None;
+                                    refresh_requested = true;
+                                }
+                                Ok(false) => {}
+                                Err(err) => error = Some(err.to_string()),
+                            }
+                        }
+                        PaletteCommand::Goto(text) => match find_unit(&rows, &text) {
+                            Some(idx) => {
+                                if idx != selected_idx {
+                                    cancel_pending_action_resolution(
+                                        &mut action_resolution_worker_rx,
+                                        &list_status_line,
+                                        list_status_line_overrides_stale,
+                                        &mut status_line,
+                                        &mut status_line_overrides_stale,
+                                    );
+                                    selected_idx = idx;
+                                }
+                                view_mode = ViewMode::List;
+                            }
+                            None => error = Some(format!("no unit matches {text}")),
+                        },
+                        PaletteCommand::Action(action, unit) => {
+                            match palette_action_request(&rows, action, &unit) {
+                                Ok((idx, request)) => {
+                                    cancel_pending_action_resolution(
+                                        &mut action_resolution_worker_rx,
+                                        &list_status_line,
+                                        list_status_line_overrides_stale,
+                                        &mut status_line,
+                                        &mut status_line_overrides_stale,
+                                    );
+                                    // Results only apply to the selected row in the list.
+                                    selected_idx = idx;
+                                    view_mode = ViewMode::List;
+                                    set_status_line(
+                                        &mut status_line,
+                                        &mut status_line_overrides_stale,
+                                        action_resolution_status_text(rows.len(), request.unit()),
+                                        true,
+                                    );
+                                    action_resolution_worker_rx =
+                                        Some(spawn_action_resolution_worker(
+                                            &config.for_scope(rows[idx].scope),
+                                            request,
+                                        ));
+                                }
+                                Err(err) => error = Some(err.to_string()),
+                            }
+                        }
+                        PaletteCommand::Ui(cmd) => direct_cmd = Some(cmd),
+                    }
+                    match error {
+                        Some(error) => {
+                            if let Some(state) = palette.as_mut() {
+                                state.message = Some(error);
+                            }
+                        }
+                        None => palette = None,
+                    }
+                }
+                if let Some(code) = key
+                    && let Some(scroll) = help_scroll.as_mut()
+                {
+                    match map_help_key(&key_bindings, &mut pending_keys, code) {
+                        Some(UiCommand::MoveDown) => {
+                            *scroll = std::cmp::min(
+                                *scroll + 1,
+                                key_hints.help_lines.len().saturating_sub(1),
+                            );
+                        }
+                        Some(UiCommand::MoveUp) => *scroll = scroll.saturating_sub(1),
+                        Some(UiCommand::Cancel) => help_scroll = None,
+                        _ => {}
+                    }
+                } else if let Some(code) = key
+                    && let Some(open) = inspector.as_mut()
+                {
+                    match map_inspector_key(&key_bindings, &mut pending_keys, code) {
+                        Some(UiCommand::MoveDown) => {
+                            open.scroll = std::cmp::min(
+                                open.scroll + 1,
+                                inspector_lines(open).len().saturating_sub(1),
+                            );
+                        }
+                        Some(UiCommand::MoveUp) => open.scroll = open.scroll.saturating_sub(1),
+                        Some(UiCommand::Cancel) => {
+                            inspector = None;
+                            entry_worker_rx = None;
+                        }
+                        _ => {}
+                    }
+                } else if let Some(code) = key
+                    && let Some(picker) = invocation_picker.as_mut()
+                {
+                    match map_invocation_picker_key(&key_bindings, &mut pending_keys, code) {
+                        Some(UiCommand::MoveDown) => picker.move_down(),
+                        Some(UiCommand::MoveUp) => picker.move_up(),
+                        Some(UiCommand::Confirm) => {
+                            if let Some(first) = picker.selected_first() {
+                                detail.scroll = first;
+                                detail.cursor = first;
+                            }
+                            invocation_picker = None;
+                        }
+                        Some(UiCommand::Cancel) => invocation_picker = None,
+                        _ => {}
+                    }
+                } else if let Some(code) = key
+                    && let Some(picker) = boot_picker.as_mut()
+                {
+                    match map_boot_picker_key(&key_bindings, &mut pending_keys, code) {
+                        Some(UiCommand::MoveDown) => picker.move_down(),
+                        Some(UiCommand::MoveUp) => picker.move_up(),
+                        Some(UiCommand::Confirm) => {
+                            if let Some(boot) = picker.selected_boot() {
+                                boot_picker = None;
+                                if apply_boot_selection(&mut config.boot, boot) {
+                                    // Previews of the previous boot are discarded.
+                                    worker_rx = None;
+                                    phase = LoadPhase::Idle;
+                                    refresh_requested = true;
+                                    if let Some(request_id) = detail.refresh() {
+                                        detail_worker_rx = Some(spawn_detail_refresh_worker(
+                                            &config, &detail, request_id,
+                                        ));
+                                    }
+                                }
+                            }
+                        }
+                        Some(UiCommand::Cancel) => {
+                            boot_picker = None;
+                            boot_worker_rx = None;
+                        }
+                        _ => {}
+                    }
+                } else if let Some(code) = key
+                    && let Some(picker) = machine_picker.as_mut()
+                {
+                    match map_machine_picker_key(&key_bindings, &mut pending_keys, code) {
+                        Some(UiCommand::MoveDown) => picker.move_down(),
+                        Some(UiCommand::MoveUp) => picker.move_up(),
+                        Some(UiCommand::Confirm) => {
+                            if let Some(machine) = picker.selected_machine() {
+                                machine_picker = None;
+                                if apply_machine_selection(&mut config.target, machine) {
+                                    // Results from the previous machine are discarded.
+                                    worker_rx = None;
+                                    phase = LoadPhase::Idle;
+                                    rows.clear();
+                                    row_index_by_key.clear();
+                                    selected_idx = 0;
+                                    loaded_once = false;
+                                    last_load_error = false;
+                                    last_load_error_message = None;
+                                    queued_action_refresh_deadline = None;
+                                    refresh_requested = true;
+                                }
+                            }
+                        }
+                        Some(UiCommand::Cancel) => {
+                            machine_picker = None;
+                            machine_worker_rx = None;
+                        }
+                        _ => {}
+                    }
+                } else if let Some(code) = key
+                    && let Some(cmd) = confirmation.as_ref().and_then(|pending| {
+                        map_confirmation_key(&key_bindings, &mut pending_keys, pending.kind, code)
+                    })
                 {
                     match cmd {
                         UiCommand::Confirm => {
@@ -625,11 +1247,13 @@ pub fn run() -> Result<()> {
                             {
                                 run_confirmed_action(
                                     &mut terminal,
-                                    config.scope,
+                                    &config.for_scope(pending.scope).target,
+                                    &config.root,
                                     &pending.unit,
                                     action,
                                     config.debug_tui,
                                     rows.len(),
+                                    &key_hints,
                                     &mut status_line,
                                     &mut status_line_overrides_stale,
                                     &mut refresh_requested,
@@ -641,11 +1265,13 @@ pub fn run() -> Result<()> {
                             if let Some(pending) = confirmation.take() {
                                 run_confirmed_action(
                                     &mut terminal,
-                                    config.scope,
+                                    &config.for_scope(pending.scope).target,
+                                    &config.root,
                                     &pending.unit,
                                     UnitAction::Restart,
                                     config.debug_tui,
                                     rows.len(),
+                                    &key_hints,
                                     &mut status_line,
                                     &mut status_line_overrides_stale,
                                     &mut refresh_requested,
@@ -657,11 +1283,13 @@ pub fn run() -> Result<()> {
                             if let Some(pending) = confirmation.take() {
                                 run_confirmed_action(
                                     &mut terminal,
-                                    config.scope,
+                                    &config.for_scope(pending.scope).target,
+                                    &config.root,
                                     &pending.unit,
                                     UnitAction::Stop,
                                     config.debug_tui,
                                     rows.len(),
+                                    &key_hints,
                                     &mut status_line,
                                     &mut status_line_overrides_stale,
                                     &mut refresh_requested,
@@ -681,7 +1309,11 @@ pub fn run() -> Result<()> {
                         _ => {}
                     }
                 } else if confirmation.is_none()
-                    && let Some(cmd) = map_key(view_mode, k.code)
+                    && let Some(cmd) = direct_cmd.or_else(|| {
+                        key.and_then(|code| {
+                            map_key(&key_bindings, &mut pending_keys, view_mode, code)
+                        })
+                    })
                 {
                     match cmd {
                         UiCommand::Quit => break,
@@ -700,11 +1332,8 @@ pub fn run() -> Result<()> {
                                 && !detail.loading
                                 && let Some(request_id) = detail.refresh()
                             {
-                                detail_worker_rx = Some(spawn_detail_worker(
-                                    &config,
-                                    detail.unit.clone(),
-                                    request_id,
-                                ));
+                                detail_worker_rx =
+                                    Some(spawn_detail_refresh_worker(&config, &detail, request_id));
                             }
                         }
                         UiCommand::MoveDown => match view_mode {
@@ -723,10 +1352,12 @@ pub fn run() -> Result<()> {
                                 }
                             }
                             ViewMode::Detail => {
-                                if !detail.logs.is_empty() {
-                                    detail.scroll =
-                                        std::cmp::min(detail.scroll + 1, detail.logs.len() - 1);
-                                }
+                                let area = terminal.get_frame().area();
+                                detail.move_cursor(
+                                    true,
+                                    &log_line_heights(area, &detail),
+                                    viewport_rows(area, view_mode, &config),
+                                );
                             }
                         },
                         UiCommand::MoveUp => match view_mode {
@@ -742,8 +1373,57 @@ pub fn run() -> Result<()> {
                                 }
                                 selected_idx = selected_idx.saturating_sub(1);
                             }
-                            ViewMode::Detail => detail.scroll = detail.scroll.saturating_sub(1),
+                            ViewMode::Detail => {
+                                let area = terminal.get_frame().area();
+                                detail.move_cursor(
+                                    false,
+                                    &log_line_heights(area, &detail),
+                                    viewport_rows(area, view_mode, &config),
+                                );
+                            }
                         },
+                        UiCommand::PageDown
+                        | UiCommand::PageUp
+                        | UiCommand::HalfPageDown
+                        | UiCommand::HalfPageUp
+                        | UiCommand::JumpTop
+                        | UiCommand::JumpBottom => {
+                            let page =
+                                viewport_rows(terminal.get_frame().area(), view_mode, &config);
+                            match view_mode {
+                                ViewMode::List => {
+                                    if !rows.is_empty() {
+                                        let target =
+                                            jump_target(selected_idx, rows.len() - 1, cmd, page);
+                                        if target != selected_idx {
+                                            cancel_pending_action_resolution(
+                                                &mut action_resolution_worker_rx,
+                                                &list_status_line,
+                                                list_status_line_overrides_stale,
+                                                &mut status_line,
+                                                &mut status_line_overrides_stale,
+                                            );
+                                            selected_idx = target;
+                                        }
+                                    }
+                                }
+                                ViewMode::Detail => {
+                                    let heights =
+                                        log_line_heights(terminal.get_frame().area(), &detail);
+                                    let previous = detail.scroll;
+                                    detail.scroll =
+                                        detail_scroll_target(previous, &heights, cmd, page);
+                                    detail.cursor = detail_cursor_target(
+                                        detail.cursor,
+                                        previous,
+                                        detail.scroll,
+                                        &heights,
+                                        cmd,
+                                        page,
+                                    );
+                                }
+                            }
+                        }
                         UiCommand::OpenDetail => {
                             if let Some(row) = rows.get(selected_idx) {
                                 cancel_pending_action_resolution(
@@ -753,9 +1433,9 @@ pub fn run() -> Result<()> {
                                     &mut status_line,
                                     &mut status_line_overrides_stale,
                                 );
-                                let request_id = detail.begin_for_unit(row.unit.clone());
+                                let request_id = detail.begin_for_unit(row.scope, row.unit.clone());
                                 detail_worker_rx = Some(spawn_detail_worker(
-                                    &config,
+                                    &config.for_scope(detail.scope),
                                     detail.unit.clone(),
                                     request_id,
                                 ));
@@ -772,11 +1452,8 @@ pub fn run() -> Result<()> {
                                 && !detail.loading
                                 && let Some(request_id) = detail.refresh()
                             {
-                                detail_worker_rx = Some(spawn_detail_worker(
-                                    &config,
-                                    detail.unit.clone(),
-                                    request_id,
-                                ));
+                                detail_worker_rx =
+                                    Some(spawn_detail_refresh_worker(&config, &detail, request_id));
                             }
                         }
                         UiCommand::RequestStartStop => {
@@ -790,7 +1467,7 @@ pub fn run() -> Result<()> {
                                     true,
                                 );
                                 action_resolution_worker_rx = Some(spawn_action_resolution_worker(
-                                    &config,
+                                    &config.for_scope(row.scope),
                                     ActionResolutionRequest::StartStop {
                                         unit: row.unit.clone(),
                                     },
@@ -808,13 +1485,152 @@ pub fn run() -> Result<()> {
                                     true,
                                 );
                                 action_resolution_worker_rx = Some(spawn_action_resolution_worker(
-                                    &config,
+                                    &config.for_scope(row.scope),
                                     ActionResolutionRequest::EnableDisable {
                                         unit: row.unit.clone(),
                                     },
                                 ));
                             }
                         }
+                        UiCommand::CycleSort | UiCommand::ReverseSort => {
+                            let (mode, reverse) = if cmd == UiCommand::CycleSort {
+                                (config.sort_mode.next(), config.sort_reverse)
+                            } else {
+                                (config.sort_mode, !config.sort_reverse)
+                            };
+                            refresh_requested |= apply_sort_change(
+                                &mut config,
+                                mode,
+                                reverse,
+                                &mut rows,
+                                &mut selected_idx,
+                            );
+                            row_index_by_key = index_rows(&rows);
+                        }
+                        UiCommand::ToggleAutoRefresh => auto_refresh.toggle(Instant::now()),
+                        UiCommand::ToggleHelp => help_scroll = Some(0),
+                        UiCommand::OpenPalette => palette = Some(PaletteState::default()),
+                        UiCommand::CycleSplit => {
+                            config.split = config.split.next();
+                            if !config.split.is_split() {
+                                log_pane.reset();
+                            }
+                        }
+                        UiCommand::OpenSearch => palette = Some(PaletteState::search()),
+                        UiCommand::NextMatch | UiCommand::PreviousMatch => {
+                            let found = if cmd == UiCommand::NextMatch {
+                                detail.search.next_match()
+                            } else {
+                                detail.search.previous_match()
+                            };
+                            if found.is_some() {
+                                let area = terminal.get_frame().area();
+                                detail.reveal_search_match(
+                                    &log_line_heights(area, &detail),
+                                    viewport_rows(area, ViewMode::Detail, &config),
+                                    log_message_columns(area, &detail),
+                                );
+                            }
+                        }
+                        UiCommand::InspectEntry => {
+                            if matches!(view_mode, ViewMode::Detail)
+                                && let Some(entry) = detail.entry_at_cursor()
+                            {
+                                inspector = Some(InspectorState::loading(entry));
+                                entry_worker_rx = Some(spawn_entry_worker(
+                                    &config.for_scope(detail.scope),
+                                    entry.clone(),
+                                ));
+                            }
+                        }
+                        UiCommand::NextInvocation => {
+                            detail.next_invocation();
+                        }
+                        UiCommand::PreviousInvocation => {
+                            detail.previous_invocation();
+                        }
+                        UiCommand::OpenInvocationPicker => {
+                            if matches!(view_mode, ViewMode::Detail) && !detail.is_timeline() {
+                                invocation_picker =
+                                    Some(InvocationPickerState::for_detail(&detail));
+                            }
+                        }
+                        UiCommand::ToggleWrap => detail.toggle_wrap(),
+                        UiCommand::ScrollLeft => detail.scroll_left(HSCROLL_STEP),
+                        UiCommand::ScrollRight => detail.scroll_right(HSCROLL_STEP),
+                        UiCommand::RequestPreset => {
+                            if action_resolution_worker_rx.is_none()
+                                && let Some(row) = rows.get(selected_idx)
+                            {
+                                set_status_line(
+                                    &mut status_line,
+                                    &mut status_line_overrides_stale,
+                                    action_resolution_status_text(rows.len(), &row.unit),
+                                    true,
+                                );
+                                action_resolution_worker_rx = Some(spawn_action_resolution_worker(
+                                    &config.for_scope(row.scope),
+                                    ActionResolutionRequest::Preset {
+                                        unit: row.unit.clone(),
+                                    },
+                                ));
+                            }
+                        }
+                        UiCommand::OpenMachinePicker => {
+                            if matches!(view_mode, ViewMode::List) {
+                                cancel_pending_action_resolution(
+                                    &mut action_resolution_worker_rx,
+                                    &list_status_line,
+                                    list_status_line_overrides_stale,
+                                    &mut status_line,
+                                    &mut status_line_overrides_stale,
+                                );
+                                machine_picker = Some(MachinePickerState::loading(
+                                    config.target.machine.clone(),
+                                ));
+                                machine_worker_rx = Some(spawn_machine_list_worker(&config));
+                            }
+                        }
+                        UiCommand::OpenBootPicker => {
+                            cancel_pending_action_resolution(
+                                &mut action_resolution_worker_rx,
+                                &list_status_line,
+                                list_status_line_overrides_stale,
+                                &mut status_line,
+                                &mut status_line_overrides_stale,
+                            );
+                            boot_picker = Some(BootPickerState::loading(config.boot.clone()));
+                            boot_worker_rx = Some(spawn_boot_list_worker(&config));
+                        }
+                        UiCommand::ToggleMark => {
+                            if matches!(view_mode, ViewMode::List)
+                                && let Some(row) = rows.get_mut(selected_idx)
+                            {
+                                row.marked = !row.marked;
+                            }
+                        }
+                        UiCommand::OpenTimeline => {
+                            if matches!(view_mode, ViewMode::List)
+                                && let Some((scope, units, skipped)) =
+                                    timeline_units(&rows, selected_idx)
+                            {
+                                cancel_pending_action_resolution(
+                                    &mut action_resolution_worker_rx,
+                                    &list_status_line,
+                                    list_status_line_overrides_stale,
+                                    &mut status_line,
+                                    &mut status_line_overrides_stale,
+                                );
+                                let request_id =
+                                    detail.begin_for_timeline(scope, units.clone(), skipped);
+                                detail_worker_rx = Some(spawn_timeline_worker(
+                                    &config.for_scope(scope),
+                                    units,
+                                    request_id,
+                                ));
+                                view_mode = ViewMode::Detail;
+                            }
+                        }
                         UiCommand::Confirm
                         | UiCommand::Cancel
                         | UiCommand::ChooseRestart
@@ -839,21 +1655,31 @@ pub fn run() -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::input::UiCommand;
-    use super::state::{list_status_text, stale_status_text};
+    use super::render::wrapped_line_counts;
+    use super::state::{
+        KeyHints, detail_cursor_target, detail_scroll_target, jump_target,
+        list_status_with_scope_error_text, stale_status_text,
+    };
     use super::{
-        ActionResolutionUiState, UNIT_ACTION_REFRESH_DELAY, activate_queued_action_refresh,
-        apply_action_resolution_msg, apply_confirmed_action_result,
-        cancel_pending_action_resolution, defer_queued_action_refresh, restore_list_status_line,
-        set_list_status_line, set_status_line,
+        ActionResolutionUiState, HSCROLL_STEP, UNIT_ACTION_REFRESH_DELAY,
+        activate_queued_action_refresh, apply_action_resolution_msg, apply_boot_selection,
+        apply_confirmed_action_result, apply_filter_change, apply_machine_selection,
+        apply_scope_selection, apply_sort_change, cancel_pending_action_resolution,
+        defer_queued_action_refresh, restore_list_status_line, set_list_status_line,
+        set_status_line,
     };
-    use crate::rows::preserve_selection;
+    use crate::rows::{apply_log_preview, preserve_selection, timeline_units};
     use crate::types::{
-        ConfirmationState, DetailState, LoadPhase, UnitAction, UnitRow, ViewMode, WorkerMsg,
+        ConfirmationState, DetailState, LoadPhase, LogPreview, Scope, SortMode, UnitAction,
+        UnitKey, UnitRow, ViewMode, WorkerMsg,
     };
     use ratatui::prelude::Style;
     use std::collections::HashMap;
     use std::time::{Duration, Instant};
 
+    const TEST_VIEWPORT_ROWS: usize = 4;
+    const TEST_LOG_WIDTH: u16 = 10;
+
     struct TestUiState {
         view_mode: ViewMode,
         rows: Vec<UnitRow>,
@@ -873,11 +1699,17 @@ mod tests {
             dot: '.',
             dot_style: Style::default(),
             unit: unit.to_string(),
+            scope: Scope::System,
             load: "loaded".to_string(),
             active: "active".to_string(),
             sub: "running".to_string(),
             description: "x".to_string(),
             last_log: String::new(),
+            last_log_usec: None,
+            changed_at: None,
+            changed_since_launch: false,
+            marked: false,
+            props: Default::default(),
         }
     }
 
@@ -918,10 +1750,8 @@ mod tests {
                     }
                 }
                 ViewMode::Detail => {
-                    if !state.detail.logs.is_empty() {
-                        state.detail.scroll =
-                            std::cmp::min(state.detail.scroll + 1, state.detail.logs.len() - 1);
-                    }
+                    let heights = wrapped_line_counts(&state.detail, TEST_LOG_WIDTH);
+                    state.detail.move_cursor(true, &heights, TEST_VIEWPORT_ROWS);
                 }
             },
             UiCommand::MoveUp => match state.view_mode {
@@ -937,7 +1767,53 @@ mod tests {
                     }
                     state.selected_idx = state.selected_idx.saturating_sub(1);
                 }
-                ViewMode::Detail => state.detail.scroll = state.detail.scroll.saturating_sub(1),
+                ViewMode::Detail => {
+                    let heights = wrapped_line_counts(&state.detail, TEST_LOG_WIDTH);
+                    state
+                        .detail
+                        .move_cursor(false, &heights, TEST_VIEWPORT_ROWS);
+                }
+            },
+            UiCommand::PageDown
+            | UiCommand::PageUp
+            | UiCommand::HalfPageDown
+            | UiCommand::HalfPageUp
+            | UiCommand::JumpTop
+            | UiCommand::JumpBottom => match state.view_mode {
+                ViewMode::List => {
+                    if !state.rows.is_empty() {
+                        let target = jump_target(
+                            state.selected_idx,
+                            state.rows.len() - 1,
+                            cmd,
+                            TEST_VIEWPORT_ROWS,
+                        );
+                        if target != state.selected_idx {
+                            cancel_pending_action_resolution(
+                                &mut state.action_resolution_active,
+                                &state.list_status_line,
+                                state.list_status_line_overrides_stale,
+                                &mut state.status_line,
+                                &mut state.status_line_overrides_stale,
+                            );
+                            state.selected_idx = target;
+                        }
+                    }
+                }
+                ViewMode::Detail => {
+                    let heights = wrapped_line_counts(&state.detail, TEST_LOG_WIDTH);
+                    let previous = state.detail.scroll;
+                    state.detail.scroll =
+                        detail_scroll_target(previous, &heights, cmd, TEST_VIEWPORT_ROWS);
+                    state.detail.cursor = detail_cursor_target(
+                        state.detail.cursor,
+                        previous,
+                        state.detail.scroll,
+                        &heights,
+                        cmd,
+                        TEST_VIEWPORT_ROWS,
+                    );
+                }
             },
             UiCommand::OpenDetail => {
                 if let Some(r) = state.rows.get(state.selected_idx) {
@@ -948,7 +1824,31 @@ mod tests {
                         &mut state.status_line,
                         &mut state.status_line_overrides_stale,
                     );
-                    let _ = state.detail.begin_for_unit(r.unit.clone());
+                    let _ = state.detail.begin_for_unit(r.scope, r.unit.clone());
+                    state.detail_worker_active = true;
+                    state.view_mode = ViewMode::Detail;
+                }
+            }
+            UiCommand::ToggleMark => {
+                if matches!(state.view_mode, ViewMode::List)
+                    && let Some(r) = state.rows.get_mut(state.selected_idx)
+                {
+                    r.marked = !r.marked;
+                }
+            }
+            UiCommand::OpenTimeline => {
+                if matches!(state.view_mode, ViewMode::List)
+                    && let Some((scope, units, skipped)) =
+                        timeline_units(&state.rows, state.selected_idx)
+                {
+                    cancel_pending_action_resolution(
+                        &mut state.action_resolution_active,
+                        &state.list_status_line,
+                        state.list_status_line_overrides_stale,
+                        &mut state.status_line,
+                        &mut state.status_line_overrides_stale,
+                    );
+                    let _ = state.detail.begin_for_timeline(scope, units, skipped);
                     state.detail_worker_active = true;
                     state.view_mode = ViewMode::Detail;
                 }
@@ -964,78 +1864,144 @@ mod tests {
             }
             UiCommand::RequestStartStop
             | UiCommand::RequestEnableDisable
+            | UiCommand::RequestPreset
+            | UiCommand::CycleSort
+            | UiCommand::ReverseSort
+            | UiCommand::ToggleAutoRefresh
+            | UiCommand::ToggleHelp
+            | UiCommand::OpenMachinePicker
+            | UiCommand::OpenPalette
+            | UiCommand::CycleSplit
             | UiCommand::Confirm
             | UiCommand::Cancel
             | UiCommand::ChooseRestart
-            | UiCommand::ChooseStop => {}
+            | UiCommand::ChooseStop
+            | UiCommand::OpenSearch
+            | UiCommand::InspectEntry
+            | UiCommand::OpenInvocationPicker
+            | UiCommand::OpenBootPicker => {}
+            UiCommand::NextInvocation => {
+                state.detail.next_invocation();
+            }
+            UiCommand::PreviousInvocation => {
+                state.detail.previous_invocation();
+            }
+            UiCommand::NextMatch | UiCommand::PreviousMatch => {
+                let found = if cmd == UiCommand::NextMatch {
+                    state.detail.search.next_match()
+                } else {
+                    state.detail.search.previous_match()
+                };
+                if found.is_some() {
+                    let heights = wrapped_line_counts(&state.detail, TEST_LOG_WIDTH);
+                    state.detail.reveal_search_match(
+                        &heights,
+                        TEST_VIEWPORT_ROWS,
+                        usize::from(TEST_LOG_WIDTH),
+                    );
+                }
+            }
+            UiCommand::ToggleWrap => state.detail.toggle_wrap(),
+            UiCommand::ScrollLeft => state.detail.scroll_left(HSCROLL_STEP),
+            UiCommand::ScrollRight => state.detail.scroll_right(HSCROLL_STEP),
         }
         false
     }
 
     struct ListWorkerTestState {
         rows: Vec<UnitRow>,
-        row_index_by_unit: HashMap<String, usize>,
+        row_index_by_key: HashMap<UnitKey, usize>,
         selected_idx: usize,
         loaded_once: bool,
         phase: LoadPhase,
         status_line: String,
         last_load_error: bool,
         last_load_error_message: Option<String>,
+        scope_error: Option<String>,
     }
 
     fn apply_list_worker_msg(state: &mut ListWorkerTestState, msg: WorkerMsg) -> bool {
         match msg {
+            WorkerMsg::ScopeError(e) => {
+                state.scope_error = Some(e);
+                false
+            }
             WorkerMsg::UnitsLoaded(new_rows) => {
                 state.loaded_once = true;
                 state.last_load_error = false;
                 state.last_load_error_message = None;
-                let previous_selected = state.rows.get(state.selected_idx).map(|r| r.unit.clone());
+                let previous_selected = state.rows.get(state.selected_idx).map(UnitRow::key);
                 state.rows = new_rows;
-                state.row_index_by_unit = state
+                state.row_index_by_key = state
                     .rows
                     .iter()
                     .enumerate()
-                    .map(|(idx, row)| (row.unit.clone(), idx))
+                    .map(|(idx, row)| (row.key(), idx))
                     .collect();
                 preserve_selection(previous_selected, &state.rows, &mut state.selected_idx);
                 if state.rows.is_empty() {
-                    state.status_line = list_status_text(0, None);
+                    state.status_line = list_status_with_scope_error_text(
+                        0,
+                        None,
+                        state.scope_error.as_deref(),
+                        &KeyHints::default(),
+                    );
                     state.phase = LoadPhase::Idle;
                 } else {
-                    state.status_line =
-                        list_status_text(state.rows.len(), Some((0, state.rows.len())));
+                    state.status_line = list_status_with_scope_error_text(
+                        state.rows.len(),
+                        Some((0, state.rows.len())),
+                        state.scope_error.as_deref(),
+                        &KeyHints::default(),
+                    );
                     state.phase = LoadPhase::FetchingLogs;
                 }
                 false
             }
             WorkerMsg::LogsProgress { done, total, logs } => {
                 for (unit, log) in logs {
-                    if let Some(idx) = state.row_index_by_unit.get(&unit).copied()
+                    if let Some(idx) = state.row_index_by_key.get(&unit).copied()
                         && let Some(row) = state.rows.get_mut(idx)
                     {
-                        row.last_log = log;
+                        apply_log_preview(row, log, Instant::now());
                     }
                 }
-                state.status_line = list_status_text(state.rows.len(), Some((done, total)));
+                state.status_line = list_status_with_scope_error_text(
+                    state.rows.len(),
+                    Some((done, total)),
+                    state.scope_error.as_deref(),
+                    &KeyHints::default(),
+                );
                 state.phase = LoadPhase::FetchingLogs;
                 false
             }
             WorkerMsg::Finished => {
                 state.phase = LoadPhase::Idle;
-                state.status_line = list_status_text(state.rows.len(), None);
+                state.status_line = list_status_with_scope_error_text(
+                    state.rows.len(),
+                    None,
+                    state.scope_error.as_deref(),
+                    &KeyHints::default(),
+                );
                 true
             }
             WorkerMsg::Error(e) => {
                 state.last_load_error = true;
                 state.last_load_error_message = Some(e);
-                state.status_line = stale_status_text(state.rows.len());
+                state.status_line = stale_status_text(state.rows.len(), &KeyHints::default());
                 state.phase = LoadPhase::Idle;
                 true
             }
             WorkerMsg::DetailLogsLoaded { .. }
             | WorkerMsg::DetailLogsError { .. }
             | WorkerMsg::ActionConfirmationReady { .. }
-            | WorkerMsg::ActionResolutionError { .. } => false,
+            | WorkerMsg::ActionResolutionError { .. }
+            | WorkerMsg::MachinesLoaded(_)
+            | WorkerMsg::MachinesError(_)
+            | WorkerMsg::BootsLoaded(_)
+            | WorkerMsg::BootsError(_)
+            | WorkerMsg::EntryLoaded(_)
+            | WorkerMsg::EntryError(_) => false,
         }
     }
 
@@ -1044,6 +2010,197 @@ mod tests {
         assert!(super::run().is_ok());
     }
 
+    #[test]
+    fn apply_command_pages_and_jumps_through_list_and_detail() {
+        let mut state = TestUiState {
+            view_mode: ViewMode::List,
+            rows: (0..10).map(|idx| row(&format!("u{idx}.service"))).collect(),
+            selected_idx: 0,
+            detail: DetailState::default(),
+            detail_worker_active: false,
+            action_resolution_active: Some(()),
+            refresh_requested: false,
+            list_status_line: "services: 10".to_string(),
+            list_status_line_overrides_stale: false,
+            status_line: "resolving action for u0.service".to_string(),
+            status_line_overrides_stale: true,
+        };
+        assert!(!apply_command(&mut state, UiCommand::PageDown));
+        assert_eq!(state.selected_idx, 4);
+        assert!(state.action_resolution_active.is_none());
+        assert!(!apply_command(&mut state, UiCommand::HalfPageDown));
+        assert_eq!(state.selected_idx, 6);
+        assert!(!apply_command(&mut state, UiCommand::JumpBottom));
+        assert_eq!(state.selected_idx, 9);
+        assert!(!apply_command(&mut state, UiCommand::PageDown));
+        assert_eq!(state.selected_idx, 9);
+        assert!(!apply_command(&mut state, UiCommand::HalfPageUp));
+        assert_eq!(state.selected_idx, 7);
+        assert!(!apply_command(&mut state, UiCommand::JumpTop));
+        assert_eq!(state.selected_idx, 0);
+
+        state.view_mode = ViewMode::Detail;
+        state.detail.logs = (0..10)
+            .map(|idx| crate::types::DetailLogEntry {
+                time: String::new(),
+                log: idx.to_string(),
+                ..Default::default()
+            })
+            .collect();
+        assert!(!apply_command(&mut state, UiCommand::JumpBottom));
+        assert_eq!((state.detail.scroll, state.detail.cursor), (6, 9));
+        assert!(!apply_command(&mut state, UiCommand::PageUp));
+        assert_eq!((state.detail.scroll, state.detail.cursor), (2, 5));
+        assert!(!apply_command(&mut state, UiCommand::PageDown));
+        assert_eq!((state.detail.scroll, state.detail.cursor), (6, 9));
+        assert!(!apply_command(&mut state, UiCommand::JumpTop));
+        assert_eq!((state.detail.scroll, state.detail.cursor), (0, 0));
+
+        // Wrapped entries take several lines of the page.
+        state.detail.logs[1].log = "x".repeat(35);
+        assert!(!apply_command(&mut state, UiCommand::ScrollRight));
+        assert_eq!(state.detail.hscroll, HSCROLL_STEP);
+        assert!(!apply_command(&mut state, UiCommand::ToggleWrap));
+        assert!(state.detail.wrap);
+        assert_eq!(state.detail.hscroll, 0);
+        assert!(!apply_command(&mut state, UiCommand::PageDown));
+        assert_eq!(state.detail.scroll, 1);
+        assert!(!apply_command(&mut state, UiCommand::PageDown));
+        assert_eq!(state.detail.scroll, 2);
+        assert!(!apply_command(&mut state, UiCommand::PageDown));
+        assert_eq!(state.detail.scroll, 6);
+    }
+
+    #[test]
+    fn apply_command_jumps_between_search_matches() {
+        let mut state = TestUiState {
+            view_mode: ViewMode::Detail,
+            rows: vec![row("a.service")],
+            selected_idx: 0,
+            detail: DetailState::default(),
+            detail_worker_active: false,
+            action_resolution_active: None,
+            refresh_requested: false,
+            list_status_line: "services: 1".to_string(),
+            list_status_line_overrides_stale: false,
+            status_line: "services: 1".to_string(),
+            status_line_overrides_stale: false,
+        };
+        state.detail.logs = (0..12)
+            .map(|idx| crate::types::DetailLogEntry {
+                time: String::new(),
+                log: if idx % 5 == 1 {
+                    "hit".to_string()
+                } else {
+                    idx.to_string()
+                },
+                ..Default::default()
+            })
+            .collect();
+        state
+            .detail
+            .search
+            .start("hit", &state.detail.logs, 0)
+            .expect("search");
+        assert!(!apply_command(&mut state, UiCommand::NextMatch));
+        assert_eq!(state.detail.search.current_entry(), Some(6));
+        assert_eq!(state.detail.scroll, 6);
+        assert!(!apply_command(&mut state, UiCommand::NextMatch));
+        assert_eq!(state.detail.scroll, 11);
+        assert_eq!(
+            state.detail.search.status_text().as_deref(),
+            Some("match 3/3")
+        );
+        assert!(!apply_command(&mut state, UiCommand::NextMatch));
+        assert_eq!(state.detail.scroll, 1);
+        assert!(!apply_command(&mut state, UiCommand::PreviousMatch));
+        assert_eq!(state.detail.scroll, 11);
+        assert!(!apply_command(&mut state, UiCommand::PreviousMatch));
+        assert_eq!(state.detail.search.current_entry(), Some(6));
+        assert_eq!(state.detail.scroll, 6);
+    }
+
+    #[test]
+    fn apply_command_jumps_between_invocations() {
+        let mut state = TestUiState {
+            view_mode: ViewMode::Detail,
+            rows: vec![row("a.service")],
+            selected_idx: 0,
+            detail: DetailState::default(),
+            detail_worker_active: false,
+            action_resolution_active: None,
+            refresh_requested: false,
+            list_status_line: "services: 1".to_string(),
+            list_status_line_overrides_stale: false,
+            status_line: "services: 1".to_string(),
+            status_line_overrides_stale: false,
+        };
+        state.detail.logs = (0..9)
+            .map(|idx| crate::types::DetailLogEntry {
+                time: String::new(),
+                log: idx.to_string(),
+                invocation: Some(format!("run-{}", idx / 3)),
+                ..Default::default()
+            })
+            .collect();
+        assert!(!apply_command(&mut state, UiCommand::NextInvocation));
+        assert_eq!(state.detail.scroll, 3);
+        assert!(!apply_command(&mut state, UiCommand::NextInvocation));
+        assert_eq!(state.detail.scroll, 6);
+        assert!(!apply_command(&mut state, UiCommand::NextInvocation));
+        assert_eq!(state.detail.scroll, 6);
+        state.detail.scroll = 7;
+        assert!(!apply_command(&mut state, UiCommand::PreviousInvocation));
+        assert_eq!(state.detail.scroll, 6);
+        assert!(!apply_command(&mut state, UiCommand::PreviousInvocation));
+        assert_eq!(state.detail.scroll, 3);
+    }
+
+    #[test]
+    fn apply_command_marks_units_and_opens_their_timeline() {
+        let mut state = TestUiState {
+            view_mode: ViewMode::List,
+            rows: vec![row("web.service"), row("worker.service"), row("db.service")],
+            selected_idx: 0,
+            detail: DetailState::default(),
+            detail_worker_active: false,
+            action_resolution_active: Some(()),
+            refresh_requested: false,
+            list_status_line: "services: 3".to_string(),
+            list_status_line_overrides_stale: false,
+            status_line: "resolving".to_string(),
+            status_line_overrides_stale: true,
+        };
+        assert!(!apply_command(&mut state, UiCommand::ToggleMark));
+        state.selected_idx = 2;
+        assert!(!apply_command(&mut state, UiCommand::ToggleMark));
+        assert!(state.rows[0].marked && !state.rows[1].marked && state.rows[2].marked);
+
+        assert!(!apply_command(&mut state, UiCommand::OpenTimeline));
+        assert!(matches!(state.view_mode, ViewMode::Detail));
+        assert!(state.detail_worker_active);
+        assert!(state.action_resolution_active.is_none());
+        assert_eq!(state.status_line, "services: 3");
+        assert_eq!(state.detail.units, vec!["web.service", "db.service"]);
+        assert!(state.detail.unit.is_empty());
+        // Runs are per unit, so run navigation does nothing in a timeline.
+        state.detail.logs = vec![
+            crate::types::DetailLogEntry {
+                invocation: Some("run".to_string()),
+                ..Default::default()
+            };
+            2
+        ];
+        state.detail.logs[1].invocation = Some("other".to_string());
+        assert!(!state.detail.next_invocation());
+        assert_eq!(state.detail.scroll, 0);
+
+        // Opening one unit leaves the timeline.
+        state.view_mode = ViewMode::List;
+        assert!(!apply_command(&mut state, UiCommand::OpenDetail));
+        assert!(!state.detail.is_timeline());
+    }
+
     #[test]
     fn apply_command_covers_list_and_detail_transitions() {
         let mut state = TestUiState {
@@ -1081,20 +2238,30 @@ mod tests {
     fn apply_list_worker_msg_covers_all_variants() {
         let mut state = ListWorkerTestState {
             rows: vec![row("a.service")],
-            row_index_by_unit: HashMap::from([(String::from("a.service"), 0usize)]),
+            row_index_by_key: HashMap::from([((Scope::System, String::from("a.service")), 0usize)]),
             selected_idx: 0,
             loaded_once: false,
             phase: LoadPhase::Idle,
             status_line: String::new(),
             last_load_error: false,
             last_load_error_message: None,
+            scope_error: None,
         };
 
+        assert!(!apply_list_worker_msg(
+            &mut state,
+            WorkerMsg::ScopeError("user scope: no bus".to_string()),
+        ));
         assert!(!apply_list_worker_msg(
             &mut state,
             WorkerMsg::UnitsLoaded(vec![row("x.service")]),
         ));
         assert!(state.loaded_once);
+        assert!(
+            state
+                .status_line
+                .contains("failed to list user scope: no bus")
+        );
         assert!(matches!(state.phase, LoadPhase::FetchingLogs));
 
         assert!(!apply_list_worker_msg(
@@ -1102,10 +2269,17 @@ mod tests {
             WorkerMsg::LogsProgress {
                 done: 1,
                 total: 1,
-                logs: vec![(String::from("x.service"), String::from("ok"))],
+                logs: vec![(
+                    (Scope::System, String::from("x.service")),
+                    LogPreview {
+                        message: String::from("ok"),
+                        usec: Some(7),
+                    }
+                )],
             },
         ));
         assert_eq!(state.rows[0].last_log, "ok");
+        assert_eq!(state.rows[0].last_log_usec, Some(7));
 
         assert!(apply_list_worker_msg(&mut state, WorkerMsg::Finished));
         assert!(matches!(state.phase, LoadPhase::Idle));
@@ -1178,6 +2352,7 @@ mod tests {
                 list_status_line: &list_status_line,
                 list_status_line_overrides_stale: false,
                 rows_len: 2,
+                hints: &KeyHints::default(),
                 view_mode: ViewMode::List,
                 selected_unit: Some("demo.service"),
             },
@@ -1203,6 +2378,7 @@ mod tests {
                 list_status_line: &list_status_line,
                 list_status_line_overrides_stale: false,
                 rows_len: 2,
+                hints: &KeyHints::default(),
                 view_mode: ViewMode::List,
                 selected_unit: Some("demo.service"),
             },
@@ -1230,6 +2406,7 @@ mod tests {
                 list_status_line: &list_status_line,
                 list_status_line_overrides_stale: false,
                 rows_len: 2,
+                hints: &KeyHints::default(),
                 view_mode: ViewMode::List,
                 selected_unit: Some("other.service"),
             },
@@ -1252,6 +2429,7 @@ mod tests {
                 list_status_line: &list_status_line,
                 list_status_line_overrides_stale: false,
                 rows_len: 2,
+                hints: &KeyHints::default(),
                 view_mode: ViewMode::Detail,
                 selected_unit: Some("demo.service"),
             },
@@ -1267,7 +2445,7 @@ mod tests {
     #[test]
     fn apply_action_resolution_msg_restores_stale_list_status_when_needed() {
         let mut confirmation = None;
-        let list_status_line = stale_status_text(2);
+        let list_status_line = stale_status_text(2, &KeyHints::default());
         let mut status_line = "resolving".to_string();
         let mut override_stale = true;
 
@@ -1279,6 +2457,7 @@ mod tests {
                 list_status_line: &list_status_line,
                 list_status_line_overrides_stale: true,
                 rows_len: 2,
+                hints: &KeyHints::default(),
                 view_mode: ViewMode::List,
                 selected_unit: Some("demo.service"),
             },
@@ -1308,6 +2487,7 @@ mod tests {
                 list_status_line: "services: 2",
                 list_status_line_overrides_stale: false,
                 rows_len: 2,
+                hints: &KeyHints::default(),
                 view_mode: ViewMode::List,
                 selected_unit: Some("demo.service"),
             },
@@ -1384,16 +2564,20 @@ mod tests {
             crate::types::DetailLogEntry {
                 time: "t1".to_string(),
                 log: "a".to_string(),
+                ..Default::default()
             },
             crate::types::DetailLogEntry {
                 time: "t2".to_string(),
                 log: "b".to_string(),
+                ..Default::default()
             },
         ];
         assert!(!apply_command(&mut state, UiCommand::MoveDown));
-        assert_eq!(state.detail.scroll, 1);
+        assert_eq!((state.detail.cursor, state.detail.scroll), (1, 0));
+        assert!(!apply_command(&mut state, UiCommand::MoveDown));
+        assert_eq!(state.detail.cursor, 1);
         assert!(!apply_command(&mut state, UiCommand::MoveUp));
-        assert_eq!(state.detail.scroll, 0);
+        assert_eq!((state.detail.cursor, state.detail.scroll), (0, 0));
         assert!(!apply_command(&mut state, UiCommand::BackToList));
         assert!(matches!(state.view_mode, ViewMode::List));
     }
@@ -1433,6 +2617,7 @@ mod tests {
             "demo.service",
             UnitAction::Restart,
             3,
+            &KeyHints::default(),
             &mut status_line,
             &mut override_stale,
             &mut refresh,
@@ -1458,6 +2643,7 @@ mod tests {
             "demo.service",
             UnitAction::Stop,
             3,
+            &KeyHints::default(),
             &mut status_line,
             &mut override_stale,
             &mut refresh,
@@ -1470,6 +2656,138 @@ mod tests {
         assert!(deadline.is_none());
     }
 
+    #[test]
+    fn apply_machine_selection_reports_only_real_changes() {
+        let mut target = crate::types::Target::new(crate::types::Scope::System);
+        assert!(!apply_machine_selection(&mut target, None));
+        assert!(apply_machine_selection(
+            &mut target,
+            Some("web".to_string())
+        ));
+        assert_eq!(target.machine.as_deref(), Some("web"));
+        assert!(!apply_machine_selection(
+            &mut target,
+            Some("web".to_string())
+        ));
+        assert!(apply_machine_selection(&mut target, None));
+        assert!(target.machine.is_none());
+    }
+
+    #[test]
+    fn apply_boot_selection_reports_only_real_changes() {
+        let mut boot = None;
+        assert!(!apply_boot_selection(&mut boot, None));
+        assert!(apply_boot_selection(&mut boot, Some("abc".to_string())));
+        assert_eq!(boot.as_deref(), Some("abc"));
+        assert!(!apply_boot_selection(&mut boot, Some("abc".to_string())));
+        assert!(apply_boot_selection(&mut boot, None));
+        assert!(boot.is_none());
+    }
+
+    #[test]
+    fn apply_filter_change_reports_only_real_changes() {
+        let mut config = crate::cli::parse_args(vec!["lsu"]).expect("default config");
+        let filters = |load: &str, active: &str, sub: &str| {
+            (load.to_string(), active.to_string(), sub.to_string())
+        };
+        let (load, active, sub) = filters("loaded", "active", "running");
+        assert!(!apply_filter_change(&mut config, load, active, sub));
+        let (load, active, sub) = filters("all", "failed", "all");
+        assert!(apply_filter_change(&mut config, load, active, sub));
+        assert_eq!(
+            (config.load_filter.as_str(), config.active_filter.as_str()),
+            ("all", "failed")
+        );
+        assert_eq!(config.sub_filter, "all");
+    }
+
+    #[test]
+    fn apply_scope_selection_switches_between_one_and_both_scopes() {
+        let mut config = crate::cli::parse_args(vec!["lsu"]).expect("default config");
+        assert!(!apply_scope_selection(&mut config, Some(Scope::System)).expect("system"));
+        assert!(apply_scope_selection(&mut config, None).expect("both"));
+        assert!(config.both_scopes);
+        assert!(!apply_scope_selection(&mut config, None).expect("both again"));
+        assert!(apply_scope_selection(&mut config, Some(Scope::User)).expect("user"));
+        assert!(!config.both_scopes);
+        assert_eq!(config.target.scope, Scope::User);
+    }
+
+    #[test]
+    fn apply_scope_selection_rejects_user_scope_on_remote_hosts() {
+        let mut config = crate::cli::parse_args(vec!["lsu", "-H", "db1"]).expect("remote config");
+        let err = apply_scope_selection(&mut config, Some(Scope::User)).expect_err("user+host");
+        assert!(
+            err.to_string()
+                .contains("--user cannot be combined with --host")
+        );
+        assert_eq!(config.target.scope, Scope::System);
+        assert!(!config.both_scopes);
+    }
+
+    #[test]
+    fn apply_scope_selection_rejects_both_scopes_on_remote_hosts() {
+        let mut config = crate::cli::parse_args(vec!["lsu", "-H", "db1"]).expect("remote config");
+        let err = apply_scope_selection(&mut config, None).expect_err("both+host");
+        assert!(
+            err.to_string()
+                .contains("--both cannot be combined with --host")
+        );
+        assert_eq!(config.target.scope, Scope::System);
+        assert!(!config.both_scopes);
+
+        let mut config = crate::cli::parse_args(vec!["lsu"]).expect("local config");
+        assert!(apply_scope_selection(&mut config, None).expect("both scopes"));
+        assert!(config.both_scopes);
+    }
+
+    #[test]
+    fn apply_sort_change_keeps_selection_and_requests_properties_once() {
+        let mut config = crate::cli::parse_args(["lsu"]).expect("defaults");
+        config.sort_mode = SortMode::Name;
+        let row = |unit: &str, memory: u64| UnitRow {
+            dot: '●',
+            dot_style: Style::default(),
+            unit: unit.to_string(),
+            scope: Scope::System,
+            load: "loaded".to_string(),
+            active: "active".to_string(),
+            sub: "running".to_string(),
+            description: String::new(),
+            last_log: String::new(),
+            last_log_usec: None,
+            changed_at: None,
+            changed_since_launch: false,
+            marked: false,
+            props: crate::types::UnitProperties {
+                memory_bytes: Some(memory),
+                ..Default::default()
+            },
+        };
+        let mut rows = vec![row("a.service", 30), row("b.service", 10)];
+        let mut selected = 0;
+
+        assert!(apply_sort_change(
+            &mut config,
+            SortMode::Memory,
+            false,
+            &mut rows,
+            &mut selected
+        ));
+        assert_eq!(rows[0].unit, "b.service");
+        assert_eq!(rows[selected].unit, "a.service");
+        assert!(!apply_sort_change(
+            &mut config,
+            SortMode::Memory,
+            true,
+            &mut rows,
+            &mut selected
+        ));
+        assert!(config.sort_reverse);
+        assert_eq!(rows[0].unit, "a.service");
+        assert_eq!(selected, 0);
+    }
+
     #[test]
     fn defer_queued_action_refresh_schedules_delayed_list_reload() {
         let mut refresh_requested = true;
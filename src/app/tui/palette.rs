@@ -0,0 +1,462 @@
+/*
+   Copyright (C) 2026 l5yth
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! `:` command palette: parsing typed commands and completing their words.
+
+use anyhow::{Result, anyhow, bail};
+
+use super::input::UiCommand;
+use crate::{
+    cli::{filter_values, validate_filter},
+    types::{ActionResolutionRequest, PaletteState, Scope, SortMode, UnitAction, UnitRow},
+};
+
+/// Palette commands that run a unit action on their argument.
+const ACTION_COMMANDS: [(&str, UnitAction); 6] = [
+    ("start", UnitAction::Start),
+    ("restart", UnitAction::Restart),
+    ("stop", UnitAction::Stop),
+    ("enable", UnitAction::Enable),
+    ("disable", UnitAction::Disable),
+    ("preset", UnitAction::Preset),
+];
+
+/// Palette commands without arguments that run a key-bound command.
+const SIMPLE_COMMANDS: [(&str, UiCommand); 5] = [
+    ("refresh", UiCommand::Refresh),
+    ("auto", UiCommand::ToggleAutoRefresh),
+    ("machine", UiCommand::OpenMachinePicker),
+    ("help", UiCommand::ToggleHelp),
+    ("quit", UiCommand::Quit),
+];
+
+const FILTER_KEYS: [&str; 3] = ["load", "active", "sub"];
+
+const SCOPES: [&str; 3] = ["system", "user", "both"];
+
+/// Usage of every palette command, as listed in the help overlay.
+pub const PALETTE_USAGE: [(&str, &str); 10] = [
+    ("filter k=v ...", "filter by load, active, or sub state"),
+    ("filter all", "show every unit"),
+    (
+        "sort <name>",
+        "sort by name, status, load, active, sub, ...",
+    ),
+    ("sort reverse", "reverse the sort order"),
+    ("scope <scope>", "list system, user, or both scopes"),
+    ("goto <text>", "select the first unit matching text"),
+    (
+        "<action> <unit>",
+        "start, restart, stop, enable, disable, preset",
+    ),
+    ("refresh, auto", "refresh now, switch auto refresh"),
+    ("machine, help", "pick a machine, show this help"),
+    ("quit", "quit"),
+];
+
+/// A command typed into the palette.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaletteCommand {
+    /// Replace the list filters; filters that are not named match every state.
+    Filter {
+        /// Unit `load` state filter.
+        load: String,
+        /// Unit `active` state filter.
+        active: String,
+        /// Unit `sub` state filter.
+        sub: String,
+    },
+    /// Sort by a column, or reverse the current order when `None`.
+    Sort(Option<SortMode>),
+    /// List units of one scope, or of both scopes when `None`.
+    Scope(Option<Scope>),
+    /// Select the first unit matching the text.
+    Goto(String),
+    /// Ask to run an action on the named unit.
+    Action(UnitAction, String),
+    /// Run a command that also has a key binding.
+    Ui(UiCommand),
+}
+
+/// Parse the palette input into a command.
+pub fn parse_palette_command(input: &str) -> Result<PaletteCommand> {
+    let mut words = input.split_whitespace();
+    let Some(name) = words.next() else {
+        bail!("type a command, e.g. filter, sort, goto, restart");
+    };
+    let args: Vec<&str> = words.collect();
+    if let Some((_, action)) = ACTION_COMMANDS.iter().find(|(n, _)| *n == name) {
+        return match args.as_slice() {
+            [unit] if unit.starts_with('-') => Err(anyhow!("unit names cannot start with '-'")),
+            [unit] => Ok(PaletteCommand::Action(*action, unit.to_string())),
+            _ => Err(anyhow!("usage: {name} <unit>")),
+        };
+    }
+    if let Some((_, command)) = SIMPLE_COMMANDS.iter().find(|(n, _)| *n == name) {
+        if !args.is_empty() {
+            bail!("{name} takes no arguments");
+        }
+        return Ok(PaletteCommand::Ui(*command));
+    }
+    match name {
+        "filter" => parse_filter(&args),
+        "sort" => match args.as_slice() {
+            ["reverse"] => Ok(PaletteCommand::Sort(None)),
+            [mode] => SortMode::from_name(mode)
+                .map(|mode| PaletteCommand::Sort(Some(mode)))
+                .ok_or_else(|| {
+                    anyhow!("unknown sort: {mode}; allowed: {}", sort_names().join(", "))
+                }),
+            _ => Err(anyhow!("usage: sort <name>|reverse")),
+        },
+        "scope" => match args.as_slice() {
+            ["system"] => Ok(PaletteCommand::Scope(Some(Scope::System))),
+            ["user"] => Ok(PaletteCommand::Scope(Some(Scope::User))),
+            ["both"] => Ok(PaletteCommand::Scope(None)),
+            _ => Err(anyhow!("usage: scope system|user|both")),
+        },
+        "goto" if args.is_empty() => Err(anyhow!("usage: goto <text>")),
+        "goto" => Ok(PaletteCommand::Goto(args.join(" "))),
+        other => Err(anyhow!("unknown command: {other}")),
+    }
+}
+
+/// Parse `filter all` or `filter key=value ...`, mirroring the `--load`/`--active`/`--sub` flags.
+fn parse_filter(args: &[&str]) -> Result<PaletteCommand> {
+    if args.is_empty() {
+        bail!("usage: filter all|load=<state> active=<state> sub=<state>");
+    }
+    let mut values = ["all"; 3];
+    if args != ["all"] {
+        for arg in args {
+            let (key, value) = arg
+                .split_once('=')
+                .ok_or_else(|| anyhow!("expected key=value, got {arg}"))?;
+            let idx = FILTER_KEYS
+                .iter()
+                .position(|known| *known == key)
+                .ok_or_else(|| anyhow!("unknown filter: {key}; allowed: load, active, sub"))?;
+            values[idx] = validate_filter(key, value)?;
+        }
+    }
+    let [load, active, sub] = values.map(str::to_string);
+    Ok(PaletteCommand::Filter { load, active, sub })
+}
+
+fn sort_names() -> Vec<&'static str> {
+    SortMode::CYCLE.iter().map(|mode| mode.name()).collect()
+}
+
+fn command_names() -> Vec<&'static str> {
+    let mut names: Vec<&str> = ["filter", "sort", "scope", "goto"]
+        .into_iter()
+        .chain(ACTION_COMMANDS.iter().map(|(name, _)| *name))
+        .chain(SIMPLE_COMMANDS.iter().map(|(name, _)| *name))
+        .collect();
+    names.sort_unstable();
+    names
+}
+
+/// Complete the word before the end of the input from commands, units, or arguments.
+///
+/// A single candidate replaces the word; several extend it to their common prefix
+/// and are listed in the palette message.
+pub fn complete_palette_input(palette: &mut PaletteState, units: &[&str]) {
+    let word_start = palette.input.rfind(' ').map_or(0, |idx| idx + 1);
+    let (head, word) = palette.input.split_at(word_start);
+    let mut previous = head.split_whitespace();
+    let options: Vec<String> = match (previous.next(), previous.next()) {
+        (None, _) => command_names().into_iter().map(String::from).collect(),
+        (Some("filter"), _) => match word.split_once('=') {
+            Some((key, _)) => filter_values(key)
+                .into_iter()
+                .map(|value| format!("{key}={value}"))
+                .collect(),
+            None => ["all", "load=", "active=", "sub="]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+        },
+        (Some("sort"), None) => sort_names()
+            .into_iter()
+            .chain(["reverse"])
+            .map(String::from)
+            .collect(),
+        (Some("scope"), None) => SCOPES.into_iter().map(String::from).collect(),
+        (Some(name), None) if name == "goto" || ACTION_COMMANDS.iter().any(|(n, _)| *n == name) => {
+            let mut units: Vec<String> = units.iter().map(|unit| unit.to_string()).collect();
+            units.sort_unstable();
+            units.dedup();
+            units
+        }
+        _ => Vec::new(),
+    };
+    let candidates: Vec<String> = options
+        .into_iter()
+        .filter(|option| option.starts_with(word))
+        .collect();
+    let completed = match candidates.as_slice() {
+        [] => {
+            palette.message = Some("no completions".to_string());
+            return;
+        }
+        [single] if single.ends_with('=') => single.clone(),
+        [single] => format!("{single} "),
+        several => {
+            palette.message = Some(several.join(" "));
+            common_prefix(several)
+        }
+    };
+    palette.input = format!("{head}{completed}");
+    if candidates.len() == 1 {
+        palette.message = None;
+    }
+}
+
+fn common_prefix(words: &[String]) -> String {
+    let first = &words[0];
+    let len = words[1..].iter().fold(first.len(), |len, word| {
+        first[..len]
+            .char_indices()
+            .zip(word.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or(len.min(word.len()), |((idx, _), _)| idx)
+    });
+    first[..len].to_string()
+}
+
+/// Return the index of the first row whose unit starts with `text`, else contains it.
+///
+/// Matching ignores case.
+pub fn find_unit(rows: &[UnitRow], text: &str) -> Option<usize> {
+    let needle = text.to_lowercase();
+    let names: Vec<String> = rows.iter().map(|row| row.unit.to_lowercase()).collect();
+    names
+        .iter()
+        .position(|name| name.starts_with(&needle))
+        .or_else(|| names.iter().position(|name| name.contains(&needle)))
+}
+
+/// Match an action typed into the palette to a listed row and build its resolution request.
+///
+/// The unit is matched by its full name or without the `.service` suffix. The
+/// returned row is selected while the request resolves, as for the action keys.
+pub fn palette_action_request(
+    rows: &[UnitRow],
+    action: UnitAction,
+    unit: &str,
+) -> Result<(usize, ActionResolutionRequest)> {
+    let service = format!("{unit}.service");
+    let idx = rows
+        .iter()
+        .position(|row| row.unit == unit)
+        .or_else(|| rows.iter().position(|row| row.unit == service))
+        .ok_or_else(|| anyhow!("no listed unit named {unit}"))?;
+    let unit = rows[idx].unit.clone();
+    Ok((idx, ActionResolutionRequest::Named { action, unit }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Style;
+
+    fn row(unit: &str, scope: Scope) -> UnitRow {
+        UnitRow {
+            dot: '●',
+            dot_style: Style::default(),
+            unit: unit.to_string(),
+            scope,
+            load: "loaded".to_string(),
+            active: "active".to_string(),
+            sub: "running".to_string(),
+            description: String::new(),
+            last_log: String::new(),
+            last_log_usec: None,
+            changed_at: None,
+            changed_since_launch: false,
+            marked: false,
+            props: Default::default(),
+        }
+    }
+
+    fn complete(input: &str, units: &[&str]) -> PaletteState {
+        let mut palette = PaletteState {
+            search: false,
+            input: input.to_string(),
+            message: None,
+        };
+        complete_palette_input(&mut palette, units);
+        palette
+    }
+
+    #[test]
+    fn parse_palette_command_accepts_every_command_form() {
+        assert_eq!(
+            parse_palette_command("filter active=failed").unwrap(),
+            PaletteCommand::Filter {
+                load: "all".to_string(),
+                active: "failed".to_string(),
+                sub: "all".to_string(),
+            }
+        );
+        assert_eq!(
+            parse_palette_command("sort status").unwrap(),
+            PaletteCommand::Sort(Some(SortMode::Status))
+        );
+        assert_eq!(
+            parse_palette_command(" sort  reverse ").unwrap(),
+            PaletteCommand::Sort(None)
+        );
+        assert_eq!(
+            parse_palette_command("restart nginx.service").unwrap(),
+            PaletteCommand::Action(UnitAction::Restart, "nginx.service".to_string())
+        );
+        assert_eq!(
+            parse_palette_command("scope user").unwrap(),
+            PaletteCommand::Scope(Some(Scope::User))
+        );
+        assert_eq!(
+            parse_palette_command("scope both").unwrap(),
+            PaletteCommand::Scope(None)
+        );
+        assert_eq!(
+            parse_palette_command("goto sshd").unwrap(),
+            PaletteCommand::Goto("sshd".to_string())
+        );
+        assert_eq!(
+            parse_palette_command("auto").unwrap(),
+            PaletteCommand::Ui(UiCommand::ToggleAutoRefresh)
+        );
+    }
+
+    #[test]
+    fn parse_palette_command_reports_usage_errors() {
+        let err = |input| parse_palette_command(input).unwrap_err().to_string();
+        assert!(err("").contains("type a command"));
+        assert_eq!(err("frobnicate"), "unknown command: frobnicate");
+        assert_eq!(err("restart"), "usage: restart <unit>");
+        assert_eq!(err("stop --all"), "unit names cannot start with '-'");
+        assert_eq!(err("quit now"), "quit takes no arguments");
+        assert!(err("filter active=sleepy").contains("invalid --active value: sleepy"));
+        assert!(err("filter state=failed").contains("unknown filter: state"));
+        assert_eq!(err("filter failed"), "expected key=value, got failed");
+        assert!(err("sort size").starts_with("unknown sort: size; allowed: status, name"));
+        assert_eq!(err("scope galaxy"), "usage: scope system|user|both");
+        assert_eq!(err("goto"), "usage: goto <text>");
+    }
+
+    #[test]
+    fn complete_palette_input_completes_commands_and_arguments() {
+        assert_eq!(complete("fil", &[]).input, "filter ");
+
+        let several = complete("s", &[]);
+        assert_eq!(several.input, "s");
+        assert_eq!(several.message.as_deref(), Some("scope sort start stop"));
+        assert_eq!(complete("st", &[]).input, "st");
+        assert_eq!(complete("sta", &[]).input, "start ");
+
+        let units = [
+            "nginx.service",
+            "nscd.service",
+            "sshd.service",
+            "nginx.service",
+        ];
+        let unit = complete("restart ngi", &units);
+        assert_eq!(unit.input, "restart nginx.service ");
+        assert_eq!(unit.message, None);
+        let prefix = complete("goto n", &units);
+        assert_eq!(prefix.input, "goto n");
+        assert_eq!(
+            prefix.message.as_deref(),
+            Some("nginx.service nscd.service")
+        );
+
+        assert_eq!(complete("sort sta", &[]).input, "sort status ");
+        assert_eq!(complete("scope u", &[]).input, "scope user ");
+        assert_eq!(complete("filter ac", &[]).input, "filter active=");
+        assert_eq!(
+            complete("filter active=fa", &[]).input,
+            "filter active=failed "
+        );
+        assert_eq!(
+            complete("filter sub=auto-restart-q", &[]).input,
+            "filter sub=auto-restart-queued "
+        );
+        assert_eq!(
+            complete("goto zzz", &units).message.as_deref(),
+            Some("no completions")
+        );
+        assert_eq!(
+            complete("quit ", &[]).message.as_deref(),
+            Some("no completions")
+        );
+    }
+
+    #[test]
+    fn palette_state_edits_clear_the_message() {
+        let mut palette = PaletteState {
+            search: false,
+            input: "s".to_string(),
+            message: Some("no completions".to_string()),
+        };
+        palette.push('o');
+        assert_eq!(palette.input, "so");
+        assert_eq!(palette.message, None);
+        palette.message = Some("usage: sort <name>|reverse".to_string());
+        palette.pop();
+        assert_eq!(palette.input, "s");
+        assert_eq!(palette.message, None);
+    }
+
+    #[test]
+    fn find_unit_prefers_prefix_matches_and_ignores_case() {
+        let rows = vec![
+            row("openssh-agent.service", Scope::User),
+            row("sshd.service", Scope::System),
+        ];
+        assert_eq!(find_unit(&rows, "SSHD"), Some(1));
+        assert_eq!(find_unit(&rows, "ssh"), Some(1));
+        assert_eq!(find_unit(&rows, "agent"), Some(0));
+        assert_eq!(find_unit(&rows, "cron"), None);
+    }
+
+    #[test]
+    fn palette_action_request_matches_listed_rows_only() {
+        let rows = vec![
+            row("cron.service", Scope::System),
+            row("syncthing.service", Scope::User),
+        ];
+        assert_eq!(
+            palette_action_request(&rows, UnitAction::Restart, "syncthing").unwrap(),
+            (
+                1,
+                ActionResolutionRequest::Named {
+                    action: UnitAction::Restart,
+                    unit: "syncthing.service".to_string(),
+                }
+            )
+        );
+        assert_eq!(
+            palette_action_request(&rows, UnitAction::Stop, "cron.service")
+                .unwrap()
+                .0,
+            0
+        );
+        let err = palette_action_request(&rows, UnitAction::Stop, "sshd").unwrap_err();
+        assert_eq!(err.to_string(), "no listed unit named sshd");
+    }
+}
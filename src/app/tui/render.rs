@@ -21,45 +21,120 @@ use ratatui::{
     widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState, Wrap},
 };
 
-use super::state::{confirmation_prompt_text, stale_status_with_error_text};
+use std::ops::Range;
+use std::time::Instant;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use super::state::{
+    KeyHints, boot_picker_lines, confirmation_prompt_text, inspector_lines,
+    invocation_picker_lines, machine_picker_lines, palette_prompt_text, short_boot_id,
+    stale_status_with_error_text, view_title, with_boot,
+};
 use crate::{
     cli::Config,
-    types::{ConfirmationState, DetailState, LoadPhase, UnitRow, ViewMode},
+    rows::{column_text, recently_changed},
+    types::{
+        BootPickerState, Column, ConfirmationState, DetailState, InspectorState,
+        InvocationPickerState, LoadPhase, MachinePickerState, PaletteState, SortMode, SplitLayout,
+        UnitRow, ViewMode, boot_boundary, invocation_at, invocation_sections,
+    },
 };
 
+/// Runtime state shown by the list and detail views and the footer.
+#[derive(Clone, Copy)]
+pub struct ViewState<'a> {
+    /// Screen currently shown.
+    pub view_mode: ViewMode,
+    /// Label naming what the list shows.
+    pub mode_label: &'a str,
+    /// Listed unit rows.
+    pub rows: &'a [UnitRow],
+    /// Index of the selected row.
+    pub selected_idx: usize,
+    /// Logs shown in the detail view and the log pane.
+    pub detail: &'a DetailState,
+    /// Background loading phase of the list.
+    pub phase: LoadPhase,
+    /// Whether the unit list has loaded at least once.
+    pub loaded_once: bool,
+    /// Whether the last refresh failed.
+    pub last_load_error: bool,
+    /// Error of the last failed refresh, if any.
+    pub last_load_error_message: Option<&'a str>,
+    /// Whether a refresh is waiting to start.
+    pub refresh_requested: bool,
+    /// Footer status text.
+    pub status_line: &'a str,
+    /// Whether the status text replaces the stale-data notice.
+    pub status_line_overrides_stale: bool,
+    /// Auto-refresh countdown shown at the right of the footer.
+    pub auto_refresh: Option<&'a str>,
+}
+
+/// Prompts, pickers, and popups drawn over the views; `None` when closed.
+#[derive(Clone, Copy, Default)]
+pub struct Overlays<'a> {
+    /// Pending unit-action prompt.
+    pub confirmation: Option<&'a ConfirmationState>,
+    /// Machine picker.
+    pub machine_picker: Option<&'a MachinePickerState>,
+    /// Scroll offset of the help overlay.
+    pub help: Option<usize>,
+    /// Journal entry inspector.
+    pub inspector: Option<&'a InspectorState>,
+    /// Invocation picker of the detail view.
+    pub invocation_picker: Option<&'a InvocationPickerState>,
+    /// Boot picker.
+    pub boot_picker: Option<&'a BootPickerState>,
+    /// Command palette or log search prompt, drawn in place of the footer.
+    pub palette: Option<&'a PaletteState>,
+}
+
 /// Render one UI frame from runtime state.
-#[allow(clippy::too_many_arguments)]
 pub fn draw_frame(
     f: &mut Frame<'_>,
-    view_mode: ViewMode,
-    mode_label: &str,
-    rows: &[UnitRow],
-    selected_idx: usize,
     list_table_state: &mut TableState,
-    detail: &DetailState,
-    phase: LoadPhase,
-    loaded_once: bool,
-    last_load_error: bool,
-    last_load_error_message: Option<&str>,
-    refresh_requested: bool,
-    status_line: &str,
-    status_line_overrides_stale: bool,
-    confirmation: Option<&ConfirmationState>,
+    view: &ViewState<'_>,
+    overlays: &Overlays<'_>,
     config: &Config,
+    hints: &KeyHints,
 ) {
+    let ViewState {
+        view_mode,
+        mode_label,
+        rows,
+        selected_idx,
+        detail,
+        phase,
+        loaded_once,
+        last_load_error,
+        last_load_error_message,
+        refresh_requested,
+        status_line,
+        status_line_overrides_stale,
+        auto_refresh,
+    } = *view;
+    let Overlays {
+        confirmation,
+        machine_picker,
+        help: help_scroll,
+        inspector,
+        invocation_picker,
+        boot_picker,
+        palette,
+    } = *overlays;
     let size = f.area();
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Min(1), Constraint::Length(1)])
-        .split(size);
-    match view_mode {
+    let chunks = frame_chunks(size);
+    let footer_text = match view_mode {
         ViewMode::List => {
+            let (list_area, log_area) = split_areas(chunks[0], config.split);
             if rows.is_empty() {
-                let block = Block::default()
-                    .borders(Borders::ALL)
-                    .title(format!("systemd {mode_label}"));
-                let inner = block.inner(chunks[0]);
-                f.render_widget(block, chunks[0]);
+                let block = Block::default().borders(Borders::ALL).title(with_boot(
+                    view_title(mode_label, &config.target),
+                    config.boot.as_deref(),
+                ));
+                let inner = block.inner(list_area);
+                f.render_widget(block, list_area);
 
                 let message = if matches!(phase, LoadPhase::Idle)
                     && loaded_once
@@ -82,118 +157,883 @@ pub fn draw_frame(
                 };
                 let p = Paragraph::new(message)
                     .alignment(Alignment::Center)
-                    .style(Style::default().fg(Color::DarkGray));
+                    .style(config.theme.muted);
                 f.render_widget(p, inner);
             } else {
-                let header = Row::new([
-                    Cell::from(" "),
-                    Cell::from("unit"),
-                    Cell::from("load"),
-                    Cell::from("active"),
-                    Cell::from("sub"),
-                    Cell::from("description"),
-                    Cell::from("log (last line)"),
-                ])
-                .style(Style::default().add_modifier(Modifier::BOLD));
+                let layout = list_layout(rows, list_area, config);
+                let mut header_cells = vec![Cell::from(" ")];
+                header_cells.extend(layout.columns.iter().map(|(column, width)| {
+                    Cell::from(truncate_to_width(
+                        &header_label(*column, config.sort_mode, config.sort_reverse),
+                        *width,
+                    ))
+                }));
+                let header = Row::new(header_cells).style(config.theme.header);
 
+                let now = Instant::now();
                 let table_rows = rows.iter().map(|r| {
-                    Row::new([
-                        Cell::from(r.dot.to_string()).style(r.dot_style),
-                        Cell::from(r.unit.clone()),
-                        Cell::from(r.load.clone()),
-                        Cell::from(r.active.clone()),
-                        Cell::from(r.sub.clone()),
-                        Cell::from(r.description.clone()),
-                        Cell::from(r.last_log.clone()),
-                    ])
+                    let marker = if r.marked {
+                        format!("{}+", r.dot)
+                    } else if config.mark_changed && r.changed_since_launch {
+                        format!("{}*", r.dot)
+                    } else {
+                        r.dot.to_string()
+                    };
+                    let mut cells = vec![Cell::from(marker).style(r.dot_style)];
+                    let row = if layout.compact {
+                        cells.push(Cell::from(compact_row_text(r, layout.columns[0].1, config)));
+                        Row::new(cells).height(2)
+                    } else {
+                        cells.extend(layout.columns.iter().map(|(column, width)| {
+                            Cell::from(truncate_to_width(&column_text(r, *column), *width))
+                        }));
+                        Row::new(cells)
+                    };
+                    if recently_changed(r, now) {
+                        row.style(config.theme.changed)
+                    } else {
+                        row
+                    }
                 });
 
-                let widths = [
-                    Constraint::Length(2),
-                    Constraint::Length(38),
-                    Constraint::Length(8),
-                    Constraint::Length(10),
-                    Constraint::Length(12),
-                    Constraint::Length(36),
-                    Constraint::Min(20),
-                ];
+                let widths = layout.constraints();
 
                 list_table_state.select((!rows.is_empty()).then_some(selected_idx));
                 let t = Table::new(table_rows, widths)
                     .header(header)
-                    .block(
-                        Block::default()
-                            .borders(Borders::ALL)
-                            .title(format!("systemd {mode_label}")),
-                    )
-                    .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+                    .block(Block::default().borders(Borders::ALL).title(with_boot(
+                        view_title(mode_label, &config.target),
+                        config.boot.as_deref(),
+                    )))
+                    .row_highlight_style(config.theme.selected)
                     .column_spacing(1);
 
-                f.render_stateful_widget(t, chunks[0], list_table_state);
+                f.render_stateful_widget(t, list_area, list_table_state);
             }
 
-            let footer_text = if !status_line_overrides_stale
+            if let Some(log_area) = log_area {
+                let status = if detail.loading {
+                    " (loading...)".to_string()
+                } else if let Some(err) = &detail.error {
+                    format!(" (error: {err})")
+                } else {
+                    String::new()
+                };
+                let title = if detail.unit.is_empty() && !detail.is_timeline() {
+                    "logs".to_string()
+                } else {
+                    format!("{}{status}", logs_title(detail, config))
+                };
+                render_logs(f, log_area, detail, title, false, config);
+            }
+
+            if !status_line_overrides_stale
                 && !rows.is_empty()
                 && last_load_error
                 && matches!(phase, LoadPhase::Idle)
             {
-                stale_status_with_error_text(rows.len(), last_load_error_message)
+                stale_status_with_error_text(rows.len(), last_load_error_message, hints)
             } else {
                 status_line.to_string()
-            };
-            let footer = Paragraph::new(footer_text).style(Style::default().fg(Color::DarkGray));
-            f.render_widget(footer, chunks[1]);
+            }
         }
         ViewMode::Detail => {
-            let unit_meta = rows
-                .iter()
-                .find(|r| r.unit == detail.unit)
-                .map(|r| format!("unit: {}", r.unit))
-                .unwrap_or_else(|| format!("unit: {}", detail.unit));
-
-            let header = Row::new([Cell::from("time"), Cell::from("log")])
-                .style(Style::default().add_modifier(Modifier::BOLD));
-            let log_rows = detail
-                .logs
-                .iter()
-                .skip(detail.scroll)
-                .map(|entry| Row::new([entry.time.clone(), entry.log.clone()]));
-
-            let table = Table::new(log_rows, [Constraint::Length(25), Constraint::Min(20)])
-                .header(header)
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .title(format!("logs for {}", detail.unit)),
-                )
-                .column_spacing(1);
-            f.render_widget(table, chunks[0]);
+            let unit_meta = if detail.is_timeline() {
+                format!("units: {}", detail.units.len())
+            } else {
+                rows.iter()
+                    .find(|r| r.unit == detail.unit)
+                    .map(|r| format!("unit: {}", r.unit))
+                    .unwrap_or_else(|| format!("unit: {}", detail.unit))
+            };
+
+            render_logs(
+                f,
+                chunks[0],
+                detail,
+                logs_title(detail, config),
+                true,
+                config,
+            );
 
             let detail_status = if detail.loading {
                 "loading logs...".to_string()
             } else if let Some(err) = &detail.error {
                 format!("error: {err}")
             } else {
-                format!("logs: {}", detail.logs.len())
+                let mut status = format!("logs: {}", detail.logs.len());
+                let sections = invocation_sections(&detail.logs);
+                if !sections.is_empty() {
+                    match invocation_at(&sections, detail.scroll) {
+                        Some(run) => {
+                            status.push_str(&format!(
+                                " | invocation {}/{}",
+                                run + 1,
+                                sections.len()
+                            ));
+                        }
+                        None => status.push_str(&format!(" | invocations: {}", sections.len())),
+                    }
+                }
+                if let Some(search) = detail.search.status_text() {
+                    status.push_str(&format!(" | {search}"));
+                }
+                status
             };
-            let footer = Paragraph::new(format!(
-                "{} | {} | ↑/↓: scroll | b/esc: back | r: refresh | q: quit",
-                unit_meta, detail_status
-            ))
-            .style(Style::default().fg(Color::DarkGray));
-            f.render_widget(footer, chunks[1]);
+            format!(
+                "{} | {} | {}",
+                unit_meta, detail_status, hints.detail_controls
+            )
+        }
+    };
+    match palette {
+        Some(palette) => {
+            f.render_widget(
+                Paragraph::new(palette_prompt_text(palette, &detail.search)),
+                chunks[1],
+            );
+            let cursor_x = chunks[1].x + 1 + palette.input.chars().count() as u16;
+            f.set_cursor_position((
+                cursor_x.min(chunks[1].right().saturating_sub(1)),
+                chunks[1].y,
+            ));
         }
+        None => render_footer(f, chunks[1], footer_text, auto_refresh, config),
     }
 
     if let Some(confirmation) = confirmation {
         let area = centered_rect(70, 5, size);
         f.render_widget(Clear, area);
-        let prompt = Paragraph::new(confirmation_prompt_text(confirmation))
+        let prompt = Paragraph::new(confirmation_prompt_text(confirmation, hints))
             .block(Block::default().borders(Borders::ALL).title("confirm"))
             .alignment(Alignment::Center)
             .wrap(Wrap { trim: true });
         f.render_widget(prompt, area);
     }
+
+    if let Some(picker) = machine_picker {
+        let lines: Vec<Line> = machine_picker_lines(picker)
+            .into_iter()
+            .enumerate()
+            .map(|(idx, text)| {
+                let style = if !picker.loading && picker.error.is_none() && idx == picker.selected {
+                    config.theme.selected
+                } else {
+                    Style::default()
+                };
+                Line::styled(text, style)
+            })
+            .collect();
+        let height = std::cmp::min(lines.len() as u16 + 2, size.height);
+        let area = centered_rect(40, height, size);
+        f.render_widget(Clear, area);
+        let picker = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("machine ({})", hints.machine_picker_controls)),
+        );
+        f.render_widget(picker, area);
+    }
+
+    if let Some(picker) = boot_picker {
+        let height = std::cmp::min(boot_picker_lines(picker).len() as u16 + 2, size.height);
+        // Keep the highlighted boot visible when there are more boots than rows.
+        let visible = usize::from(height.saturating_sub(2)).max(1);
+        let skip = (picker.selected + 1).saturating_sub(visible);
+        let lines: Vec<Line> = boot_picker_lines(picker)
+            .into_iter()
+            .enumerate()
+            .skip(skip)
+            .map(|(idx, text)| {
+                let style = if !picker.loading && picker.error.is_none() && idx == picker.selected {
+                    config.theme.selected
+                } else {
+                    Style::default()
+                };
+                Line::styled(text, style)
+            })
+            .collect();
+        let area = centered_rect(70, height, size);
+        f.render_widget(Clear, area);
+        let picker = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("boot ({})", hints.boot_picker_controls)),
+        );
+        f.render_widget(picker, area);
+    }
+
+    if let Some(picker) = invocation_picker {
+        let height = std::cmp::min(picker.entries.len().max(1) as u16 + 2, size.height);
+        // Keep the highlighted run visible when there are more runs than rows.
+        let visible = usize::from(height.saturating_sub(2)).max(1);
+        let skip = (picker.selected + 1).saturating_sub(visible);
+        let lines: Vec<Line> = invocation_picker_lines(picker)
+            .into_iter()
+            .enumerate()
+            .skip(skip)
+            .map(|(idx, text)| {
+                let style = if !picker.entries.is_empty() && idx == picker.selected {
+                    config.theme.selected
+                } else {
+                    Style::default()
+                };
+                Line::styled(text, style)
+            })
+            .collect();
+        let area = centered_rect(70, height, size);
+        f.render_widget(Clear, area);
+        let picker = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("runs ({})", hints.invocation_picker_controls)),
+        );
+        f.render_widget(picker, area);
+    }
+
+    if let Some(inspector) = inspector {
+        let area = centered_rect(80, size.height.saturating_sub(4), size);
+        f.render_widget(Clear, area);
+        let lines: Vec<Line> = inspector_lines(inspector)
+            .into_iter()
+            .skip(inspector.scroll)
+            .map(Line::raw)
+            .collect();
+        let popup = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(truncate_to_width(
+                        &format!("entry ({}) {}", hints.inspector_controls, inspector.title),
+                        area.width.saturating_sub(2),
+                    )),
+            )
+            .wrap(Wrap { trim: false });
+        f.render_widget(popup, area);
+    }
+
+    if let Some(scroll) = help_scroll {
+        let lines = &hints.help_lines;
+        let height = std::cmp::min(lines.len() as u16 + 2, size.height);
+        let area = centered_rect(70, height, size);
+        f.render_widget(Clear, area);
+        let help = Paragraph::new(
+            lines
+                .iter()
+                .skip(scroll)
+                .map(|line| Line::raw(line.as_str()))
+                .collect::<Vec<_>>(),
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("help ({})", hints.help_controls)),
+        );
+        f.render_widget(help, area);
+    }
+}
+
+/// Return the title of the log table, naming the unit and, with both scopes listed, its scope.
+fn logs_title(detail: &DetailState, config: &Config) -> String {
+    let title = if detail.is_timeline() {
+        match detail.skipped_units {
+            0 => format!("timeline of {} units", detail.units.len()),
+            skipped => format!(
+                "timeline of {} units, {skipped} marked in the other scope skipped",
+                detail.units.len()
+            ),
+        }
+    } else if config.both_scopes {
+        format!("logs for {} ({})", detail.unit, detail.scope.label())
+    } else {
+        format!("logs for {}", detail.unit)
+    };
+    with_boot(title, config.boot.as_deref())
+}
+
+/// Width of the time column in log tables.
+const LOG_TIME_WIDTH: u16 = 25;
+
+/// Widest the unit column of a merged timeline grows.
+const TIMELINE_UNIT_MAX_WIDTH: u16 = 24;
+
+/// Return the width of the unit column of `detail`, or 0 when it shows a single unit.
+fn timeline_unit_width(detail: &DetailState) -> u16 {
+    if !detail.is_timeline() {
+        return 0;
+    }
+    let widest = detail
+        .units
+        .iter()
+        .map(|unit| unit.width())
+        .max()
+        .unwrap_or(0);
+    widest.min(usize::from(TIMELINE_UNIT_MAX_WIDTH)) as u16
+}
+
+/// Return the width of the message column in a log table of `detail` drawn in `area`.
+fn log_message_width(area: Rect, detail: &DetailState) -> u16 {
+    // Two border cells and the spacing after the time column.
+    let mut width = area.width.saturating_sub(LOG_TIME_WIDTH + 3);
+    let unit_width = timeline_unit_width(detail);
+    if unit_width > 0 {
+        width = width.saturating_sub(unit_width + 1);
+    }
+    width
+}
+
+/// Render the loaded logs of `detail` from its scroll offset.
+///
+/// With wrapping on, messages reflow over several lines; otherwise they are cut
+/// at the column edge after skipping `detail.hscroll` cells. With `cursor`, the
+/// entry under the detail cursor is highlighted as the one the inspector opens.
+fn render_logs(
+    f: &mut Frame<'_>,
+    area: Rect,
+    detail: &DetailState,
+    title: String,
+    cursor: bool,
+    config: &Config,
+) {
+    let width = log_message_width(area, detail);
+    let unit_width = timeline_unit_width(detail);
+    let mut header_cells = vec![Cell::from("time")];
+    if unit_width > 0 {
+        header_cells.push(Cell::from("unit"));
+    }
+    header_cells.push(Cell::from("log"));
+    let header = Row::new(header_cells).style(config.theme.header);
+    let search = &detail.search;
+    let current = search
+        .current
+        .and_then(|current| search.matches.get(current));
+    let sections = invocation_sections(&detail.logs);
+    let log_rows = detail
+        .logs
+        .iter()
+        .enumerate()
+        .skip(detail.scroll)
+        .map(|(idx, entry)| {
+            let first = search.matches.partition_point(|(entry, _)| *entry < idx);
+            let last = search.matches.partition_point(|(entry, _)| *entry <= idx);
+            let marks: Vec<(Range<usize>, Style)> = search.matches[first..last]
+                .iter()
+                .map(|found| {
+                    let style = if Some(found) == current {
+                        config.theme.search_match.add_modifier(Modifier::REVERSED)
+                    } else {
+                        config.theme.search_match
+                    };
+                    (found.1.clone(), style)
+                })
+                .collect();
+            let text = entry.log.as_str();
+            let mut lines: Vec<Line> = if detail.wrap {
+                wrap_ranges(text, width)
+                    .into_iter()
+                    .map(|range| marked_line(text, range, &marks))
+                    .collect()
+            } else {
+                let start = text.len() - skip_width(text, detail.hscroll).len();
+                let (kept, cut) = fit_to_width(&text[start..], width);
+                let mut line = marked_line(text, start..start + kept.len(), &marks);
+                if cut {
+                    line.push_span(Span::raw("…"));
+                }
+                vec![line]
+            };
+            let mut time = vec![Line::raw(entry.time.clone())];
+            // Rule off the entries of an older boot with a line naming it.
+            if let Some(boot) = boot_boundary(&detail.logs, idx) {
+                let rule = |label: String, width: u16| {
+                    let fill = usize::from(width).saturating_sub(label.width());
+                    Line::styled(format!("{label}{}", "─".repeat(fill)), config.theme.muted)
+                };
+                time.insert(0, rule(String::new(), LOG_TIME_WIDTH));
+                lines.insert(0, rule(format!("── boot {} ", short_boot_id(boot)), width));
+            }
+            let height = lines.len() as u16;
+            let mut cells = vec![Cell::from(Text::from(time))];
+            if unit_width > 0 {
+                let unit = entry.unit.as_deref().unwrap_or_default();
+                let style = detail
+                    .units
+                    .iter()
+                    .position(|wanted| wanted == unit)
+                    .map(|position| config.theme.timeline_unit(position))
+                    .unwrap_or_default();
+                cells.push(Cell::from(truncate_to_width(unit, unit_width)).style(style));
+            }
+            cells.push(Cell::from(Text::from(lines)));
+            let row = Row::new(cells).height(height);
+            // Underline the oldest entry of each run to mark where the run before it ends.
+            let mut style = Style::default();
+            if idx + 1 < detail.logs.len() && sections.iter().any(|run| run.last == idx) {
+                style = style.add_modifier(Modifier::UNDERLINED);
+            }
+            if cursor && idx == detail.cursor {
+                style = style.patch(config.theme.selected);
+            }
+            row.style(style)
+        });
+
+    let title = if detail.wrap {
+        format!("{title} [wrap]")
+    } else if detail.hscroll > 0 {
+        format!("{title} [+{} cols]", detail.hscroll)
+    } else {
+        title
+    };
+    let mut widths = vec![Constraint::Length(LOG_TIME_WIDTH)];
+    if unit_width > 0 {
+        widths.push(Constraint::Length(unit_width));
+    }
+    widths.push(Constraint::Length(width));
+    let table = Table::new(log_rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .column_spacing(1);
+    f.render_widget(table, area);
+}
+
+/// Return the width of the log messages of `detail` in the detail view for a frame of size `area`.
+pub fn log_message_columns(area: Rect, detail: &DetailState) -> usize {
+    usize::from(log_message_width(frame_chunks(area)[0], detail))
+}
+
+/// Return the screen lines each detail log entry takes in a frame of size `area`.
+pub fn log_line_heights(area: Rect, detail: &DetailState) -> Vec<usize> {
+    let width = log_message_width(frame_chunks(area)[0], detail);
+    wrapped_line_counts(detail, width)
+}
+
+/// Return the lines each log entry takes when messages are `width` cells wide.
+///
+/// Entries opening an older boot take one more line for the boot rule above them.
+pub fn wrapped_line_counts(detail: &DetailState, width: u16) -> Vec<usize> {
+    detail
+        .logs
+        .iter()
+        .enumerate()
+        .map(|(idx, entry)| {
+            let lines = if detail.wrap {
+                wrap_ranges(&entry.log, width).len()
+            } else {
+                1
+            };
+            lines + usize::from(boot_boundary(&detail.logs, idx).is_some())
+        })
+        .collect()
+}
+
+/// Render the footer line, keeping the auto-refresh countdown visible at its right edge.
+fn render_footer(
+    f: &mut Frame<'_>,
+    area: Rect,
+    text: String,
+    auto_refresh: Option<&str>,
+    config: &Config,
+) {
+    let Some(label) = auto_refresh else {
+        f.render_widget(Paragraph::new(text).style(config.theme.muted), area);
+        return;
+    };
+    let label_width = label.chars().count() as u16 + 1;
+    let parts = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(1), Constraint::Length(label_width)])
+        .split(area);
+    f.render_widget(Paragraph::new(text).style(config.theme.muted), parts[0]);
+    f.render_widget(
+        Paragraph::new(label)
+            .alignment(Alignment::Right)
+            .style(config.theme.muted),
+        parts[1],
+    );
+}
+
+/// Return the header text of a list column, marking the column rows are sorted by.
+fn header_label(column: Column, sort_mode: SortMode, reverse: bool) -> String {
+    if sort_mode.column() == Some(column) {
+        format!("{} {}", column.header(), if reverse { '▼' } else { '▲' })
+    } else {
+        column.header().to_string()
+    }
+}
+
+/// Screen areas of the list table, used to map mouse clicks to rows and columns.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListHitAreas {
+    /// Header row inside the table border.
+    pub header: Rect,
+    /// Area holding the unit rows below the header.
+    pub rows: Rect,
+    /// Terminal lines taken by one unit row.
+    pub row_height: u16,
+    /// Column and its horizontal extent `(x, width)` within the table.
+    pub columns: Vec<(Column, u16, u16)>,
+}
+
+impl ListHitAreas {
+    /// Return the list column whose header cell contains `(x, y)`.
+    pub fn header_column_at(&self, x: u16, y: u16) -> Option<Column> {
+        if y != self.header.y {
+            return None;
+        }
+        self.columns
+            .iter()
+            .find(|(_, start, width)| x >= *start && x < start + width)
+            .map(|(column, _, _)| *column)
+    }
+
+    /// Return the visible row offset (0 is the first row below the header) at `(x, y)`.
+    pub fn row_at(&self, x: u16, y: u16) -> Option<usize> {
+        let rows = self.rows;
+        (x >= rows.x && x < rows.right() && y >= rows.y && y < rows.bottom())
+            .then(|| usize::from((y - rows.y) / self.row_height.max(1)))
+    }
+}
+
+/// Compute the list table areas for a frame of size `area`, matching [`draw_frame`].
+pub fn list_hit_areas(area: Rect, rows: &[UnitRow], config: &Config) -> ListHitAreas {
+    let (table_area, _) = split_areas(frame_chunks(area)[0], config.split);
+    let inner = Block::default().borders(Borders::ALL).inner(table_area);
+    let layout = list_layout(rows, table_area, config);
+    let cells = Layout::horizontal(layout.constraints())
+        .spacing(1)
+        .split(inner);
+    ListHitAreas {
+        header: Rect { height: 1, ..inner },
+        rows: Rect {
+            y: inner.y.saturating_add(1),
+            height: inner.height.saturating_sub(1),
+            ..inner
+        },
+        row_height: layout.row_height(),
+        // The first cell holds the state marker.
+        columns: layout
+            .columns
+            .iter()
+            .zip(cells.iter().skip(1))
+            .map(|((column, _), cell)| (*column, cell.x, cell.width))
+            .collect(),
+    }
+}
+
+/// Return how many list or log rows fit below the table header in a frame of size `area`.
+pub fn viewport_rows(area: Rect, view_mode: ViewMode, config: &Config) -> usize {
+    let main = frame_chunks(area)[0];
+    let (table_area, row_height) = match view_mode {
+        ViewMode::List => {
+            let list_area = split_areas(main, config.split).0;
+            (list_area, if is_compact(list_area) { 2 } else { 1 })
+        }
+        ViewMode::Detail => (main, 1),
+    };
+    // Two border lines and the header row.
+    usize::from(table_area.height.saturating_sub(3) / row_height)
+}
+
+/// Split the main view into the list and, in a split layout, the log pane.
+fn split_areas(main: Rect, layout: SplitLayout) -> (Rect, Option<Rect>) {
+    let direction = match layout {
+        SplitLayout::Off => return (main, None),
+        SplitLayout::Below => Direction::Vertical,
+        SplitLayout::Beside => Direction::Horizontal,
+    };
+    let parts = Layout::default()
+        .direction(direction)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(main);
+    (parts[0], Some(parts[1]))
+}
+
+/// Split the frame into the main view and the footer line.
+fn frame_chunks(area: Rect) -> std::rc::Rc<[Rect]> {
+    Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(area)
+}
+
+/// List tables narrower than this show each unit on two lines.
+const COMPACT_WIDTH: u16 = 60;
+
+/// Visible list columns and their widths for one table area.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ListLayout {
+    /// Shown columns with their width in cells; columns that do not fit are left out.
+    columns: Vec<(Column, u16)>,
+    /// Whether rows use the two-line compact form in a single column.
+    compact: bool,
+}
+
+impl ListLayout {
+    /// Return the table column constraints, led by the state marker column.
+    fn constraints(&self) -> Vec<Constraint> {
+        std::iter::once(Constraint::Length(2))
+            .chain(
+                self.columns
+                    .iter()
+                    .map(|(_, width)| Constraint::Length(*width)),
+            )
+            .collect()
+    }
+
+    /// Return how many terminal lines one unit row takes.
+    fn row_height(&self) -> u16 {
+        if self.compact { 2 } else { 1 }
+    }
+}
+
+/// Return whether a list table in `table_area` uses compact two-line rows.
+fn is_compact(table_area: Rect) -> bool {
+    table_area.width < COMPACT_WIDTH
+}
+
+/// Lay out the list columns for a table drawn in `table_area`.
+fn list_layout(rows: &[UnitRow], table_area: Rect, config: &Config) -> ListLayout {
+    // Borders take two cells; the state marker column and its spacing take three more.
+    let available = table_area.width.saturating_sub(2).saturating_sub(3);
+    if is_compact(table_area) {
+        return ListLayout {
+            columns: vec![(Column::Unit, available)],
+            compact: true,
+        };
+    }
+    let columns = config.list_columns();
+    let headers: Vec<String> = columns
+        .iter()
+        .map(|column| header_label(*column, config.sort_mode, config.sort_reverse))
+        .collect();
+    ListLayout {
+        columns: column_widths(&columns, &headers, rows, available),
+        compact: false,
+    }
+}
+
+/// Size list columns to their content within `available` cells.
+///
+/// The log column (or, without it, the description) fills the remaining width.
+/// When content does not fit, description and unit shrink first; after that the
+/// lowest-priority columns are hidden until the rest fits. The unit column is always kept.
+fn column_widths(
+    columns: &[Column],
+    headers: &[String],
+    rows: &[UnitRow],
+    available: u16,
+) -> Vec<(Column, u16)> {
+    const SPACING: usize = 1;
+    const MIN_FLEX: usize = 12;
+    let available = usize::from(available);
+    let natural: Vec<usize> = columns
+        .iter()
+        .zip(headers)
+        .map(|(column, header)| {
+            let cap = match column {
+                Column::Unit => 48,
+                Column::Description => 40,
+                _ => 24,
+            };
+            rows.iter()
+                .map(|row| column_text(row, *column).width())
+                .chain([header.width()])
+                .max()
+                .unwrap_or(0)
+                .min(cap)
+        })
+        .collect();
+
+    let mut shown: Vec<usize> = (0..columns.len()).collect();
+    loop {
+        let position = |column| shown.iter().copied().find(|idx| columns[*idx] == column);
+        let flex = position(Column::Log).or_else(|| position(Column::Description));
+        let mut widths = natural.clone();
+        let fixed = |widths: &[usize]| -> usize {
+            shown
+                .iter()
+                .filter(|idx| Some(**idx) != flex)
+                .map(|idx| widths[*idx] + SPACING)
+                .sum::<usize>()
+        };
+        let needed = |widths: &[usize]| fixed(widths) + flex.map_or(0, |_| MIN_FLEX);
+        for shrinkable in [Column::Description, Column::Unit] {
+            let overflow = needed(&widths).saturating_sub(available);
+            if overflow == 0 {
+                break;
+            }
+            if let Some(idx) = position(shrinkable)
+                && Some(idx) != flex
+            {
+                widths[idx] = widths[idx]
+                    .saturating_sub(overflow)
+                    .max(MIN_FLEX.min(widths[idx]));
+            }
+        }
+
+        if needed(&widths) > available
+            && let Some(hidden) = shown
+                .iter()
+                .enumerate()
+                .filter(|(_, idx)| columns[**idx] != Column::Unit)
+                .max_by_key(|(_, idx)| columns[**idx].priority())
+                .map(|(pos, _)| pos)
+        {
+            shown.remove(hidden);
+            continue;
+        }
+
+        let remaining = available.saturating_sub(fixed(&widths));
+        return shown
+            .iter()
+            .map(|idx| {
+                let width = if Some(*idx) == flex {
+                    remaining
+                } else {
+                    widths[*idx].min(available)
+                };
+                (columns[*idx], width as u16)
+            })
+            .collect();
+    }
+}
+
+/// Return the two lines of a compact list row: the unit with its state, then the
+/// last log line (or the description) indented below it.
+fn compact_row_text(row: &UnitRow, width: u16, config: &Config) -> Text<'static> {
+    let state = format!("{}/{}", row.active, row.sub);
+    let detail = if config.list_columns().contains(&Column::Log) && !row.last_log.is_empty() {
+        &row.last_log
+    } else {
+        &row.description
+    };
+    Text::from(vec![
+        Line::raw(truncate_to_width(&format!("{} {state}", row.unit), width)),
+        Line::styled(
+            truncate_to_width(&format!("  {detail}"), width),
+            config.theme.muted,
+        ),
+    ])
+}
+
+/// Split `text` into lines of at most `width` terminal cells, as byte ranges of `text`.
+///
+/// Embedded newlines are kept, lines break after the last space that fits, and
+/// words longer than a line are split. Always returns at least one line.
+fn wrap_ranges(text: &str, width: u16) -> Vec<Range<usize>> {
+    let width = usize::from(width.max(1));
+    let mut lines = Vec::new();
+    let mut offset = 0;
+    for source in text.split('\n') {
+        let mut start = offset;
+        let mut used = 0;
+        let mut last_space = None;
+        for (idx, ch) in source.char_indices().map(|(idx, ch)| (offset + idx, ch)) {
+            let ch_width = ch.width().unwrap_or(0);
+            if used + ch_width > width && idx > start {
+                if ch == ' ' {
+                    lines.push(start..idx);
+                    start = idx + 1;
+                    used = 0;
+                    last_space = None;
+                    continue;
+                }
+                match last_space.take() {
+                    Some(space) => {
+                        lines.push(start..space);
+                        start = space + 1;
+                        used = text[start..idx].width();
+                    }
+                    None => {
+                        lines.push(start..idx);
+                        start = idx;
+                        used = 0;
+                    }
+                }
+            }
+            if ch == ' ' && idx > start {
+                last_space = Some(idx);
+            }
+            used += ch_width;
+        }
+        offset += source.len();
+        lines.push(start..offset);
+        // Skip the newline itself.
+        offset += 1;
+    }
+    lines
+}
+
+/// Return `text` without its first `cells` terminal cells.
+fn skip_width(text: &str, cells: usize) -> &str {
+    let mut skipped = 0;
+    for (idx, ch) in text.char_indices() {
+        if skipped >= cells {
+            return &text[idx..];
+        }
+        skipped += ch.width().unwrap_or(0);
+    }
+    ""
+}
+
+/// Shorten `text` to at most `width` terminal cells, ending cut text with an ellipsis.
+fn truncate_to_width(text: &str, width: u16) -> String {
+    match fit_to_width(text, width) {
+        (kept, true) => format!("{kept}…"),
+        (kept, false) => kept.to_string(),
+    }
+}
+
+/// Return the part of `text` shown in `width` cells and whether it was cut.
+///
+/// Cut text leaves one cell for the ellipsis.
+fn fit_to_width(text: &str, width: u16) -> (&str, bool) {
+    let width = usize::from(width);
+    if text.width() <= width {
+        return (text, false);
+    }
+    if width == 0 {
+        return ("", false);
+    }
+    let mut used = 0;
+    let mut end = 0;
+    for (idx, ch) in text.char_indices() {
+        let ch_width = ch.width().unwrap_or(0);
+        if used + ch_width + 1 > width {
+            break;
+        }
+        used += ch_width;
+        end = idx + ch.len_utf8();
+    }
+    (text[..end].trim_end(), true)
+}
+
+/// Build a line from the `visible` byte range of `text`, styling the parts inside `marks`.
+///
+/// `marks` are byte ranges of `text` with their style, in order and without overlap.
+fn marked_line(
+    text: &str,
+    visible: Range<usize>,
+    marks: &[(Range<usize>, Style)],
+) -> Line<'static> {
+    let mut spans = Vec::new();
+    let mut pos = visible.start;
+    for (range, style) in marks {
+        let start = range.start.clamp(pos, visible.end);
+        let end = range.end.clamp(start, visible.end);
+        if start == end {
+            continue;
+        }
+        if pos < start {
+            spans.push(Span::raw(text[pos..start].to_string()));
+        }
+        spans.push(Span::styled(text[start..end].to_string(), *style));
+        pos = end;
+    }
+    if pos < visible.end {
+        spans.push(Span::raw(text[pos..visible.end].to_string()));
+    }
+    Line::from(spans)
 }
 
 fn centered_rect(width_percent: u16, height: u16, area: Rect) -> Rect {
@@ -219,6 +1059,9 @@ fn centered_rect(width_percent: u16, height: u16, area: Rect) -> Rect {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::Column;
+    use crate::types::Scope;
+    use crate::types::{DetailLogEntry, InvocationPickerState, JournalEntry};
     use ratatui::{Terminal, backend::TestBackend};
 
     fn rendered_text(terminal: &Terminal<TestBackend>) -> String {
@@ -234,6 +1077,29 @@ mod tests {
             .join("\n")
     }
 
+    fn view<'a>(
+        view_mode: ViewMode,
+        rows: &'a [UnitRow],
+        detail: &'a DetailState,
+        status_line: &'a str,
+    ) -> ViewState<'a> {
+        ViewState {
+            view_mode,
+            mode_label: "services",
+            rows,
+            selected_idx: 0,
+            detail,
+            phase: LoadPhase::Idle,
+            loaded_once: true,
+            last_load_error: false,
+            last_load_error_message: None,
+            refresh_requested: false,
+            status_line,
+            status_line_overrides_stale: false,
+            auto_refresh: None,
+        }
+    }
+
     fn sample_config() -> Config {
         Config {
             load_filter: "loaded".to_string(),
@@ -242,8 +1108,22 @@ mod tests {
             show_help: false,
             show_version: false,
             debug_tui: false,
-            scope: crate::types::Scope::System,
+            target: crate::types::Target::new(crate::types::Scope::System),
+            both_scopes: false,
             sort_mode: crate::types::SortMode::Name,
+            journal: crate::types::JournalSource::Local,
+            root: crate::types::UnitFileRoot::Live,
+            command_timeout: None,
+            detail_lines: 300,
+            refresh_interval: None,
+            key_bindings: Default::default(),
+            theme: Default::default(),
+            columns: crate::types::Column::DEFAULT.to_vec(),
+            preset_drift: false,
+            sort_reverse: false,
+            mark_changed: false,
+            split: crate::types::SplitLayout::Off,
+            boot: None,
         }
     }
 
@@ -252,11 +1132,17 @@ mod tests {
             dot: '.',
             dot_style: Style::default(),
             unit: "a.service".to_string(),
+            scope: Scope::System,
             load: "loaded".to_string(),
             active: "active".to_string(),
             sub: "running".to_string(),
             description: "A".to_string(),
             last_log: "log".to_string(),
+            last_log_usec: None,
+            changed_at: None,
+            changed_since_launch: false,
+            marked: false,
+            props: Default::default(),
         }
     }
 
@@ -270,26 +1156,798 @@ mod tests {
             .draw(|f| {
                 draw_frame(
                     f,
-                    ViewMode::List,
-                    "services",
-                    &[sample_row()],
-                    0,
                     &mut state,
-                    &detail,
-                    LoadPhase::Idle,
-                    true,
-                    false,
-                    None,
-                    false,
-                    "services: 1",
-                    false,
-                    None,
+                    &view(ViewMode::List, &[sample_row()], &detail, "services: 1"),
+                    &Overlays::default(),
                     &sample_config(),
+                    &KeyHints::default(),
                 )
             })
             .expect("draw");
     }
 
+    #[test]
+    fn draw_frame_marks_changed_rows_when_configured() {
+        let mut changed = sample_row();
+        changed.unit = "b.service".to_string();
+        changed.changed_since_launch = true;
+        let rows = [sample_row(), changed];
+        let render = |config: &Config| {
+            let mut terminal = Terminal::new(TestBackend::new(100, 8)).expect("terminal");
+            let mut state = TableState::default();
+            let detail = DetailState::default();
+            terminal
+                .draw(|f| {
+                    draw_frame(
+                        f,
+                        &mut state,
+                        &view(ViewMode::List, &rows, &detail, "services: 2"),
+                        &Overlays::default(),
+                        config,
+                        &KeyHints::default(),
+                    )
+                })
+                .expect("draw");
+            rendered_text(&terminal)
+        };
+        assert!(!render(&sample_config()).contains(".*"));
+        let text = render(&Config {
+            mark_changed: true,
+            split: crate::types::SplitLayout::Off,
+            boot: None,
+            ..sample_config()
+        });
+        let line = |unit: &str| {
+            text.lines()
+                .find(|line| line.contains(unit))
+                .expect("row")
+                .to_string()
+        };
+        assert!(line("b.service").contains(".*"));
+        assert!(!line("a.service").contains(".*"));
+    }
+
+    #[test]
+    fn list_hit_areas_match_rendered_header_and_rows() {
+        let config = Config {
+            columns: vec![Column::Unit, Column::Active, Column::Log],
+            ..sample_config()
+        };
+        let rows = [sample_row()];
+        let mut terminal = Terminal::new(TestBackend::new(80, 10)).expect("terminal");
+        let mut state = TableState::default();
+        let detail = DetailState::default();
+        terminal
+            .draw(|f| {
+                draw_frame(
+                    f,
+                    &mut state,
+                    &view(ViewMode::List, &rows, &detail, "services: 1"),
+                    &Overlays::default(),
+                    &config,
+                    &KeyHints::default(),
+                )
+            })
+            .expect("draw");
+        let text = rendered_text(&terminal);
+        let lines: Vec<&str> = text.lines().collect();
+
+        let areas = list_hit_areas(Rect::new(0, 0, 80, 10), &rows, &config);
+        let header_line: Vec<char> = lines[usize::from(areas.header.y)].chars().collect();
+        for (column, x, _) in &areas.columns {
+            let label = header_label(*column, config.sort_mode, config.sort_reverse);
+            let at: String = header_line[usize::from(*x)..].iter().collect();
+            assert!(at.starts_with(&label), "{label} not at {x}: {at}");
+            assert_eq!(areas.header_column_at(*x, areas.header.y), Some(*column));
+        }
+        assert!(lines[usize::from(areas.rows.y)].contains("a.service"));
+        assert_eq!(
+            viewport_rows(Rect::new(0, 0, 80, 10), ViewMode::List, &config),
+            usize::from(areas.rows.height)
+        );
+        assert_eq!(areas.row_at(areas.rows.x, areas.rows.y), Some(0));
+        assert_eq!(areas.row_at(0, areas.rows.y), None);
+        assert_eq!(
+            areas.header_column_at(areas.columns[0].1, areas.rows.y),
+            None
+        );
+    }
+
+    #[test]
+    fn draw_frame_renders_help_overlay_from_scroll_offset() {
+        let render = |scroll| {
+            let mut terminal = Terminal::new(TestBackend::new(100, 20)).expect("terminal");
+            let mut state = TableState::default();
+            let detail = DetailState::default();
+            terminal
+                .draw(|f| {
+                    draw_frame(
+                        f,
+                        &mut state,
+                        &view(ViewMode::List, &[sample_row()], &detail, "services: 1"),
+                        &Overlays {
+                            help: Some(scroll),
+                            ..Overlays::default()
+                        },
+                        &sample_config(),
+                        &KeyHints::default(),
+                    )
+                })
+                .expect("draw");
+            rendered_text(&terminal)
+        };
+        let text = render(0);
+        assert!(text.contains("help (↑/↓: scroll | esc/?: close)"));
+        assert!(text.contains("list:"));
+        assert!(text.contains("open logs of the selected unit"));
+        assert!(!render(1).contains("list:"));
+    }
+
+    #[test]
+    fn draw_frame_shows_auto_refresh_countdown_in_footer() {
+        let backend = TestBackend::new(200, 10);
+        let mut terminal = Terminal::new(backend).expect("terminal");
+        let mut state = TableState::default();
+        let detail = DetailState::default();
+        terminal
+            .draw(|f| {
+                draw_frame(
+                    f,
+                    &mut state,
+                    &ViewState {
+                        auto_refresh: Some("auto 4s"),
+                        ..view(ViewMode::List, &[sample_row()], &detail, "services: 1")
+                    },
+                    &Overlays::default(),
+                    &sample_config(),
+                    &KeyHints::default(),
+                )
+            })
+            .expect("draw");
+        let text = rendered_text(&terminal);
+        let footer = text.lines().last().expect("footer");
+        assert!(footer.starts_with("services: 1"));
+        assert!(footer.trim_end().ends_with("auto 4s"));
+    }
+
+    #[test]
+    fn draw_frame_replaces_footer_with_open_palette() {
+        let backend = TestBackend::new(120, 10);
+        let mut terminal = Terminal::new(backend).expect("terminal");
+        let mut state = TableState::default();
+        let detail = DetailState::default();
+        let palette = PaletteState {
+            search: false,
+            input: "sort s".to_string(),
+            message: Some("status sub".to_string()),
+        };
+        terminal
+            .draw(|f| {
+                draw_frame(
+                    f,
+                    &mut state,
+                    &ViewState {
+                        auto_refresh: Some("auto 4s"),
+                        ..view(ViewMode::Detail, &[sample_row()], &detail, "services: 1")
+                    },
+                    &Overlays {
+                        palette: Some(&palette),
+                        ..Overlays::default()
+                    },
+                    &sample_config(),
+                    &KeyHints::default(),
+                )
+            })
+            .expect("draw");
+        let text = rendered_text(&terminal);
+        let footer = text.lines().last().expect("footer");
+        assert_eq!(footer.trim_end(), ":sort s   status sub");
+        terminal
+            .backend_mut()
+            .assert_cursor_position(Position::new(7, 9));
+    }
+
+    #[test]
+    fn draw_frame_labels_rows_with_scope_when_both_scopes_are_listed() {
+        let backend = TestBackend::new(140, 10);
+        let mut terminal = Terminal::new(backend).expect("terminal");
+        let mut state = TableState::default();
+        let detail = DetailState::default();
+        let mut user_row = sample_row();
+        user_row.unit = "pipewire.service".to_string();
+        user_row.scope = Scope::User;
+        let config = Config {
+            both_scopes: true,
+            ..sample_config()
+        };
+        terminal
+            .draw(|f| {
+                draw_frame(
+                    f,
+                    &mut state,
+                    &view(
+                        ViewMode::List,
+                        &[sample_row(), user_row.clone()],
+                        &detail,
+                        "services: 2",
+                    ),
+                    &Overlays::default(),
+                    &config,
+                    &KeyHints::default(),
+                )
+            })
+            .expect("draw");
+        let text = rendered_text(&terminal);
+        assert!(text.contains("scope"));
+        let user_line = text
+            .lines()
+            .find(|line| line.contains("pipewire.service"))
+            .expect("user row");
+        assert!(user_line.contains("user"));
+    }
+
+    #[test]
+    fn draw_frame_renders_configured_columns_in_order() {
+        let backend = TestBackend::new(100, 8);
+        let mut terminal = Terminal::new(backend).expect("terminal");
+        let mut state = TableState::default();
+        let detail = DetailState::default();
+        let mut row = sample_row();
+        row.props.memory_bytes = Some(2048);
+        let config = Config {
+            columns: vec![Column::Memory, Column::Unit, Column::MainPid],
+            ..sample_config()
+        };
+        terminal
+            .draw(|f| {
+                draw_frame(
+                    f,
+                    &mut state,
+                    &view(ViewMode::List, &[row.clone()], &detail, "services: 1"),
+                    &Overlays::default(),
+                    &config,
+                    &KeyHints::default(),
+                )
+            })
+            .expect("draw");
+        let text = rendered_text(&terminal);
+        let header = text.lines().find(|l| l.contains("memory")).expect("header");
+        assert!(header.find("memory") < header.find("unit"));
+        assert!(header.find("unit") < header.find("pid"));
+        assert!(!header.contains("description"));
+        let row_line = text.lines().find(|l| l.contains("a.service")).expect("row");
+        assert!(row_line.contains("2.0K"));
+    }
+
+    #[test]
+    fn header_label_marks_sorted_column_and_direction() {
+        assert_eq!(
+            header_label(Column::Memory, SortMode::Memory, false),
+            "memory ▲"
+        );
+        assert_eq!(header_label(Column::Unit, SortMode::Name, true), "unit ▼");
+        assert_eq!(header_label(Column::Unit, SortMode::Status, false), "unit");
+    }
+
+    #[test]
+    fn column_widths_fit_content_and_shrink_to_available_space() {
+        let mut row = sample_row();
+        row.unit = "a-very-long-unit-name-for-testing.service".to_string();
+        row.description = "d".repeat(60);
+        let columns = Column::DEFAULT.to_vec();
+        let headers: Vec<String> = columns.iter().map(|c| c.header().to_string()).collect();
+        let wide = column_widths(&columns, &headers, &[row.clone()], 200);
+        assert_eq!(
+            wide,
+            vec![
+                (Column::Unit, 41),
+                (Column::Load, 6),
+                (Column::Active, 6),
+                (Column::Sub, 7),
+                (Column::Description, 40),
+                (Column::Log, 95),
+            ]
+        );
+        let narrow = column_widths(&columns, &headers, &[row.clone()], 70);
+        assert_eq!(narrow[4], (Column::Description, 12));
+        assert_eq!(narrow[0], (Column::Unit, 22));
+        assert_eq!(narrow[5], (Column::Log, 12));
+
+        // Below that, columns are hidden from the lowest priority up; unit stays.
+        let tight = column_widths(&columns, &headers, &[row.clone()], 50);
+        let shown: Vec<Column> = tight.iter().map(|(column, _)| *column).collect();
+        assert_eq!(
+            shown,
+            vec![Column::Unit, Column::Active, Column::Sub, Column::Log]
+        );
+        assert!(tight.iter().map(|(_, width)| width + 1).sum::<u16>() <= 51);
+        let tiny = column_widths(&columns, &headers, &[row], 10);
+        assert_eq!(tiny, vec![(Column::Unit, 10)]);
+    }
+
+    #[test]
+    fn truncate_to_width_counts_terminal_cells() {
+        assert_eq!(truncate_to_width("short", 10), "short");
+        assert_eq!(truncate_to_width("exactly", 7), "exactly");
+        assert_eq!(truncate_to_width("truncated", 6), "trunc…");
+        // Wide characters take two cells each.
+        assert_eq!(truncate_to_width("日本語テキスト", 7), "日本語…");
+        assert_eq!(truncate_to_width("日本語", 4), "日…");
+        assert_eq!(truncate_to_width("abc", 0), "");
+    }
+
+    #[test]
+    fn wrap_ranges_break_at_spaces_newlines_and_long_words() {
+        let wrap = |text: &str, width| -> Vec<String> {
+            wrap_ranges(text, width)
+                .into_iter()
+                .map(|range| text[range].to_string())
+                .collect()
+        };
+        assert_eq!(wrap("short", 10), vec!["short"]);
+        assert_eq!(wrap("", 10), vec![""]);
+        assert_eq!(
+            wrap("one two three four", 9),
+            vec!["one two", "three", "four"]
+        );
+        assert_eq!(wrap("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+        assert_eq!(
+            wrap("Exception\n\tat Foo.bar", 20),
+            vec!["Exception", "\tat Foo.bar"]
+        );
+        assert_eq!(
+            wrap("日本語テキスト", 5),
+            vec!["日本", "語テ", "キス", "ト"]
+        );
+        assert_eq!(wrap_ranges("ab\ncd", 5), vec![0..2, 3..5]);
+    }
+
+    #[test]
+    fn marked_line_styles_matches_inside_the_visible_range() {
+        let style = Style::default().bg(Color::Yellow);
+        let line = marked_line(
+            "an error and another error",
+            3..26,
+            &[(3..8, style), (21..26, style)],
+        );
+        let spans: Vec<(String, Style)> = line
+            .spans
+            .iter()
+            .map(|span| (span.content.to_string(), span.style))
+            .collect();
+        assert_eq!(
+            spans,
+            vec![
+                ("error".to_string(), style),
+                (" and another ".to_string(), Style::default()),
+                ("error".to_string(), style),
+            ]
+        );
+        let cut = marked_line("an error", 0..5, &[(3..8, style)]);
+        assert_eq!(cut.spans[1].content, "er");
+    }
+
+    #[test]
+    fn skip_width_drops_leading_cells() {
+        assert_eq!(skip_width("abcdef", 0), "abcdef");
+        assert_eq!(skip_width("abcdef", 2), "cdef");
+        assert_eq!(skip_width("日本語", 2), "本語");
+        assert_eq!(skip_width("abc", 9), "");
+    }
+
+    #[test]
+    fn draw_frame_wraps_or_scrolls_long_detail_messages() {
+        let mut detail = DetailState::default();
+        detail.unit = "a.service".to_string();
+        detail.logs = vec![
+            crate::types::DetailLogEntry {
+                time: "t1".to_string(),
+                log: format!("start {} end", "x".repeat(60)),
+                ..DetailLogEntry::default()
+            },
+            crate::types::DetailLogEntry {
+                time: "t2".to_string(),
+                log: "next".to_string(),
+                ..DetailLogEntry::default()
+            },
+        ];
+        let render = |detail: &DetailState| {
+            let mut terminal = Terminal::new(TestBackend::new(60, 10)).expect("terminal");
+            let mut state = TableState::default();
+            terminal
+                .draw(|f| {
+                    draw_frame(
+                        f,
+                        &mut state,
+                        &view(ViewMode::Detail, &[sample_row()], detail, "services: 1"),
+                        &Overlays::default(),
+                        &sample_config(),
+                        &KeyHints::default(),
+                    )
+                })
+                .expect("draw");
+            rendered_text(&terminal)
+        };
+
+        let cut = render(&detail);
+        let lines: Vec<&str> = cut.lines().collect();
+        assert!(lines[2].contains("start xxx"));
+        assert!(lines[2].contains('…'));
+        assert!(lines[3].contains("next"));
+
+        detail.scroll_right(6);
+        let scrolled = render(&detail);
+        assert!(scrolled.contains("[+6 cols]"));
+        assert!(!scrolled.contains("start"));
+
+        detail.toggle_wrap();
+        let wrapped = render(&detail);
+        let lines: Vec<&str> = wrapped.lines().collect();
+        assert!(wrapped.contains("[wrap]"), "{wrapped}");
+        assert!(lines[2].ends_with("start                           │"));
+        assert!(lines[3].contains(&"x".repeat(32)));
+        assert!(lines[4].contains("x end"));
+        assert!(lines[5].contains("next"));
+        let heights = log_line_heights(Rect::new(0, 0, 60, 10), &detail);
+        assert_eq!(heights, vec![3, 1]);
+        assert_eq!(log_message_columns(Rect::new(0, 0, 60, 10), &detail), 32);
+    }
+
+    #[test]
+    fn draw_frame_highlights_search_matches_and_shows_match_count() {
+        let mut detail = DetailState::default();
+        detail.unit = "a.service".to_string();
+        detail.logs = ["ok", "disk error", "another error"]
+            .into_iter()
+            .map(|log| crate::types::DetailLogEntry {
+                time: "t".to_string(),
+                log: log.to_string(),
+                ..DetailLogEntry::default()
+            })
+            .collect();
+        detail
+            .search
+            .start("error", &detail.logs, 0)
+            .expect("search");
+        let config = sample_config();
+        let mut terminal = Terminal::new(TestBackend::new(60, 8)).expect("terminal");
+        let mut state = TableState::default();
+        let search_prompt = PaletteState::search();
+        let mut draw = |terminal: &mut Terminal<TestBackend>, palette: Option<&PaletteState>| {
+            terminal
+                .draw(|f| {
+                    draw_frame(
+                        f,
+                        &mut state,
+                        &view(ViewMode::Detail, &[sample_row()], &detail, "services: 1"),
+                        &Overlays {
+                            palette,
+                            ..Overlays::default()
+                        },
+                        &config,
+                        &KeyHints::default(),
+                    )
+                })
+                .expect("draw");
+        };
+        draw(&mut terminal, None);
+        let text = rendered_text(&terminal);
+        assert!(text.contains("logs: 3 | match 1/2"));
+        let buffer = terminal.backend().buffer();
+        // Message column starts after the border, the time column, and its spacing.
+        let current = &buffer[(1 + 26 + 5, 3)];
+        assert_eq!(current.symbol(), "e");
+        assert!(current.modifier.contains(Modifier::REVERSED));
+        let other = &buffer[(1 + 26 + 8, 4)];
+        assert_eq!(other.symbol(), "e");
+        assert_eq!(other.bg, Color::Yellow);
+        assert!(!other.modifier.contains(Modifier::REVERSED));
+        assert_eq!(buffer[(1 + 26, 3)].bg, Color::Reset);
+
+        draw(&mut terminal, Some(&search_prompt));
+        let text = rendered_text(&terminal);
+        assert!(text.contains("/   [text, match case]"));
+    }
+
+    #[test]
+    fn draw_frame_marks_the_top_entry_and_shows_the_inspector() {
+        let mut detail = DetailState::default();
+        detail.unit = "a.service".to_string();
+        detail.logs = ["first", "second"]
+            .into_iter()
+            .map(|log| DetailLogEntry {
+                time: "2026-02-24T10:00:00+0000".to_string(),
+                log: log.to_string(),
+                ..DetailLogEntry::default()
+            })
+            .collect();
+        let mut inspector = InspectorState::loading(&detail.logs[0]);
+        inspector.apply_loaded(JournalEntry {
+            fields: vec![
+                ("MESSAGE".to_string(), "first".to_string()),
+                ("_PID".to_string(), "42".to_string()),
+            ],
+            catalog: Some("Subject: explained".to_string()),
+        });
+        let config = sample_config();
+        let mut terminal = Terminal::new(TestBackend::new(80, 16)).expect("terminal");
+        let mut state = TableState::default();
+        let mut draw = |terminal: &mut Terminal<TestBackend>,
+                        inspector: Option<&InspectorState>| {
+            terminal
+                .draw(|f| {
+                    draw_frame(
+                        f,
+                        &mut state,
+                        &view(ViewMode::Detail, &[sample_row()], &detail, "services: 1"),
+                        &Overlays {
+                            inspector,
+                            ..Overlays::default()
+                        },
+                        &config,
+                        &KeyHints::default(),
+                    )
+                })
+                .expect("draw");
+        };
+        draw(&mut terminal, None);
+        let buffer = terminal.backend().buffer();
+        assert!(buffer[(1 + 26, 2)].modifier.contains(Modifier::REVERSED));
+        assert!(!buffer[(1 + 26, 3)].modifier.contains(Modifier::REVERSED));
+        assert!(rendered_text(&terminal).contains("enter: inspect"));
+
+        draw(&mut terminal, Some(&inspector));
+        let text = rendered_text(&terminal);
+        assert!(text.contains("esc/enter: close"));
+        assert!(text.contains("MESSAGE=first"));
+        assert!(text.contains("_PID=42"));
+        assert!(text.contains("catalog:"));
+        assert!(text.contains("Subject: explained"));
+    }
+
+    #[test]
+    fn draw_frame_marks_run_boundaries_and_shows_the_invocation_picker() {
+        let mut detail = DetailState::default();
+        detail.unit = "a.service".to_string();
+        detail.logs = [
+            ("b", "restarted", None),
+            ("a", "crashed", Some("result=signal")),
+        ]
+        .into_iter()
+        .map(|(invocation, log, exit)| DetailLogEntry {
+            time: "2026-02-24T10:00:00+0000".to_string(),
+            log: log.to_string(),
+            invocation: Some(invocation.to_string()),
+            exit: exit.map(str::to_string),
+            ..DetailLogEntry::default()
+        })
+        .chain(std::iter::once(DetailLogEntry {
+            time: "2026-02-24T09:00:00+0000".to_string(),
+            log: "older".to_string(),
+            ..DetailLogEntry::default()
+        }))
+        .collect();
+        let picker = InvocationPickerState::for_detail(&detail);
+        let config = sample_config();
+        let mut terminal = Terminal::new(TestBackend::new(100, 12)).expect("terminal");
+        let mut state = TableState::default();
+        let mut draw = |terminal: &mut Terminal<TestBackend>,
+                        picker: Option<&InvocationPickerState>| {
+            terminal
+                .draw(|f| {
+                    draw_frame(
+                        f,
+                        &mut state,
+                        &view(ViewMode::Detail, &[sample_row()], &detail, "services: 1"),
+                        &Overlays {
+                            invocation_picker: picker,
+                            ..Overlays::default()
+                        },
+                        &config,
+                        &KeyHints::default(),
+                    )
+                })
+                .expect("draw");
+        };
+        draw(&mut terminal, None);
+        let buffer = terminal.backend().buffer();
+        let top = buffer[(1 + 26, 2)].modifier;
+        assert!(top.contains(Modifier::UNDERLINED | Modifier::REVERSED));
+        // The second run holds the last row too, so nothing follows to mark.
+        assert!(buffer[(1 + 26, 3)].modifier.is_empty());
+        assert!(buffer[(1 + 26, 4)].modifier.is_empty());
+        assert!(rendered_text(&terminal).contains("logs: 3 | invocation 1/2"));
+
+        draw(&mut terminal, Some(&picker));
+        let text = rendered_text(&terminal);
+        assert!(text.contains("runs (enter: jump | esc: cancel)"));
+        assert!(text.contains("2026-02-24T10:00:00+0000  no exit recorded  (1 line)"));
+        assert!(text.contains("2026-02-24T09:00:00+0000  result=signal  (2 lines)"));
+    }
+
+    #[test]
+    fn draw_frame_rules_off_older_boots_and_shows_the_boot_picker() {
+        let old = "0123456789abcdef0123456789abcdef";
+        let mut detail = DetailState::default();
+        detail.unit = "a.service".to_string();
+        detail.logs = [
+            ("started", "fedcba9876543210fedcba9876543210"),
+            ("older", old),
+        ]
+        .into_iter()
+        .map(|(log, boot)| DetailLogEntry {
+            time: "2026-02-24T10:00:00+0000".to_string(),
+            log: log.to_string(),
+            boot: Some(boot.to_string()),
+            ..DetailLogEntry::default()
+        })
+        .collect();
+        assert_eq!(wrapped_line_counts(&detail, 40), vec![1, 2]);
+        let mut picker = BootPickerState::loading(Some(old.to_string()));
+        picker.apply_loaded(vec![crate::types::Boot {
+            index: -1,
+            id: old.to_string(),
+            span: "Mon 2026-02-23 08:00:00 UTC Mon 2026-02-23 20:00:00 UTC".to_string(),
+        }]);
+        let mut config = sample_config();
+        config.boot = Some(old.to_string());
+        let mut terminal = Terminal::new(TestBackend::new(100, 12)).expect("terminal");
+        let mut state = TableState::default();
+        let mut draw = |terminal: &mut Terminal<TestBackend>, picker: Option<&BootPickerState>| {
+            terminal
+                .draw(|f| {
+                    draw_frame(
+                        f,
+                        &mut state,
+                        &view(ViewMode::Detail, &[sample_row()], &detail, "services: 1"),
+                        &Overlays {
+                            boot_picker: picker,
+                            ..Overlays::default()
+                        },
+                        &config,
+                        &KeyHints::default(),
+                    )
+                })
+                .expect("draw");
+        };
+        draw(&mut terminal, None);
+        let lines: Vec<String> = rendered_text(&terminal)
+            .lines()
+            .map(str::to_string)
+            .collect();
+        assert!(lines[0].contains("logs for a.service in boot 01234567"));
+        assert!(lines[2].contains("started"));
+        let rule = format!("{} ── boot 01234567 ───", "─".repeat(25));
+        assert!(lines[3].contains(&rule));
+        assert!(lines[4].contains("older"));
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer[(1 + 26, 3)].style().fg, config.theme.muted.fg);
+
+        draw(&mut terminal, Some(&picker));
+        let text = rendered_text(&terminal);
+        assert!(text.contains("boot (enter: select | esc: cancel)"));
+        assert!(text.contains("all boots"));
+        assert!(text.contains(" -1  Mon 2026-02-23 08:00:00 UTC Mon 2026-02-23 20:00:00 UTC"));
+    }
+
+    #[test]
+    fn draw_frame_shows_a_colored_unit_column_for_timelines_and_marks_rows() {
+        let mut detail = DetailState::default();
+        detail.begin_for_timeline(
+            Scope::System,
+            vec![
+                "a.service".to_string(),
+                "a-much-longer-unit-name.service".to_string(),
+            ],
+            1,
+        );
+        detail.loading = false;
+        detail.logs = [
+            ("a-much-longer-unit-name.service", "second"),
+            ("a.service", "first"),
+        ]
+        .into_iter()
+        .map(|(unit, log)| DetailLogEntry {
+            time: "2026-02-24T10:00:00+0000".to_string(),
+            log: log.to_string(),
+            unit: Some(unit.to_string()),
+            ..DetailLogEntry::default()
+        })
+        .collect();
+        assert_eq!(log_message_columns(Rect::new(0, 0, 100, 10), &detail), 47);
+        let mut config = sample_config();
+        config.theme = crate::theme::Theme::dark();
+        let mut marked = sample_row();
+        marked.marked = true;
+        let mut terminal = Terminal::new(TestBackend::new(100, 10)).expect("terminal");
+        let mut state = TableState::default();
+        let mut draw = |terminal: &mut Terminal<TestBackend>, mode: ViewMode| {
+            terminal
+                .draw(|f| {
+                    draw_frame(
+                        f,
+                        &mut state,
+                        &view(mode, std::slice::from_ref(&marked), &detail, "services: 1"),
+                        &Overlays::default(),
+                        &config,
+                        &KeyHints::default(),
+                    )
+                })
+                .expect("draw");
+        };
+        draw(&mut terminal, ViewMode::Detail);
+        let lines: Vec<String> = rendered_text(&terminal)
+            .lines()
+            .map(str::to_string)
+            .collect();
+        assert!(lines[0].contains("timeline of 2 units, 1 marked in the other scope skipped"));
+        assert!(lines[1].contains("time") && lines[1].contains("unit"));
+        assert!(lines[2].contains("a-much-longer-unit-name… second"));
+        assert!(lines[3].contains("a.service                first"));
+        assert!(lines[9].starts_with("units: 2 | logs: 2"));
+        let buffer = terminal.backend().buffer();
+        assert_eq!(
+            buffer[(1 + 26, 3)].style().fg,
+            config.theme.timeline_unit(0).fg
+        );
+        assert_eq!(
+            buffer[(1 + 26, 2)].style().fg,
+            config.theme.timeline_unit(1).fg
+        );
+
+        draw(&mut terminal, ViewMode::List);
+        assert!(rendered_text(&terminal).contains(&format!("{}+", marked.dot)));
+    }
+
+    #[test]
+    fn draw_frame_hides_columns_and_compacts_rows_on_narrow_terminals() {
+        let mut row = sample_row();
+        row.description = "Some rather long description text".to_string();
+        row.last_log = "the last log line of this unit".to_string();
+        let rows = [row];
+        let mut columns = Column::DEFAULT.to_vec();
+        columns.extend([Column::Memory, Column::Type]);
+        let config = Config {
+            columns,
+            ..sample_config()
+        };
+        let render = |width| {
+            let mut terminal = Terminal::new(TestBackend::new(width, 10)).expect("terminal");
+            let mut state = TableState::default();
+            let detail = DetailState::default();
+            terminal
+                .draw(|f| {
+                    draw_frame(
+                        f,
+                        &mut state,
+                        &view(ViewMode::List, &rows, &detail, "services: 1"),
+                        &Overlays::default(),
+                        &config,
+                        &KeyHints::default(),
+                    )
+                })
+                .expect("draw");
+            rendered_text(&terminal)
+        };
+
+        let narrow = render(70);
+        assert!(narrow.contains("memory"));
+        assert!(!narrow.contains("type"));
+        assert!(narrow.contains("Some rather…"), "{narrow}");
+
+        let compact = render(30);
+        let lines: Vec<&str> = compact.lines().collect();
+        assert!(lines[2].contains("a.service active/running"));
+        assert!(lines[3].contains("  the last log line of t…"));
+        let areas = list_hit_areas(Rect::new(0, 0, 30, 10), &rows, &config);
+        assert_eq!(areas.row_height, 2);
+        assert_eq!(areas.row_at(areas.rows.x, areas.rows.y + 3), Some(1));
+        assert_eq!(
+            viewport_rows(Rect::new(0, 0, 30, 10), ViewMode::List, &config),
+            3
+        );
+    }
+
     #[test]
     fn draw_frame_renders_detail_mode() {
         let backend = TestBackend::new(120, 30);
@@ -300,31 +1958,83 @@ mod tests {
         detail.logs.push(crate::types::DetailLogEntry {
             time: "t".to_string(),
             log: "line".to_string(),
+            ..DetailLogEntry::default()
         });
         terminal
             .draw(|f| {
                 draw_frame(
                     f,
-                    ViewMode::Detail,
-                    "services",
-                    &[sample_row()],
-                    0,
                     &mut state,
-                    &detail,
-                    LoadPhase::Idle,
-                    true,
-                    false,
-                    None,
-                    false,
-                    "services: 1",
-                    false,
-                    None,
+                    &view(ViewMode::Detail, &[sample_row()], &detail, "services: 1"),
+                    &Overlays::default(),
                     &sample_config(),
+                    &KeyHints::default(),
                 )
             })
             .expect("draw");
     }
 
+    #[test]
+    fn draw_frame_shows_log_pane_next_to_list_in_split_layouts() {
+        let mut detail = DetailState::default();
+        let request_id = detail.begin_for_unit(Scope::System, "a.service".to_string());
+        detail.apply_loaded(
+            request_id,
+            "a.service",
+            vec![crate::types::DetailLogEntry {
+                time: "t".to_string(),
+                log: "pane line".to_string(),
+                ..DetailLogEntry::default()
+            }],
+        );
+        let render = |split| {
+            let mut terminal = Terminal::new(TestBackend::new(120, 20)).expect("terminal");
+            let mut state = TableState::default();
+            let config = Config {
+                split,
+                ..sample_config()
+            };
+            terminal
+                .draw(|f| {
+                    draw_frame(
+                        f,
+                        &mut state,
+                        &view(ViewMode::List, &[sample_row()], &detail, "services: 1"),
+                        &Overlays::default(),
+                        &config,
+                        &KeyHints::default(),
+                    )
+                })
+                .expect("draw");
+            rendered_text(&terminal)
+        };
+
+        assert!(!render(SplitLayout::Off).contains("logs for a.service"));
+        let below = render(SplitLayout::Below);
+        let lines: Vec<&str> = below.lines().collect();
+        let pane = lines
+            .iter()
+            .position(|line| line.contains("logs for a.service"))
+            .expect("log pane title");
+        assert!(pane >= 9);
+        assert!(lines[pane + 2].contains("pane line"));
+        let beside = render(SplitLayout::Beside);
+        let title = beside
+            .lines()
+            .find(|line| line.contains("logs for a.service"))
+            .expect("log pane title");
+        assert!(title.starts_with("┌systemd services"));
+        assert!(title.find("logs for").expect("title") >= 60);
+
+        let area = Rect::new(0, 0, 120, 20);
+        let config = Config {
+            split: SplitLayout::Below,
+            ..sample_config()
+        };
+        assert_eq!(viewport_rows(area, ViewMode::List, &config), 7);
+        assert_eq!(viewport_rows(area, ViewMode::Detail, &config), 16);
+    }
+
     #[test]
     fn draw_frame_renders_empty_no_match_and_error_states() {
         let backend = TestBackend::new(120, 30);
@@ -336,21 +2046,11 @@ mod tests {
             .draw(|f| {
                 draw_frame(
                     f,
-                    ViewMode::List,
-                    "services",
-                    &[],
-                    0,
                     &mut state,
-                    &detail,
-                    LoadPhase::Idle,
-                    true,
-                    false,
-                    None,
-                    false,
-                    "services: 0",
-                    false,
-                    None,
+                    &view(ViewMode::List, &[], &detail, "services: 0"),
+                    &Overlays::default(),
                     &sample_config(),
+                    &KeyHints::default(),
                 )
             })
             .expect("draw");
@@ -359,21 +2059,15 @@ mod tests {
             .draw(|f| {
                 draw_frame(
                     f,
-                    ViewMode::List,
-                    "services",
-                    &[],
-                    0,
                     &mut state,
-                    &detail,
-                    LoadPhase::Idle,
-                    true,
-                    true,
-                    Some("boom"),
-                    false,
-                    "services: 0",
-                    false,
-                    None,
+                    &ViewState {
+                        last_load_error: true,
+                        last_load_error_message: Some("boom"),
+                        ..view(ViewMode::List, &[], &detail, "services: 0")
+                    },
+                    &Overlays::default(),
                     &sample_config(),
+                    &KeyHints::default(),
                 )
             })
             .expect("draw");
@@ -389,21 +2083,15 @@ mod tests {
             .draw(|f| {
                 draw_frame(
                     f,
-                    ViewMode::List,
-                    "services",
-                    &[sample_row()],
-                    0,
                     &mut state,
-                    &detail,
-                    LoadPhase::Idle,
-                    true,
-                    true,
-                    Some("stale"),
-                    false,
-                    "services: 1",
-                    false,
-                    None,
+                    &ViewState {
+                        last_load_error: true,
+                        last_load_error_message: Some("stale"),
+                        ..view(ViewMode::List, &[sample_row()], &detail, "services: 1")
+                    },
+                    &Overlays::default(),
                     &sample_config(),
+                    &KeyHints::default(),
                 )
             })
             .expect("draw");
@@ -427,21 +2115,14 @@ mod tests {
             .draw(|f| {
                 draw_frame(
                     f,
-                    ViewMode::List,
-                    "services",
-                    &[sample_row()],
-                    0,
                     &mut state,
-                    &detail,
-                    LoadPhase::Idle,
-                    true,
-                    false,
-                    None,
-                    false,
-                    "services: 1",
-                    false,
-                    Some(&confirmation),
+                    &view(ViewMode::List, &[sample_row()], &detail, "services: 1"),
+                    &Overlays {
+                        confirmation: Some(&confirmation),
+                        ..Overlays::default()
+                    },
                     &sample_config(),
+                    &KeyHints::default(),
                 )
             })
             .expect("draw");
@@ -459,21 +2140,21 @@ mod tests {
             .draw(|f| {
                 draw_frame(
                     f,
-                    ViewMode::List,
-                    "services",
-                    &[sample_row()],
-                    0,
                     &mut state,
-                    &detail,
-                    LoadPhase::Idle,
-                    true,
-                    true,
-                    Some("stale"),
-                    false,
-                    "starting a.service...",
-                    true,
-                    None,
+                    &ViewState {
+                        last_load_error: true,
+                        last_load_error_message: Some("stale"),
+                        status_line_overrides_stale: true,
+                        ..view(
+                            ViewMode::List,
+                            &[sample_row()],
+                            &detail,
+                            "starting a.service...",
+                        )
+                    },
+                    &Overlays::default(),
                     &sample_config(),
+                    &KeyHints::default(),
                 )
             })
             .expect("draw");
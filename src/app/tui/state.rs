@@ -16,48 +16,492 @@
 
 //! Small state helpers for list status text generation.
 
-use crate::types::{ConfirmationKind, ConfirmationState};
+use super::{
+    input::{KeyBindings, KeyContext, UiCommand, key_sequence_label},
+    palette::PALETTE_USAGE,
+};
+use crate::types::{
+    AutoRefreshState, BootPickerState, ConfirmationKind, ConfirmationState, InspectorState,
+    InvocationPickerState, LogSearch, MachinePickerState, PaletteState, Target,
+};
+use std::time::Instant;
 
 /// Static mode label used by the list view.
 pub const MODE_LABEL: &str = "services";
 
-fn list_controls_text() -> &'static str {
-    "↑/↓: select | l/enter: inspect logs | s: start/restart/stop | e: enable/disable | r: refresh | q: quit"
+/// Footer and prompt key hints generated from the active bindings.
+#[derive(Debug, Clone)]
+pub struct KeyHints {
+    /// Key hints shown in the list view footer.
+    pub list_controls: String,
+    /// Key hints shown in the detail view footer.
+    pub detail_controls: String,
+    /// Key hints shown in the machine picker title.
+    pub machine_picker_controls: String,
+    /// Key hints shown in the boot picker title.
+    pub boot_picker_controls: String,
+    /// Key hints shown in the entry inspector title.
+    pub inspector_controls: String,
+    /// Key hints shown in the invocation picker title.
+    pub invocation_picker_controls: String,
+    confirm: String,
+    decline: String,
+    restart: String,
+    stop: String,
+    cancel_restart_or_stop: String,
+    /// Lines of the help overlay, listing every key binding.
+    pub help_lines: Vec<String>,
+    /// Key hints shown in the help overlay title.
+    pub help_controls: String,
+}
+
+impl Default for KeyHints {
+    fn default() -> Self {
+        Self::from_bindings(&KeyBindings::default())
+    }
+}
+
+impl KeyHints {
+    /// Describe the given bindings in footers, picker titles, prompts, and the help overlay.
+    pub fn from_bindings(bindings: &KeyBindings) -> Self {
+        let first = |context, command| {
+            bindings
+                .keys_for(context, command)
+                .first()
+                .map(|keys| key_sequence_label(keys))
+                .unwrap_or_else(|| "-".to_string())
+        };
+        Self {
+            list_controls: controls_text(
+                bindings,
+                KeyContext::List,
+                &[
+                    (UiCommand::OpenDetail, "inspect logs"),
+                    (UiCommand::RequestStartStop, "start/restart/stop"),
+                    (UiCommand::RequestEnableDisable, "enable/disable"),
+                    (UiCommand::RequestPreset, "preset"),
+                    (UiCommand::CycleSort, "sort"),
+                    (UiCommand::ReverseSort, "reverse"),
+                    (UiCommand::OpenMachinePicker, "machine"),
+                    (UiCommand::OpenBootPicker, "boot"),
+                    (UiCommand::ToggleMark, "mark"),
+                    (UiCommand::OpenTimeline, "timeline"),
+                    (UiCommand::CycleSplit, "split"),
+                    (UiCommand::Refresh, "refresh"),
+                    (UiCommand::ToggleAutoRefresh, "auto"),
+                    (UiCommand::OpenPalette, "command"),
+                    (UiCommand::ToggleHelp, "help"),
+                    (UiCommand::Quit, "quit"),
+                ],
+                "select",
+            ),
+            detail_controls: controls_text(
+                bindings,
+                KeyContext::Detail,
+                &[
+                    (UiCommand::BackToList, "back"),
+                    (UiCommand::InspectEntry, "inspect"),
+                    (UiCommand::OpenInvocationPicker, "runs"),
+                    (UiCommand::ToggleWrap, "wrap"),
+                    (UiCommand::OpenSearch, "search"),
+                    (UiCommand::Refresh, "refresh"),
+                    (UiCommand::ToggleAutoRefresh, "auto"),
+                    (UiCommand::OpenPalette, "command"),
+                    (UiCommand::ToggleHelp, "help"),
+                    (UiCommand::Quit, "quit"),
+                ],
+                "scroll",
+            ),
+            machine_picker_controls: format!(
+                "{}: select | {}: cancel",
+                first(KeyContext::MachinePicker, UiCommand::Confirm),
+                first(KeyContext::MachinePicker, UiCommand::Cancel)
+            ),
+            boot_picker_controls: format!(
+                "{}: select | {}: cancel",
+                first(KeyContext::BootPicker, UiCommand::Confirm),
+                first(KeyContext::BootPicker, UiCommand::Cancel)
+            ),
+            inspector_controls: controls_text(
+                bindings,
+                KeyContext::Inspector,
+                &[(UiCommand::Cancel, "close")],
+                "scroll",
+            ),
+            invocation_picker_controls: format!(
+                "{}: jump | {}: cancel",
+                first(KeyContext::InvocationPicker, UiCommand::Confirm),
+                first(KeyContext::InvocationPicker, UiCommand::Cancel)
+            ),
+            confirm: first(KeyContext::ConfirmAction, UiCommand::Confirm),
+            decline: first(KeyContext::ConfirmAction, UiCommand::Cancel),
+            restart: first(KeyContext::RestartOrStop, UiCommand::ChooseRestart),
+            stop: first(KeyContext::RestartOrStop, UiCommand::ChooseStop),
+            cancel_restart_or_stop: first(KeyContext::RestartOrStop, UiCommand::Cancel),
+            help_lines: help_lines(bindings),
+            help_controls: controls_text(
+                bindings,
+                KeyContext::Help,
+                &[(UiCommand::Cancel, "close")],
+                "scroll",
+            ),
+        }
+    }
+}
+
+/// Join hints as `keys: label`; the up/down pair leads as `up/down: move_label`.
+fn controls_text(
+    bindings: &KeyBindings,
+    context: KeyContext,
+    entries: &[(UiCommand, &str)],
+    move_label: &str,
+) -> String {
+    let all_keys = |command| {
+        bindings
+            .keys_for(context, command)
+            .iter()
+            .map(|keys| key_sequence_label(keys))
+            .collect::<Vec<_>>()
+    };
+    let mut parts = Vec::new();
+    let up = all_keys(UiCommand::MoveUp);
+    let down = all_keys(UiCommand::MoveDown);
+    if let (Some(up), Some(down)) = (up.first(), down.first()) {
+        parts.push(format!("{up}/{down}: {move_label}"));
+    }
+    for (command, label) in entries {
+        let keys = all_keys(*command);
+        if !keys.is_empty() {
+            parts.push(format!("{}: {label}", keys.join("/")));
+        }
+    }
+    parts.join(" | ")
+}
+
+/// List every binding per context, followed by the mouse actions.
+fn help_lines(bindings: &KeyBindings) -> Vec<String> {
+    let mut lines = Vec::new();
+    for context in KeyContext::ALL {
+        let commands = bindings.commands_in(context);
+        if commands.is_empty() {
+            continue;
+        }
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.push(format!("{}:", context.label()));
+        for (command, keys) in commands {
+            let keys: Vec<String> = keys.iter().map(|keys| key_sequence_label(keys)).collect();
+            lines.push(format!(
+                "  {:<16} {}",
+                keys.join("/"),
+                command.description()
+            ));
+        }
+    }
+    lines.extend([
+        String::new(),
+        "mouse:".to_string(),
+        format!(
+            "  {:<16} {}",
+            "click", "select a unit; on a header, sort by it"
+        ),
+        format!("  {:<16} {}", "double-click", "open logs of the unit"),
+        format!("  {:<16} {}", "wheel", "move through the list or logs"),
+        String::new(),
+        "commands (:):".to_string(),
+    ]);
+    lines.extend(
+        PALETTE_USAGE
+            .iter()
+            .map(|(usage, description)| format!("  {usage:<16} {description}")),
+    );
+    lines
+}
+
+/// Build the list/detail block title, naming the machine or host when one is targeted.
+pub fn view_title(mode_label: &str, target: &Target) -> String {
+    match target.machine.as_ref().or(target.host.as_ref()) {
+        Some(name) => format!("systemd {mode_label} on {name}"),
+        None => format!("systemd {mode_label}"),
+    }
+}
+
+/// Return the leading part of a boot ID, enough to tell boots apart on screen.
+pub fn short_boot_id(id: &str) -> &str {
+    id.get(..8).unwrap_or(id)
+}
+
+/// Append the selected boot to a block title; `None` (every boot) leaves it unchanged.
+pub fn with_boot(title: String, boot: Option<&str>) -> String {
+    match boot {
+        Some(id) => format!("{title} in boot {}", short_boot_id(id)),
+        None => title,
+    }
+}
+
+/// Build the lines shown in the machine picker overlay.
+pub fn machine_picker_lines(picker: &MachinePickerState) -> Vec<String> {
+    if picker.loading {
+        return vec!["loading machines...".to_string()];
+    }
+    if let Some(err) = &picker.error {
+        return vec![format!("error: {err}")];
+    }
+    picker
+        .entries
+        .iter()
+        .map(|entry| entry.clone().unwrap_or_else(|| "local host".to_string()))
+        .collect()
+}
+
+/// Build the lines shown in the boot picker: "all boots", then each boot's offset and time span.
+pub fn boot_picker_lines(picker: &BootPickerState) -> Vec<String> {
+    if picker.loading {
+        return vec!["loading boots...".to_string()];
+    }
+    if let Some(err) = &picker.error {
+        return vec![format!("error: {err}")];
+    }
+    picker
+        .entries
+        .iter()
+        .map(|entry| match entry {
+            Some(boot) => format!("{:>3}  {}", boot.index, boot.span),
+            None => "all boots".to_string(),
+        })
+        .collect()
+}
+
+/// Build the lines shown in the invocation picker: start time, exit result, and size of each run.
+pub fn invocation_picker_lines(picker: &InvocationPickerState) -> Vec<String> {
+    if picker.entries.is_empty() {
+        return vec!["no invocation IDs in the loaded logs".to_string()];
+    }
+    picker
+        .entries
+        .iter()
+        .map(|run| {
+            let lines = run.last - run.first + 1;
+            format!(
+                "{}  {}  ({lines} {})",
+                run.start,
+                run.result.as_deref().unwrap_or("no exit recorded"),
+                if lines == 1 { "line" } else { "lines" }
+            )
+        })
+        .collect()
+}
+
+/// Build the lines shown in the entry inspector: every field, then the catalog text.
+pub fn inspector_lines(inspector: &InspectorState) -> Vec<String> {
+    if let Some(err) = &inspector.error {
+        return vec![format!("error: {err}")];
+    }
+    let Some(entry) = &inspector.entry else {
+        return vec!["loading entry...".to_string()];
+    };
+    let mut lines: Vec<String> = entry
+        .fields
+        .iter()
+        .map(|(name, value)| format!("{name}={value}"))
+        .collect();
+    if let Some(catalog) = &entry.catalog {
+        lines.push(String::new());
+        lines.push("catalog:".to_string());
+        lines.extend(catalog.lines().map(str::to_string));
+    }
+    lines
+}
+
+/// Build the footer line of the open command palette or search prompt.
+///
+/// The search prompt also lists the modes of `search`.
+pub fn palette_prompt_text(palette: &PaletteState, search: &LogSearch) -> String {
+    let (prefix, modes) = if palette.search {
+        ('/', format!("   {}", search.modes_text()))
+    } else {
+        (':', String::new())
+    };
+    match &palette.message {
+        Some(message) => format!("{prefix}{}{modes}   {message}", palette.input),
+        None => format!("{prefix}{}{modes}", palette.input),
+    }
 }
 
 /// Build a list footer status text for idle, loading, and log-progress phases.
-pub fn list_status_text(rows: usize, logs_progress: Option<(usize, usize)>) -> String {
+pub fn list_status_text(
+    rows: usize,
+    logs_progress: Option<(usize, usize)>,
+    hints: &KeyHints,
+) -> String {
+    list_status_with_scope_error_text(rows, logs_progress, None, hints)
+}
+
+/// Build a list footer status text, naming a listed scope that failed to load.
+pub fn list_status_with_scope_error_text(
+    rows: usize,
+    logs_progress: Option<(usize, usize)>,
+    scope_error: Option<&str>,
+    hints: &KeyHints,
+) -> String {
+    let error = scope_error
+        .map(|err| format!(" | failed to list {err}"))
+        .unwrap_or_default();
     match logs_progress {
         Some((done, total)) if done < total => format!(
-            "{MODE_LABEL}: {rows} | logs: {done}/{total} | {}",
-            list_controls_text()
+            "{MODE_LABEL}: {rows}{error} | logs: {done}/{total} | {}",
+            hints.list_controls
         ),
-        Some(_) => format!("{MODE_LABEL}: {rows} | {}", list_controls_text()),
-        None => format!("{MODE_LABEL}: {rows} | {}", list_controls_text()),
+        Some(_) => format!("{MODE_LABEL}: {rows}{error} | {}", hints.list_controls),
+        None => format!("{MODE_LABEL}: {rows}{error} | {}", hints.list_controls),
     }
 }
 
 /// Build the stale-data status text after a failed refresh.
-pub fn stale_status_text(rows: usize) -> String {
-    stale_status_with_error_text(rows, None)
+pub fn stale_status_text(rows: usize, hints: &KeyHints) -> String {
+    stale_status_with_error_text(rows, None, hints)
 }
 
 /// Build the stale-data status text after a failed refresh, optionally including the error text.
-pub fn stale_status_with_error_text(rows: usize, error: Option<&str>) -> String {
+pub fn stale_status_with_error_text(rows: usize, error: Option<&str>, hints: &KeyHints) -> String {
     let error_suffix = error
         .filter(|err| !err.trim().is_empty())
         .map(|err| format!(": {err}"))
         .unwrap_or_default();
     format!(
         "{MODE_LABEL}: {rows} | refresh failed (stale data){} | {}",
-        error_suffix,
-        list_controls_text(),
+        error_suffix, hints.list_controls,
     )
 }
 
 /// Build the loading status text shown while units are being fetched.
-pub fn loading_units_status_text() -> String {
-    format!("{MODE_LABEL}: loading units... | {}", list_controls_text())
+pub fn loading_units_status_text(hints: &KeyHints) -> String {
+    format!("{MODE_LABEL}: loading units... | {}", hints.list_controls)
+}
+
+/// Return the index reached by a page or jump command.
+///
+/// `last` is the highest reachable index and `page` the number of visible rows;
+/// other commands keep `current`.
+pub fn jump_target(current: usize, last: usize, command: UiCommand, page: usize) -> usize {
+    let page = page.max(1);
+    let half = (page / 2).max(1);
+    let target = match command {
+        UiCommand::PageDown => current.saturating_add(page),
+        UiCommand::PageUp => current.saturating_sub(page),
+        UiCommand::HalfPageDown => current.saturating_add(half),
+        UiCommand::HalfPageUp => current.saturating_sub(half),
+        UiCommand::JumpTop => 0,
+        UiCommand::JumpBottom => last,
+        _ => current,
+    };
+    target.min(last)
+}
+
+/// Return the detail scroll offset reached by a page or jump command.
+///
+/// `heights` holds the screen lines each log entry takes and `page` the visible
+/// lines, so wrapped entries page by what was on screen. Scrolling stops once the
+/// last page is fully visible; other commands keep `current`.
+pub fn detail_scroll_target(
+    current: usize,
+    heights: &[usize],
+    command: UiCommand,
+    page: usize,
+) -> usize {
+    let page = page.max(1);
+    let half = (page / 2).max(1);
+    // First entry of the page that ends with the last entry.
+    let mut last = heights.len();
+    let mut used = 0;
+    while last > 0 && used + heights[last - 1] <= page {
+        last -= 1;
+        used += heights[last];
+    }
+    let last = last.min(heights.len().saturating_sub(1)).max(current);
+
+    let forward = |lines: usize| {
+        let mut idx = current;
+        let mut used = 0;
+        while idx < last && used + heights[idx] <= lines {
+            used += heights[idx];
+            idx += 1;
+        }
+        // An entry taller than the page still moves on by one.
+        if idx == current {
+            (current + 1).min(last)
+        } else {
+            idx
+        }
+    };
+    let backward = |lines: usize| {
+        let mut idx = current;
+        let mut used = 0;
+        while idx > 0 && used + heights[idx - 1] <= lines {
+            idx -= 1;
+            used += heights[idx];
+        }
+        if idx == current {
+            current.saturating_sub(1)
+        } else {
+            idx
+        }
+    };
+    let target = match command {
+        UiCommand::PageDown => forward(page),
+        UiCommand::PageUp => backward(page),
+        UiCommand::HalfPageDown => forward(half),
+        UiCommand::HalfPageUp => backward(half),
+        UiCommand::JumpTop => 0,
+        UiCommand::JumpBottom => last,
+        _ => current,
+    };
+    target.min(last)
+}
+
+/// Return the detail cursor after a page or jump command scrolled from `previous` to `scroll`.
+///
+/// Jumps put the cursor on the first or last entry. Paging moves it along with the view,
+/// or to the edge of the page when the view cannot move, and keeps it among the entries
+/// that fit in `page` lines of `heights`.
+pub fn detail_cursor_target(
+    cursor: usize,
+    previous: usize,
+    scroll: usize,
+    heights: &[usize],
+    command: UiCommand,
+    page: usize,
+) -> usize {
+    let mut last = scroll;
+    let mut used = heights.get(scroll).copied().unwrap_or(0);
+    while last + 1 < heights.len() && used + heights[last + 1] <= page.max(1) {
+        last += 1;
+        used += heights[last];
+    }
+    let moved = match command {
+        UiCommand::JumpTop => 0,
+        UiCommand::JumpBottom => usize::MAX,
+        UiCommand::PageUp | UiCommand::HalfPageUp if scroll == previous => 0,
+        UiCommand::PageDown | UiCommand::HalfPageDown if scroll == previous => usize::MAX,
+        _ => (cursor + scroll).saturating_sub(previous),
+    };
+    moved.clamp(scroll, last)
+}
+
+/// Build the auto-refresh countdown shown at the right edge of the footer.
+pub fn auto_refresh_text(auto_refresh: &AutoRefreshState, now: Instant) -> Option<String> {
+    if !auto_refresh.enabled {
+        return None;
+    }
+    let countdown = match auto_refresh.remaining(now) {
+        Some(left) => format!("auto {}s", left.as_secs_f64().ceil() as u64),
+        None => "auto ...".to_string(),
+    };
+    Some(match auto_refresh.consecutive_errors {
+        0 => countdown,
+        failed => format!("{countdown} ({failed} failed)"),
+    })
 }
 
 /// Build the footer status text shown while an action prompt is being resolved.
@@ -83,12 +527,13 @@ pub fn action_queued_status_text(
     rows: usize,
     action: crate::types::UnitAction,
     unit: &str,
+    hints: &KeyHints,
 ) -> String {
     format!(
         "{MODE_LABEL}: {rows} | queued {} for {} | {}",
         action.as_systemctl_arg(),
         unit,
-        list_controls_text()
+        hints.list_controls
     )
 }
 
@@ -98,37 +543,43 @@ pub fn action_error_status_text(
     action: crate::types::UnitAction,
     unit: &str,
     error: &str,
+    hints: &KeyHints,
 ) -> String {
     format!(
         "{MODE_LABEL}: {rows} | failed to {} {}: {} | {}",
         action.as_systemctl_arg(),
         unit,
         error,
-        list_controls_text()
+        hints.list_controls
     )
 }
 
 /// Build the footer status text after resolving an action target fails.
-pub fn action_resolution_error_status_text(rows: usize, unit: &str, error: &str) -> String {
+pub fn action_resolution_error_status_text(
+    rows: usize,
+    unit: &str,
+    error: &str,
+    hints: &KeyHints,
+) -> String {
     format!(
         "{MODE_LABEL}: {rows} | failed to inspect {}: {} | {}",
-        unit,
-        error,
-        list_controls_text()
+        unit, error, hints.list_controls
     )
 }
 
 /// Build the confirmation prompt shown before a unit action executes.
-pub fn confirmation_prompt_text(confirmation: &ConfirmationState) -> String {
+pub fn confirmation_prompt_text(confirmation: &ConfirmationState, hints: &KeyHints) -> String {
     match confirmation.kind {
         ConfirmationKind::ConfirmAction(action) => format!(
-            "confirm {} of unit {} (y/n)",
+            "confirm {} of unit {} ({}/{})",
             action.prompt_verb(),
-            confirmation.unit
+            confirmation.unit,
+            hints.confirm,
+            hints.decline
         ),
         ConfirmationKind::RestartOrStop => format!(
-            "unit {} is running: (r) restart or (s) stop or (esc) cancel",
-            confirmation.unit
+            "unit {} is running: ({}) restart or ({}) stop or ({}) cancel",
+            confirmation.unit, hints.restart, hints.stop, hints.cancel_restart_or_stop
         ),
     }
 }
@@ -136,25 +587,310 @@ pub fn confirmation_prompt_text(confirmation: &ConfirmationState) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{ConfirmationState, UnitAction};
+    use crate::types::{
+        ConfirmationState, DetailLogEntry, DetailState, JournalEntry, Scope, UnitAction,
+    };
 
     #[test]
     fn list_status_text_formats_logs_progress() {
-        let s = list_status_text(12, Some((3, 12)));
+        let s = list_status_text(12, Some((3, 12)), &KeyHints::default());
         assert!(s.contains("services: 12"));
         assert!(s.contains("logs: 3/12"));
         assert!(s.contains("s: start/restart/stop"));
     }
 
+    #[test]
+    fn list_status_with_scope_error_text_names_the_failed_scope() {
+        let s = list_status_with_scope_error_text(
+            3,
+            Some((1, 3)),
+            Some("user scope: no bus"),
+            &KeyHints::default(),
+        );
+        assert!(s.starts_with("services: 3 | failed to list user scope: no bus | logs: 1/3 | "));
+    }
+
+    #[test]
+    fn help_lines_list_every_bound_command_per_context() {
+        let bindings = KeyBindings::default();
+        let lines = help_lines(&bindings);
+        assert_eq!(lines.first().map(String::as_str), Some("list:"));
+        assert!(lines.contains(&"detail:".to_string()));
+        assert!(lines.contains(&format!("  {:<16} {}", "home/g", "jump to the top")));
+        for context in KeyContext::ALL {
+            for (command, _) in bindings.commands_in(context) {
+                assert!(
+                    lines
+                        .iter()
+                        .any(|line| line.ends_with(command.description())),
+                    "{} missing",
+                    command.config_name()
+                );
+            }
+        }
+
+        let overrides =
+            std::collections::BTreeMap::from([("refresh".to_string(), vec!["f5".to_string()])]);
+        let lines = help_lines(&KeyBindings::with_overrides(&overrides).expect("valid"));
+        assert!(lines.contains(&format!("  {:<16} {}", "f5", "refresh now")));
+        assert!(lines.contains(&"commands (:):".to_string()));
+        assert!(lines.contains(&format!("  {:<16} {}", "goto <text>", PALETTE_USAGE[5].1)));
+    }
+
+    #[test]
+    fn palette_prompt_text_shows_input_and_message() {
+        let search = LogSearch::default();
+        let mut palette = PaletteState::default();
+        palette.push('s');
+        assert_eq!(palette_prompt_text(&palette, &search), ":s");
+        palette.message = Some("scope sort".to_string());
+        assert_eq!(palette_prompt_text(&palette, &search), ":s   scope sort");
+
+        let mut palette = PaletteState::search();
+        palette.push('x');
+        assert_eq!(
+            palette_prompt_text(&palette, &search),
+            "/x   [text, match case]"
+        );
+        let search = LogSearch {
+            regex: true,
+            ignore_case: true,
+            ..LogSearch::default()
+        };
+        palette.message = Some("invalid pattern".to_string());
+        assert_eq!(
+            palette_prompt_text(&palette, &search),
+            "/x   [regex, ignore case]   invalid pattern"
+        );
+    }
+
+    #[test]
+    fn jump_target_pages_and_clamps() {
+        assert_eq!(jump_target(5, 99, UiCommand::PageDown, 20), 25);
+        assert_eq!(jump_target(95, 99, UiCommand::PageDown, 20), 99);
+        assert_eq!(jump_target(5, 99, UiCommand::PageUp, 20), 0);
+        assert_eq!(jump_target(30, 99, UiCommand::HalfPageUp, 20), 20);
+        assert_eq!(jump_target(30, 99, UiCommand::HalfPageDown, 1), 31);
+        assert_eq!(jump_target(30, 99, UiCommand::JumpTop, 20), 0);
+        assert_eq!(jump_target(30, 99, UiCommand::JumpBottom, 20), 99);
+        assert_eq!(jump_target(3, 0, UiCommand::PageDown, 0), 0);
+        assert_eq!(jump_target(30, 99, UiCommand::Quit, 20), 30);
+    }
+
+    #[test]
+    fn detail_scroll_target_accounts_for_wrapped_entries() {
+        // One line per entry behaves like plain paging.
+        let flat = [1; 10];
+        assert_eq!(detail_scroll_target(0, &flat, UiCommand::JumpBottom, 4), 6);
+        assert_eq!(detail_scroll_target(6, &flat, UiCommand::PageUp, 4), 2);
+        assert_eq!(detail_scroll_target(2, &flat, UiCommand::PageDown, 4), 6);
+        assert_eq!(detail_scroll_target(6, &flat, UiCommand::PageDown, 4), 6);
+
+        let wrapped = [3, 1, 2, 4, 1, 1];
+        assert_eq!(detail_scroll_target(0, &wrapped, UiCommand::PageDown, 4), 2);
+        assert_eq!(detail_scroll_target(2, &wrapped, UiCommand::PageDown, 4), 3);
+        assert_eq!(detail_scroll_target(3, &wrapped, UiCommand::PageUp, 4), 1);
+        assert_eq!(
+            detail_scroll_target(0, &wrapped, UiCommand::JumpBottom, 4),
+            4
+        );
+        assert_eq!(
+            detail_scroll_target(3, &wrapped, UiCommand::HalfPageUp, 4),
+            2
+        );
+        // Entries taller than the page still move one at a time.
+        assert_eq!(
+            detail_scroll_target(0, &[9, 9, 9], UiCommand::PageDown, 4),
+            1
+        );
+        assert_eq!(detail_scroll_target(2, &[9, 9, 9], UiCommand::PageUp, 4), 1);
+        assert_eq!(detail_scroll_target(0, &[], UiCommand::PageDown, 4), 0);
+    }
+
+    #[test]
+    fn detail_cursor_target_follows_paging_and_stays_on_screen() {
+        let flat = [1; 10];
+        assert_eq!(
+            detail_cursor_target(1, 0, 4, &flat, UiCommand::PageDown, 4),
+            5
+        );
+        assert_eq!(
+            detail_cursor_target(7, 6, 6, &flat, UiCommand::PageDown, 4),
+            9
+        );
+        assert_eq!(
+            detail_cursor_target(2, 0, 0, &flat, UiCommand::PageUp, 4),
+            0
+        );
+        assert_eq!(
+            detail_cursor_target(9, 6, 0, &flat, UiCommand::JumpTop, 4),
+            0
+        );
+        assert_eq!(
+            detail_cursor_target(0, 0, 6, &flat, UiCommand::JumpBottom, 4),
+            9
+        );
+        // Only entries that fit in the page keep the cursor.
+        let wrapped = [3, 1, 2, 4, 1, 1];
+        assert_eq!(
+            detail_cursor_target(1, 0, 2, &wrapped, UiCommand::PageDown, 4),
+            2
+        );
+        assert_eq!(
+            detail_cursor_target(0, 0, 0, &[], UiCommand::PageDown, 4),
+            0
+        );
+    }
+
+    #[test]
+    fn auto_refresh_text_shows_countdown_and_failures() {
+        let now = Instant::now();
+        let mut auto = AutoRefreshState::new(None);
+        assert_eq!(auto_refresh_text(&auto, now), None);
+        auto.toggle(now);
+        assert_eq!(auto_refresh_text(&auto, now).as_deref(), Some("auto 5s"));
+        auto.begin_refresh();
+        assert_eq!(auto_refresh_text(&auto, now).as_deref(), Some("auto ..."));
+        auto.record_error(now);
+        assert_eq!(
+            auto_refresh_text(&auto, now).as_deref(),
+            Some("auto 10s (1 failed)")
+        );
+    }
+
+    #[test]
+    fn view_title_names_target_machine() {
+        assert_eq!(
+            view_title(MODE_LABEL, &Target::new(Scope::System)),
+            "systemd services"
+        );
+        let target = Target {
+            machine: Some("web".to_string()),
+            ..Target::new(Scope::System)
+        };
+        assert_eq!(view_title(MODE_LABEL, &target), "systemd services on web");
+        let remote = Target {
+            host: Some("ops@db1".to_string()),
+            ..Target::new(Scope::System)
+        };
+        assert_eq!(
+            view_title(MODE_LABEL, &remote),
+            "systemd services on ops@db1"
+        );
+    }
+
+    #[test]
+    fn with_boot_names_the_selected_boot() {
+        assert_eq!(
+            with_boot("logs".to_string(), Some("0123456789abcdef0123456789abcdef")),
+            "logs in boot 01234567"
+        );
+        assert_eq!(with_boot("logs".to_string(), None), "logs");
+    }
+
+    #[test]
+    fn boot_picker_lines_cover_loading_error_and_entries() {
+        let mut picker = BootPickerState::loading(None);
+        assert_eq!(boot_picker_lines(&picker), vec!["loading boots..."]);
+        picker.apply_loaded(vec![crate::types::Boot {
+            index: -1,
+            id: "0123456789abcdef0123456789abcdef".to_string(),
+            span: "Mon 2026-02-23 08:00:00 UTC Mon 2026-02-23 20:00:00 UTC".to_string(),
+        }]);
+        assert_eq!(
+            boot_picker_lines(&picker),
+            vec![
+                "all boots",
+                " -1  Mon 2026-02-23 08:00:00 UTC Mon 2026-02-23 20:00:00 UTC"
+            ]
+        );
+        picker.apply_error("boom".to_string());
+        assert_eq!(boot_picker_lines(&picker), vec!["error: boom"]);
+    }
+
+    #[test]
+    fn machine_picker_lines_cover_loading_error_and_entries() {
+        let mut picker = MachinePickerState::loading(None);
+        assert_eq!(machine_picker_lines(&picker), vec!["loading machines..."]);
+        picker.apply_loaded(vec!["web".to_string()]);
+        assert_eq!(machine_picker_lines(&picker), vec!["local host", "web"]);
+        picker.apply_error("boom".to_string());
+        assert_eq!(machine_picker_lines(&picker), vec!["error: boom"]);
+    }
+
+    #[test]
+    fn invocation_picker_lines_show_start_result_and_size() {
+        let entry = |invocation: &str, time: &str, exit: Option<&str>| DetailLogEntry {
+            time: time.to_string(),
+            log: "line".to_string(),
+            invocation: Some(invocation.to_string()),
+            exit: exit.map(str::to_string),
+            ..DetailLogEntry::default()
+        };
+        let mut detail = DetailState::default();
+        detail.logs = vec![
+            entry("b", "10:00:05", None),
+            entry("a", "10:00:03", Some("result=exit-code")),
+            entry("a", "10:00:02", None),
+        ];
+        detail.scroll = 2;
+        let picker = InvocationPickerState::for_detail(&detail);
+        assert_eq!(picker.selected, 1);
+        assert_eq!(
+            invocation_picker_lines(&picker),
+            vec![
+                "10:00:05  no exit recorded  (1 line)",
+                "10:00:02  result=exit-code  (2 lines)",
+            ]
+        );
+        assert_eq!(
+            invocation_picker_lines(&InvocationPickerState::default()),
+            vec!["no invocation IDs in the loaded logs"]
+        );
+    }
+
+    #[test]
+    fn inspector_lines_list_fields_then_catalog() {
+        let entry = DetailLogEntry {
+            time: "2026-01-01T12:00:00+0000".to_string(),
+            log: "started".to_string(),
+            ..DetailLogEntry::default()
+        };
+        let mut inspector = InspectorState::loading(&entry);
+        assert_eq!(inspector.title, "2026-01-01T12:00:00+0000 started");
+        assert_eq!(inspector_lines(&inspector), vec!["loading entry..."]);
+        inspector.apply_loaded(JournalEntry {
+            fields: vec![
+                ("MESSAGE".to_string(), "started".to_string()),
+                ("_PID".to_string(), "42".to_string()),
+            ],
+            catalog: Some("Subject: started\n\nThe unit started.".to_string()),
+        });
+        assert_eq!(
+            inspector_lines(&inspector),
+            vec![
+                "MESSAGE=started",
+                "_PID=42",
+                "",
+                "catalog:",
+                "Subject: started",
+                "",
+                "The unit started.",
+            ]
+        );
+        inspector.apply_error("not found".to_string());
+        assert_eq!(inspector_lines(&inspector), vec!["error: not found"]);
+    }
+
     #[test]
     fn stale_status_text_mentions_stale_data() {
-        let s = stale_status_text(4);
+        let s = stale_status_text(4, &KeyHints::default());
         assert!(s.contains("refresh failed (stale data)"));
     }
 
     #[test]
     fn stale_status_with_error_text_mentions_error_and_action_keys() {
-        let s = stale_status_with_error_text(4, Some("boom"));
+        let s = stale_status_with_error_text(4, Some("boom"), &KeyHints::default());
         assert!(s.contains("refresh failed (stale data): boom"));
         assert!(s.contains("s: start/restart/stop"));
         assert!(s.contains("e: enable/disable"));
@@ -162,16 +898,16 @@ mod tests {
 
     #[test]
     fn loading_units_status_text_mentions_loading() {
-        let s = loading_units_status_text();
+        let s = loading_units_status_text(&KeyHints::default());
         assert!(s.contains("loading units"));
     }
 
     #[test]
     fn confirmation_prompt_text_matches_requested_copy() {
-        let s = confirmation_prompt_text(&ConfirmationState::confirm_action(
-            UnitAction::Disable,
-            "foobar.service".to_string(),
-        ));
+        let s = confirmation_prompt_text(
+            &ConfirmationState::confirm_action(UnitAction::Disable, "foobar.service".to_string()),
+            &KeyHints::default(),
+        );
         assert_eq!(s, "confirm disabling of unit foobar.service (y/n)");
     }
 
@@ -189,29 +925,87 @@ mod tests {
 
     #[test]
     fn action_queued_and_error_status_include_controls() {
-        let queued = action_queued_status_text(4, UnitAction::Enable, "demo.service");
+        let queued =
+            action_queued_status_text(4, UnitAction::Enable, "demo.service", &KeyHints::default());
         assert!(queued.contains("queued enable for demo.service"));
         assert!(queued.contains("e: enable/disable"));
 
-        let error = action_error_status_text(4, UnitAction::Stop, "demo.service", "boom");
+        let error = action_error_status_text(
+            4,
+            UnitAction::Stop,
+            "demo.service",
+            "boom",
+            &KeyHints::default(),
+        );
         assert!(error.contains("failed to stop demo.service: boom"));
         assert!(error.contains("s: start/restart/stop"));
     }
 
     #[test]
     fn action_resolution_error_status_mentions_unit() {
-        let s = action_resolution_error_status_text(2, "demo.service", "state error");
+        let s = action_resolution_error_status_text(
+            2,
+            "demo.service",
+            "state error",
+            &KeyHints::default(),
+        );
         assert!(s.contains("failed to inspect demo.service: state error"));
     }
 
     #[test]
     fn confirmation_prompt_text_for_running_unit_offers_restart_or_stop() {
-        let s = confirmation_prompt_text(&ConfirmationState::restart_or_stop(
-            "foobar.service".to_string(),
-        ));
+        let s = confirmation_prompt_text(
+            &ConfirmationState::restart_or_stop("foobar.service".to_string()),
+            &KeyHints::default(),
+        );
         assert_eq!(
             s,
             "unit foobar.service is running: (r) restart or (s) stop or (esc) cancel"
         );
     }
+
+    #[test]
+    fn key_hints_are_generated_from_bindings() {
+        let defaults = KeyHints::from_bindings(&KeyBindings::default());
+        assert_eq!(
+            defaults.list_controls,
+            "↑/↓: select | l/enter: inspect logs | s: start/restart/stop | e: enable/disable | p: preset | o: sort | O: reverse | m: machine | B: boot | space: mark | T: timeline | v: split | r: refresh | a: auto | :: command | ?: help | q: quit"
+        );
+        assert_eq!(
+            defaults.detail_controls,
+            "↑/↓: scroll | b/esc: back | enter: inspect | i: runs | w: wrap | /: search | r: refresh | a: auto | :: command | ?: help | q: quit"
+        );
+        assert_eq!(
+            defaults.machine_picker_controls,
+            "enter: select | esc: cancel"
+        );
+        assert_eq!(defaults.boot_picker_controls, "enter: select | esc: cancel");
+        assert_eq!(
+            defaults.invocation_picker_controls,
+            "enter: jump | esc: cancel"
+        );
+        assert_eq!(
+            defaults.inspector_controls,
+            "↑/↓: scroll | esc/enter: close"
+        );
+
+        let overrides = std::collections::BTreeMap::from([
+            (
+                "move_down".to_string(),
+                vec!["j".to_string(), "down".to_string()],
+            ),
+            ("move_up".to_string(), vec!["k".to_string()]),
+            ("quit".to_string(), vec!["Z Z".to_string()]),
+            ("machine_picker".to_string(), vec![]),
+            ("confirm".to_string(), vec!["enter".to_string()]),
+        ]);
+        let hints =
+            KeyHints::from_bindings(&KeyBindings::with_overrides(&overrides).expect("valid"));
+        assert_eq!(
+            hints.list_controls,
+            "k/j: select | l/enter: inspect logs | s: start/restart/stop | e: enable/disable | p: preset | o: sort | O: reverse | B: boot | space: mark | T: timeline | v: split | r: refresh | a: auto | :: command | ?: help | ZZ: quit"
+        );
+        assert_eq!(hints.confirm, "enter");
+        assert_eq!(hints.decline, "n");
+    }
 }
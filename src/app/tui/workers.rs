@@ -19,59 +19,155 @@
 use std::{
     sync::mpsc::{self, Receiver},
     thread,
+    time::Instant,
 };
 
 #[cfg(feature = "debug_tui")]
 use super::debug::{
-    spawn_debug_action_resolution_worker, spawn_debug_detail_worker, spawn_debug_refresh_worker,
+    spawn_debug_action_resolution_worker, spawn_debug_boot_list_worker, spawn_debug_detail_worker,
+    spawn_debug_entry_worker, spawn_debug_machine_list_worker, spawn_debug_refresh_worker,
+    spawn_debug_timeline_worker,
 };
 #[cfg(test)]
-use crate::types::{Scope, SortMode};
+use crate::types::{JournalSource, Scope, SortMode};
 use crate::{
     cli::Config,
-    journal::{fetch_unit_logs, latest_log_lines_batch},
-    rows::{build_rows, seed_logs_from_previous, sort_rows},
+    journal::{
+        fetch_boots, fetch_journal_units, fetch_log_entry, fetch_timeline_logs, fetch_unit_logs,
+        latest_log_lines_batch,
+    },
+    rows::{build_rows, seed_from_previous, sort_rows},
     systemd::{
-        fetch_services, fetch_unit_files, filter_services, merge_unit_file_entries,
-        select_enable_disable_action, select_start_stop_action, should_fetch_all,
+        fetch_machines, fetch_services, fetch_unit_files, fetch_unit_properties, filter_services,
+        journal_only_units, merge_unit_file_entries, preset_drift, select_enable_disable_action,
+        select_start_stop_action, should_fetch_all, unit_file_only_units, validate_preset_action,
+    },
+    types::{
+        ActionResolutionRequest, ConfirmationState, DetailLogEntry, DetailState, SystemctlUnit,
+        Target, UnitAction, UnitFileRoot, UnitRow, WorkerMsg,
     },
-    types::{ActionResolutionRequest, ConfirmationState, UnitAction, UnitRow, WorkerMsg},
 };
 
+/// Fetch the units shown in the list view.
+///
+/// Offline journals carry no live manager state, so units are discovered from the journal
+/// itself and the load/active/sub filters are not applied. Offline roots likewise only
+/// expose unit-file state.
+fn fetch_list_units(config: &Config) -> anyhow::Result<Vec<SystemctlUnit>> {
+    if config.root.is_offline() {
+        return fetch_unit_files(&config.target, &config.root).map(|entries| {
+            unit_file_only_units(entries)
+                .into_iter()
+                .filter(|u| {
+                    !config.preset_drift
+                        || preset_drift(u.unit_file_state.as_deref(), u.preset.as_deref())
+                })
+                .collect()
+        });
+    }
+    if config.journal_only_offline() {
+        return fetch_journal_units(&config.target, &config.journal).map(journal_only_units);
+    }
+
+    let fetch_all = should_fetch_all(config);
+    // The drift filter needs unit-file state and presets, which only list-unit-files reports.
+    let include_unit_files = config.preset_drift
+        || (config.load_filter == "all"
+            && config.active_filter == "all"
+            && config.sub_filter == "all");
+    fetch_services(&config.target, fetch_all)
+        .and_then(|units| {
+            if include_unit_files {
+                let unit_files = fetch_unit_files(&config.target, &config.root)?;
+                Ok(merge_unit_file_entries(units, unit_files))
+            } else {
+                Ok(units)
+            }
+        })
+        .map(|u| filter_services(u, config))
+}
+
+/// Fill the properties shown by optional columns into rows of one scope.
+///
+/// Only loaded units are asked, and only for the properties the columns and sort order
+/// use. Offline journals and roots have no manager to query, so their rows keep `-` apart
+/// from the unit-file state and preset read from `list-unit-files`; when `systemctl show`
+/// fails, the rows keep `-` as well instead of failing the refresh.
+fn attach_unit_properties(config: &Config, rows: &mut [UnitRow]) {
+    let properties = config.unit_properties();
+    if properties.is_empty() || config.root.is_offline() || config.journal.is_offline() {
+        return;
+    }
+    let units: Vec<String> = rows
+        .iter()
+        .filter(|row| row.load == "loaded")
+        .map(|row| row.unit.clone())
+        .collect();
+    let Ok(mut props) = fetch_unit_properties(&config.target, &units, &properties) else {
+        return;
+    };
+    for row in rows {
+        if let Some(mut unit_props) = props.remove(&row.unit) {
+            // Keep what list-unit-files reported when the query did not ask for it.
+            unit_props.unit_file_state = unit_props
+                .unit_file_state
+                .or(row.props.unit_file_state.take());
+            unit_props.preset = unit_props.preset.or(row.props.preset.take());
+            row.props = unit_props;
+        }
+    }
+}
+
 /// Spawn a background worker that fetches units and batched log previews.
-pub fn spawn_refresh_worker(config: Config, previous_rows: Vec<UnitRow>) -> Receiver<WorkerMsg> {
+///
+/// `same_query` tells whether `previous_rows` came from the same [`Config::list_query`],
+/// so that state differences count as changes.
+pub fn spawn_refresh_worker(
+    config: Config,
+    previous_rows: Vec<UnitRow>,
+    same_query: bool,
+) -> Receiver<WorkerMsg> {
     #[cfg(feature = "debug_tui")]
     if config.debug_tui {
-        return spawn_debug_refresh_worker(previous_rows);
+        return spawn_debug_refresh_worker(previous_rows, same_query, &config);
     }
 
     let (tx, rx) = mpsc::channel();
     thread::spawn(move || {
-        let fetch_all = should_fetch_all(&config);
-        let include_unit_files = config.load_filter == "all"
-            && config.active_filter == "all"
-            && config.sub_filter == "all";
-        let units = match fetch_services(config.scope, fetch_all)
-            .and_then(|units| {
-                if include_unit_files {
-                    let unit_files = fetch_unit_files(config.scope)?;
-                    Ok(merge_unit_file_entries(units, unit_files))
-                } else {
-                    Ok(units)
+        let scopes = config.scopes();
+        let mut rows = Vec::new();
+        let mut failures = Vec::new();
+        for &scope in &scopes {
+            let scoped = config.for_scope(scope);
+            let scope_rows = fetch_list_units(&scoped).map(|units| {
+                let mut scope_rows = build_rows(units, scope, &config.theme);
+                attach_unit_properties(&scoped, &mut scope_rows);
+                scope_rows
+            });
+            match scope_rows {
+                Ok(scope_rows) => rows.extend(scope_rows),
+                Err(e) if scopes.len() > 1 => {
+                    failures.push(format!("{} scope: {e}", scope.label()));
                 }
-            })
-            .map(|u| filter_services(u, &config))
-        {
-            Ok(units) => units,
-            Err(e) => {
-                let _ = tx.send(WorkerMsg::Error(e.to_string()));
+                Err(e) => {
+                    let _ = tx.send(WorkerMsg::Error(e.to_string()));
+                    return;
+                }
+            }
+        }
+        // A failing scope only fails the refresh when no other scope could be listed.
+        if failures.len() == scopes.len() {
+            let _ = tx.send(WorkerMsg::Error(failures.join("; ")));
+            return;
+        }
+        for failure in failures {
+            if tx.send(WorkerMsg::ScopeError(failure)).is_err() {
                 return;
             }
-        };
+        }
 
-        let mut rows = build_rows(units);
-        seed_logs_from_previous(&mut rows, &previous_rows);
-        sort_rows(&mut rows, config.sort_mode);
+        seed_from_previous(&mut rows, &previous_rows, same_query, Instant::now());
+        sort_rows(&mut rows, config.sort_mode, config.sort_reverse);
         let total = rows.len();
 
         if tx.send(WorkerMsg::UnitsLoaded(rows.clone())).is_err() {
@@ -86,14 +182,32 @@ pub fn spawn_refresh_worker(config: Config, previous_rows: Vec<UnitRow>) -> Rece
         let mut done = 0usize;
         while done < rows.len() {
             let end = std::cmp::min(done + LOG_BATCH_SIZE, rows.len());
-            let units: Vec<String> = rows[done..end].iter().map(|r| r.unit.clone()).collect();
-            let logs = match latest_log_lines_batch(config.scope, &units) {
-                Ok(logs) => logs.into_iter().collect(),
-                Err(e) => {
-                    let _ = tx.send(WorkerMsg::Error(e.to_string()));
-                    return;
+            let mut logs = Vec::new();
+            for &scope in &scopes {
+                let units: Vec<String> = rows[done..end]
+                    .iter()
+                    .filter(|r| r.scope == scope)
+                    .map(|r| r.unit.clone())
+                    .collect();
+                if units.is_empty() {
+                    continue;
                 }
-            };
+                let target = config.for_scope(scope).target;
+                match latest_log_lines_batch(
+                    &target,
+                    &config.journal,
+                    config.boot.as_deref(),
+                    &units,
+                ) {
+                    Ok(batch) => {
+                        logs.extend(batch.into_iter().map(|(unit, log)| ((scope, unit), log)))
+                    }
+                    Err(e) => {
+                        let _ = tx.send(WorkerMsg::Error(e.to_string()));
+                        return;
+                    }
+                }
+            }
             if tx
                 .send(WorkerMsg::LogsProgress {
                     done: end,
@@ -120,41 +234,175 @@ pub fn spawn_detail_worker(config: &Config, unit: String, request_id: u64) -> Re
     }
 
     let (tx, rx) = mpsc::channel();
-    let scope = config.scope;
-    thread::spawn(move || match fetch_unit_logs(scope, &unit, 300) {
-        Ok(logs) => {
-            let _ = tx.send(WorkerMsg::DetailLogsLoaded {
-                unit,
-                request_id,
-                logs,
-            });
+    let target = config.target.clone();
+    let journal = config.journal.clone();
+    let boot = config.boot.clone();
+    let max_lines = config.detail_lines;
+    thread::spawn(move || {
+        match fetch_unit_logs(&target, &journal, boot.as_deref(), &unit, max_lines) {
+            Ok(logs) => {
+                let _ = tx.send(WorkerMsg::DetailLogsLoaded {
+                    unit,
+                    request_id,
+                    logs,
+                });
+            }
+            Err(e) => {
+                let _ = tx.send(WorkerMsg::DetailLogsError {
+                    unit,
+                    request_id,
+                    error: e.to_string(),
+                });
+            }
         }
-        Err(e) => {
-            let _ = tx.send(WorkerMsg::DetailLogsError {
-                unit,
-                request_id,
-                error: e.to_string(),
-            });
+    });
+    rx
+}
+
+/// Spawn a background worker that loads the merged timeline of several units.
+///
+/// Results are reported under an empty unit name, as a timeline shows no single
+/// unit (see [`DetailState::begin_for_timeline`]).
+pub fn spawn_timeline_worker(
+    config: &Config,
+    units: Vec<String>,
+    request_id: u64,
+) -> Receiver<WorkerMsg> {
+    #[cfg(feature = "debug_tui")]
+    if config.debug_tui {
+        return spawn_debug_timeline_worker(units, request_id);
+    }
+
+    let (tx, rx) = mpsc::channel();
+    let target = config.target.clone();
+    let journal = config.journal.clone();
+    let boot = config.boot.clone();
+    let max_lines = config.detail_lines;
+    thread::spawn(move || {
+        let unit = String::new();
+        match fetch_timeline_logs(&target, &journal, boot.as_deref(), &units, max_lines) {
+            Ok(logs) => {
+                let _ = tx.send(WorkerMsg::DetailLogsLoaded {
+                    unit,
+                    request_id,
+                    logs,
+                });
+            }
+            Err(e) => {
+                let _ = tx.send(WorkerMsg::DetailLogsError {
+                    unit,
+                    request_id,
+                    error: e.to_string(),
+                });
+            }
         }
     });
     rx
 }
 
+/// Spawn the worker that reloads what `detail` shows: one unit's logs or a merged timeline.
+pub fn spawn_detail_refresh_worker(
+    config: &Config,
+    detail: &DetailState,
+    request_id: u64,
+) -> Receiver<WorkerMsg> {
+    let config = config.for_scope(detail.scope);
+    if detail.is_timeline() {
+        spawn_timeline_worker(&config, detail.units.clone(), request_id)
+    } else {
+        spawn_detail_worker(&config, detail.unit.clone(), request_id)
+    }
+}
+
+/// Spawn a background worker that loads every field of a detail log entry for the inspector.
+///
+/// The entry is looked up by the journal cursor it was loaded with.
+pub fn spawn_entry_worker(config: &Config, entry: DetailLogEntry) -> Receiver<WorkerMsg> {
+    #[cfg(feature = "debug_tui")]
+    if config.debug_tui {
+        return spawn_debug_entry_worker(entry);
+    }
+
+    let (tx, rx) = mpsc::channel();
+    let target = config.target.clone();
+    let journal = config.journal.clone();
+    thread::spawn(move || {
+        let msg = match entry.cursor {
+            Some(cursor) => match fetch_log_entry(&target, &journal, &cursor) {
+                Ok(entry) => WorkerMsg::EntryLoaded(entry),
+                Err(e) => WorkerMsg::EntryError(e.to_string()),
+            },
+            None => WorkerMsg::EntryError("entry has no journal cursor".to_string()),
+        };
+        let _ = tx.send(msg);
+    });
+    rx
+}
+
 fn resolve_action_confirmation(
-    scope: crate::types::Scope,
+    target: &Target,
+    root: &UnitFileRoot,
     request: ActionResolutionRequest,
 ) -> anyhow::Result<ConfirmationState> {
     match request {
+        ActionResolutionRequest::StartStop { .. } if root.is_offline() => Err(anyhow::anyhow!(
+            "start/stop is unavailable for offline roots"
+        )),
         ActionResolutionRequest::StartStop { unit } => {
-            let action = select_start_stop_action(scope, &unit)?;
+            let action = select_start_stop_action(target, &unit)?;
             Ok(match action {
                 UnitAction::Stop => ConfirmationState::restart_or_stop(unit),
                 _ => ConfirmationState::confirm_action(action, unit),
-            })
+            }
+            .with_scope(target.scope))
         }
         ActionResolutionRequest::EnableDisable { unit } => {
-            let action = select_enable_disable_action(scope, &unit)?;
-            Ok(ConfirmationState::confirm_action(action, unit))
+            let action = select_enable_disable_action(target, root, &unit)?;
+            Ok(ConfirmationState::confirm_action(action, unit).with_scope(target.scope))
+        }
+        ActionResolutionRequest::Preset { unit } => {
+            validate_preset_action(target, root, &unit)?;
+            Ok(
+                ConfirmationState::confirm_action(UnitAction::Preset, unit)
+                    .with_scope(target.scope),
+            )
+        }
+        ActionResolutionRequest::Named { action, unit } => {
+            let action = resolve_named_action(target, root, action, &unit)?;
+            Ok(ConfirmationState::confirm_action(action, unit).with_scope(target.scope))
+        }
+    }
+}
+
+/// Check an action named in the palette with the same lookups the key bindings use.
+///
+/// `disable` keeps `--runtime` for units that are only enabled at runtime.
+fn resolve_named_action(
+    target: &Target,
+    root: &UnitFileRoot,
+    action: UnitAction,
+    unit: &str,
+) -> anyhow::Result<UnitAction> {
+    match action {
+        UnitAction::Start | UnitAction::Restart | UnitAction::Stop if root.is_offline() => Err(
+            anyhow::anyhow!("start/stop is unavailable for offline roots"),
+        ),
+        UnitAction::Start | UnitAction::Restart => {
+            select_start_stop_action(target, unit)?;
+            Ok(action)
+        }
+        UnitAction::Stop => Ok(action),
+        UnitAction::Enable | UnitAction::Disable | UnitAction::DisableRuntime => {
+            match select_enable_disable_action(target, root, unit)? {
+                UnitAction::DisableRuntime if action != UnitAction::Enable => {
+                    Ok(UnitAction::DisableRuntime)
+                }
+                _ => Ok(action),
+            }
+        }
+        UnitAction::Preset => {
+            validate_preset_action(target, root, unit)?;
+            Ok(action)
         }
     }
 }
@@ -170,10 +418,19 @@ pub fn spawn_action_resolution_worker(
     }
 
     let (tx, rx) = mpsc::channel();
-    let scope = config.scope;
+    let target = config.target.clone();
+    let root = config.root.clone();
+    let offline_journal = config.journal_only_offline();
     thread::spawn(move || {
         let unit = request.unit().to_string();
-        match resolve_action_confirmation(scope, request) {
+        let resolved = if offline_journal {
+            Err(anyhow::anyhow!(
+                "unit actions are unavailable while reading an offline journal"
+            ))
+        } else {
+            resolve_action_confirmation(&target, &root, request)
+        };
+        match resolved {
             Ok(confirmation) => {
                 let _ = tx.send(WorkerMsg::ActionConfirmationReady { unit, confirmation });
             }
@@ -188,24 +445,90 @@ pub fn spawn_action_resolution_worker(
     rx
 }
 
+/// Spawn a background worker that lists machines for the machine picker.
+pub fn spawn_machine_list_worker(config: &Config) -> Receiver<WorkerMsg> {
+    #[cfg(feature = "debug_tui")]
+    if config.debug_tui {
+        return spawn_debug_machine_list_worker();
+    }
+
+    let (tx, rx) = mpsc::channel();
+    let offline = config.journal.is_offline() || config.root.is_offline();
+    let remote = config.target.host.is_some();
+    thread::spawn(move || {
+        let msg = if offline {
+            WorkerMsg::MachinesError(
+                "machine selection is unavailable for offline journals or roots".to_string(),
+            )
+        } else if remote {
+            WorkerMsg::MachinesError("machine selection is unavailable with --host".to_string())
+        } else {
+            match fetch_machines() {
+                Ok(machines) => WorkerMsg::MachinesLoaded(machines),
+                Err(e) => WorkerMsg::MachinesError(e.to_string()),
+            }
+        };
+        let _ = tx.send(msg);
+    });
+    rx
+}
+
+/// Spawn a background worker that lists journal boots for the boot picker.
+pub fn spawn_boot_list_worker(config: &Config) -> Receiver<WorkerMsg> {
+    #[cfg(feature = "debug_tui")]
+    if config.debug_tui {
+        return spawn_debug_boot_list_worker();
+    }
+
+    let (tx, rx) = mpsc::channel();
+    let target = config.target.clone();
+    let journal = config.journal.clone();
+    thread::spawn(move || {
+        let msg = match fetch_boots(&target, &journal) {
+            Ok(boots) => WorkerMsg::BootsLoaded(boots),
+            Err(e) => WorkerMsg::BootsError(e.to_string()),
+        };
+        let _ = tx.send(msg);
+    });
+    rx
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::time::Duration;
 
-    #[test]
-    fn refresh_worker_emits_units_then_finished_with_stubbed_backends() {
-        let cfg = Config {
+    fn sample_config() -> Config {
+        Config {
             load_filter: "loaded".to_string(),
             active_filter: "active".to_string(),
             sub_filter: "running".to_string(),
             show_help: false,
             show_version: false,
             debug_tui: false,
-            scope: Scope::System,
+            target: Target::new(Scope::System),
+            both_scopes: false,
             sort_mode: SortMode::Name,
-        };
-        let rx = spawn_refresh_worker(cfg, Vec::new());
+            journal: JournalSource::Local,
+            root: UnitFileRoot::Live,
+            command_timeout: None,
+            detail_lines: 300,
+            refresh_interval: None,
+            key_bindings: Default::default(),
+            theme: Default::default(),
+            columns: crate::types::Column::DEFAULT.to_vec(),
+            preset_drift: false,
+            sort_reverse: false,
+            mark_changed: false,
+            split: crate::types::SplitLayout::Off,
+            boot: None,
+        }
+    }
+
+    #[test]
+    fn refresh_worker_emits_units_then_finished_with_stubbed_backends() {
+        let cfg = sample_config();
+        let rx = spawn_refresh_worker(cfg, Vec::new(), true);
         match rx
             .recv_timeout(Duration::from_millis(500))
             .expect("first msg")
@@ -225,16 +548,10 @@ mod tests {
     #[test]
     fn refresh_worker_emits_log_progress_for_non_empty_rows() {
         let cfg = Config {
-            load_filter: "loaded".to_string(),
-            active_filter: "active".to_string(),
             sub_filter: "all".to_string(),
-            show_help: false,
-            show_version: false,
-            debug_tui: false,
-            scope: Scope::System,
-            sort_mode: SortMode::Name,
+            ..sample_config()
         };
-        let rx = spawn_refresh_worker(cfg, Vec::new());
+        let rx = spawn_refresh_worker(cfg, Vec::new(), true);
         match rx
             .recv_timeout(Duration::from_millis(500))
             .expect("units msg")
@@ -265,16 +582,10 @@ mod tests {
     #[test]
     fn refresh_worker_emits_error_when_systemd_fetch_fails() {
         let cfg = Config {
-            load_filter: "loaded".to_string(),
-            active_filter: "active".to_string(),
-            sub_filter: "running".to_string(),
-            show_help: false,
-            show_version: false,
-            debug_tui: false,
-            scope: Scope::User,
-            sort_mode: SortMode::Name,
+            target: Target::new(Scope::User),
+            ..sample_config()
         };
-        let rx = spawn_refresh_worker(cfg, Vec::new());
+        let rx = spawn_refresh_worker(cfg, Vec::new(), true);
         match rx
             .recv_timeout(Duration::from_millis(500))
             .expect("error msg")
@@ -287,16 +598,11 @@ mod tests {
     #[test]
     fn refresh_worker_emits_error_when_journal_batch_fails() {
         let cfg = Config {
-            load_filter: "loaded".to_string(),
             active_filter: "inactive".to_string(),
             sub_filter: "dead".to_string(),
-            show_help: false,
-            show_version: false,
-            debug_tui: false,
-            scope: Scope::System,
-            sort_mode: SortMode::Name,
+            ..sample_config()
         };
-        let rx = spawn_refresh_worker(cfg, Vec::new());
+        let rx = spawn_refresh_worker(cfg, Vec::new(), true);
         match rx
             .recv_timeout(Duration::from_millis(500))
             .expect("units msg")
@@ -314,21 +620,42 @@ mod tests {
     }
 
     #[test]
-    fn detail_worker_emits_loaded_with_stubbed_backend() {
-        let rx = spawn_detail_worker(
-            &Config {
-                load_filter: "loaded".to_string(),
-                active_filter: "active".to_string(),
-                sub_filter: "running".to_string(),
-                show_help: false,
-                show_version: false,
-                debug_tui: false,
-                scope: Scope::System,
-                sort_mode: SortMode::Name,
-            },
-            "a.service".to_string(),
-            7,
+    fn timeline_worker_reports_merged_logs_under_an_empty_unit() {
+        let config = sample_config();
+        let mut detail = DetailState::default();
+        let request_id = detail.begin_for_timeline(
+            Scope::System,
+            vec!["a.service".to_string(), "b.service".to_string()],
+            0,
         );
+        match spawn_detail_refresh_worker(&config, &detail, request_id)
+            .recv_timeout(Duration::from_millis(500))
+            .expect("timeline msg")
+        {
+            WorkerMsg::DetailLogsLoaded { unit, logs, .. } => {
+                assert!(unit.is_empty());
+                assert!(detail.apply_loaded(request_id, &unit, logs));
+                assert_eq!(detail.logs.len(), 2);
+                assert!(detail.refresh().is_some());
+            }
+            other => panic!("expected DetailLogsLoaded, got {other:?}"),
+        }
+
+        match spawn_timeline_worker(&config, vec!["error.service".to_string()], 9)
+            .recv_timeout(Duration::from_millis(500))
+            .expect("timeline msg")
+        {
+            WorkerMsg::DetailLogsError { unit, error, .. } => {
+                assert!(unit.is_empty());
+                assert!(error.contains("timeline journal test error"));
+            }
+            other => panic!("expected DetailLogsError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn detail_worker_emits_loaded_with_stubbed_backend() {
+        let rx = spawn_detail_worker(&sample_config(), "a.service".to_string(), 7);
         match rx
             .recv_timeout(Duration::from_millis(500))
             .expect("detail msg")
@@ -348,20 +675,7 @@ mod tests {
 
     #[test]
     fn detail_worker_emits_error_when_backend_fails() {
-        let rx = spawn_detail_worker(
-            &Config {
-                load_filter: "loaded".to_string(),
-                active_filter: "active".to_string(),
-                sub_filter: "running".to_string(),
-                show_help: false,
-                show_version: false,
-                debug_tui: false,
-                scope: Scope::System,
-                sort_mode: SortMode::Name,
-            },
-            "error.service".to_string(),
-            9,
-        );
+        let rx = spawn_detail_worker(&sample_config(), "error.service".to_string(), 9);
         match rx
             .recv_timeout(Duration::from_millis(500))
             .expect("detail error msg")
@@ -379,19 +693,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn entry_worker_emits_loaded_and_error_from_stubbed_backend() {
+        let cfg = sample_config();
+        let entry = |cursor: Option<&str>| DetailLogEntry {
+            time: "2026-02-24T10:00:00+0000".to_string(),
+            log: "hello".to_string(),
+            cursor: cursor.map(str::to_string),
+            ..DetailLogEntry::default()
+        };
+        match spawn_entry_worker(&cfg, entry(Some("c1")))
+            .recv_timeout(Duration::from_millis(500))
+            .expect("entry msg")
+        {
+            WorkerMsg::EntryLoaded(found) => {
+                assert!(
+                    found
+                        .fields
+                        .contains(&("__CURSOR".to_string(), "c1".to_string()))
+                );
+            }
+            other => panic!("expected EntryLoaded, got {other:?}"),
+        }
+        for (cursor, expected) in [
+            (Some("error"), "entry journal test error"),
+            (None, "entry has no journal cursor"),
+        ] {
+            match spawn_entry_worker(&cfg, entry(cursor))
+                .recv_timeout(Duration::from_millis(500))
+                .expect("entry msg")
+            {
+                WorkerMsg::EntryError(error) => assert!(error.contains(expected)),
+                other => panic!("expected EntryError, got {other:?}"),
+            }
+        }
+    }
+
     #[test]
     fn action_resolution_worker_resolves_start_stop_from_active_state() {
         let rx = spawn_action_resolution_worker(
-            &Config {
-                load_filter: "loaded".to_string(),
-                active_filter: "active".to_string(),
-                sub_filter: "running".to_string(),
-                show_help: false,
-                show_version: false,
-                debug_tui: false,
-                scope: Scope::System,
-                sort_mode: SortMode::Name,
-            },
+            &sample_config(),
             ActionResolutionRequest::StartStop {
                 unit: "running.service".to_string(),
             },
@@ -411,16 +752,7 @@ mod tests {
     #[test]
     fn action_resolution_worker_treats_refreshing_units_as_running() {
         let rx = spawn_action_resolution_worker(
-            &Config {
-                load_filter: "loaded".to_string(),
-                active_filter: "active".to_string(),
-                sub_filter: "running".to_string(),
-                show_help: false,
-                show_version: false,
-                debug_tui: false,
-                scope: Scope::System,
-                sort_mode: SortMode::Name,
-            },
+            &sample_config(),
             ActionResolutionRequest::StartStop {
                 unit: "refreshing.service".to_string(),
             },
@@ -440,16 +772,7 @@ mod tests {
     #[test]
     fn action_resolution_worker_uses_live_start_stop_lookup() {
         let rx = spawn_action_resolution_worker(
-            &Config {
-                load_filter: "loaded".to_string(),
-                active_filter: "active".to_string(),
-                sub_filter: "running".to_string(),
-                show_help: false,
-                show_version: false,
-                debug_tui: false,
-                scope: Scope::System,
-                sort_mode: SortMode::Name,
-            },
+            &sample_config(),
             ActionResolutionRequest::StartStop {
                 unit: "stopped.service".to_string(),
             },
@@ -472,16 +795,7 @@ mod tests {
     #[test]
     fn action_resolution_worker_rejects_non_loadable_start_targets() {
         let rx = spawn_action_resolution_worker(
-            &Config {
-                load_filter: "loaded".to_string(),
-                active_filter: "active".to_string(),
-                sub_filter: "running".to_string(),
-                show_help: false,
-                show_version: false,
-                debug_tui: false,
-                scope: Scope::System,
-                sort_mode: SortMode::Name,
-            },
+            &sample_config(),
             ActionResolutionRequest::StartStop {
                 unit: "masked.service".to_string(),
             },
@@ -499,17 +813,74 @@ mod tests {
     }
 
     #[test]
-    fn action_resolution_worker_resolves_enable_disable_and_errors() {
-        let cfg = Config {
-            load_filter: "loaded".to_string(),
-            active_filter: "active".to_string(),
-            sub_filter: "running".to_string(),
-            show_help: false,
-            show_version: false,
-            debug_tui: false,
-            scope: Scope::System,
-            sort_mode: SortMode::Name,
+    fn action_resolution_worker_checks_named_palette_actions() {
+        let resolve =
+            |config: &Config, action: UnitAction, unit: &str| match spawn_action_resolution_worker(
+                config,
+                ActionResolutionRequest::Named {
+                    action,
+                    unit: unit.to_string(),
+                },
+            )
+            .recv_timeout(Duration::from_millis(500))
+            .expect("named resolution msg")
+            {
+                WorkerMsg::ActionConfirmationReady { confirmation, .. } => Ok(confirmation),
+                WorkerMsg::ActionResolutionError { error, .. } => Err(error),
+                other => panic!("expected a resolution message, got {other:?}"),
+            };
+        let cfg = sample_config();
+        assert_eq!(
+            resolve(&cfg, UnitAction::Restart, "running.service"),
+            Ok(ConfirmationState::confirm_action(
+                UnitAction::Restart,
+                "running.service".to_string()
+            ))
+        );
+        assert!(
+            resolve(&cfg, UnitAction::Start, "masked.service")
+                .unwrap_err()
+                .contains("does not support start")
+        );
+        assert_eq!(
+            resolve(&cfg, UnitAction::Disable, "enabled-runtime.service")
+                .map(|confirmation| confirmation.confirmed_action()),
+            Ok(Some(UnitAction::DisableRuntime))
+        );
+        assert!(
+            resolve(&cfg, UnitAction::Enable, "static.service")
+                .unwrap_err()
+                .contains("does not support enable/disable")
+        );
+        assert!(
+            resolve(&cfg, UnitAction::Preset, "missing.service")
+                .unwrap_err()
+                .contains("no unit file found")
+        );
+
+        let offline = Config {
+            root: UnitFileRoot::Directory("/mnt/os".into()),
+            ..sample_config()
+        };
+        assert!(
+            resolve(&offline, UnitAction::Stop, "a.service")
+                .unwrap_err()
+                .contains("offline roots")
+        );
+        let journal_only = Config {
+            journal: JournalSource::Directory("/tmp/journal".into()),
+            ..sample_config()
         };
+        assert!(
+            resolve(&journal_only, UnitAction::Preset, "a.service")
+                .unwrap_err()
+                .contains("offline journal")
+        );
+    }
+
+    #[test]
+    fn action_resolution_worker_resolves_enable_disable_and_errors() {
+        let cfg = sample_config();
 
         let rx = spawn_action_resolution_worker(
             &cfg,
@@ -575,13 +946,10 @@ mod tests {
             load_filter: "all".to_string(),
             active_filter: "all".to_string(),
             sub_filter: "all".to_string(),
-            show_help: false,
-            show_version: false,
-            debug_tui: false,
-            scope: Scope::System,
             sort_mode: SortMode::Status,
+            ..sample_config()
         };
-        let rx = spawn_refresh_worker(cfg, Vec::new());
+        let rx = spawn_refresh_worker(cfg, Vec::new(), true);
         match rx
             .recv_timeout(Duration::from_millis(500))
             .expect("units msg")
@@ -604,19 +972,65 @@ mod tests {
         }
     }
 
+    #[test]
+    fn refresh_worker_fills_properties_only_for_property_columns() {
+        let cfg = Config {
+            load_filter: "all".to_string(),
+            active_filter: "all".to_string(),
+            sub_filter: "all".to_string(),
+            sort_mode: SortMode::Status,
+            columns: vec![crate::types::Column::Unit, crate::types::Column::Memory],
+            ..sample_config()
+        };
+        let loaded_rows = |cfg: Config| match spawn_refresh_worker(cfg, Vec::new(), true)
+            .recv_timeout(Duration::from_millis(500))
+            .expect("units msg")
+        {
+            WorkerMsg::UnitsLoaded(rows) => rows,
+            other => panic!("expected UnitsLoaded, got {other:?}"),
+        };
+
+        let rows = loaded_rows(cfg.clone());
+        let a = rows.iter().find(|r| r.unit == "a.service").expect("a row");
+        assert_eq!(a.props.main_pid, None);
+        assert_eq!(a.props.memory_bytes, Some(3 * 1024 * 1024));
+        assert_eq!(a.props.unit_file_state.as_deref(), Some("enabled"));
+        // Unit files that are not loaded keep what list-unit-files reported.
+        let unloaded = rows
+            .iter()
+            .find(|r| r.unit == "unloaded.service")
+            .expect("unit file row");
+        assert_eq!(unloaded.props.unit_file_state.as_deref(), Some("disabled"));
+
+        // A failing property query leaves the columns at `-` instead of failing.
+        let mut user_rows = rows.clone();
+        for row in &mut user_rows {
+            row.props = Default::default();
+        }
+        attach_unit_properties(&cfg.for_scope(Scope::User), &mut user_rows);
+        assert!(user_rows.iter().all(|r| r.props.memory_bytes.is_none()));
+
+        let rows = loaded_rows(Config {
+            columns: crate::types::Column::DEFAULT.to_vec(),
+            ..cfg
+        });
+        let a = rows.iter().find(|r| r.unit == "a.service").expect("a row");
+        assert_eq!(a.props.main_pid, None);
+        assert_eq!(a.props.memory_bytes, None);
+        assert_eq!(a.props.unit_file_state.as_deref(), Some("enabled"));
+    }
+
     #[test]
     fn refresh_worker_emits_error_when_unit_file_fetch_fails() {
         let cfg = Config {
             load_filter: "all".to_string(),
             active_filter: "all".to_string(),
             sub_filter: "all".to_string(),
-            show_help: false,
-            show_version: false,
-            debug_tui: false,
-            scope: Scope::User,
+            target: Target::new(Scope::User),
             sort_mode: SortMode::Status,
+            ..sample_config()
         };
-        let rx = spawn_refresh_worker(cfg, Vec::new());
+        let rx = spawn_refresh_worker(cfg, Vec::new(), true);
         match rx
             .recv_timeout(Duration::from_millis(500))
             .expect("error msg")
@@ -631,20 +1045,307 @@ mod tests {
         }
     }
 
+    #[test]
+    fn refresh_worker_discovers_units_from_offline_journal() {
+        let cfg = Config {
+            journal: JournalSource::Directory("/tmp/journal".into()),
+            ..sample_config()
+        };
+        let rx = spawn_refresh_worker(cfg, Vec::new(), true);
+        match rx
+            .recv_timeout(Duration::from_millis(500))
+            .expect("units msg")
+        {
+            WorkerMsg::UnitsLoaded(rows) => {
+                let units: Vec<&str> = rows.iter().map(|r| r.unit.as_str()).collect();
+                assert_eq!(units, vec!["a.service", "offline.service"]);
+                assert!(rows.iter().all(|r| r.active == "-"));
+            }
+            other => panic!("expected UnitsLoaded, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn action_resolution_worker_rejects_offline_journal() {
+        let rx = spawn_action_resolution_worker(
+            &Config {
+                load_filter: "all".to_string(),
+                active_filter: "all".to_string(),
+                sub_filter: "all".to_string(),
+                journal: JournalSource::Files(vec!["system.journal".into()]),
+                ..sample_config()
+            },
+            ActionResolutionRequest::StartStop {
+                unit: "a.service".to_string(),
+            },
+        );
+        match rx
+            .recv_timeout(Duration::from_millis(500))
+            .expect("resolution msg")
+        {
+            WorkerMsg::ActionResolutionError { unit, error } => {
+                assert_eq!(unit, "a.service");
+                assert!(error.contains("offline journal"));
+            }
+            other => panic!("expected ActionResolutionError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn machine_list_worker_lists_machines_or_rejects_offline_sources() {
+        let mut cfg = Config {
+            load_filter: "all".to_string(),
+            active_filter: "all".to_string(),
+            sub_filter: "all".to_string(),
+            ..sample_config()
+        };
+        match spawn_machine_list_worker(&cfg)
+            .recv_timeout(Duration::from_millis(500))
+            .expect("machines msg")
+        {
+            WorkerMsg::MachinesLoaded(machines) => assert_eq!(machines, vec!["web", "db"]),
+            other => panic!("expected MachinesLoaded, got {other:?}"),
+        }
+
+        cfg.journal = JournalSource::Directory("/tmp/journal".into());
+        match spawn_machine_list_worker(&cfg)
+            .recv_timeout(Duration::from_millis(500))
+            .expect("machines msg")
+        {
+            WorkerMsg::MachinesError(error) => assert!(error.contains("offline")),
+            other => panic!("expected MachinesError, got {other:?}"),
+        }
+
+        cfg.journal = JournalSource::Local;
+        cfg.target.host = Some("ops@db1".to_string());
+        match spawn_machine_list_worker(&cfg)
+            .recv_timeout(Duration::from_millis(500))
+            .expect("machines msg")
+        {
+            WorkerMsg::MachinesError(error) => assert!(error.contains("--host")),
+            other => panic!("expected MachinesError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn boot_list_worker_reports_boots_and_errors() {
+        let mut cfg = Config {
+            load_filter: "all".to_string(),
+            active_filter: "all".to_string(),
+            sub_filter: "all".to_string(),
+            ..sample_config()
+        };
+        match spawn_boot_list_worker(&cfg)
+            .recv_timeout(Duration::from_millis(500))
+            .expect("boots msg")
+        {
+            WorkerMsg::BootsLoaded(boots) => assert_eq!(boots.len(), 2),
+            other => panic!("expected BootsLoaded, got {other:?}"),
+        }
+
+        cfg.target = Target::new(Scope::User);
+        match spawn_boot_list_worker(&cfg)
+            .recv_timeout(Duration::from_millis(500))
+            .expect("boots msg")
+        {
+            WorkerMsg::BootsError(error) => assert!(error.contains("boot list test error")),
+            other => panic!("expected BootsError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn refresh_worker_lists_unit_files_from_offline_root() {
+        let cfg = Config {
+            journal: JournalSource::Root("/mnt/os".into()),
+            root: UnitFileRoot::Directory("/mnt/os".into()),
+            ..sample_config()
+        };
+        let rx = spawn_refresh_worker(cfg, Vec::new(), true);
+        match rx
+            .recv_timeout(Duration::from_millis(500))
+            .expect("units msg")
+        {
+            WorkerMsg::UnitsLoaded(rows) => {
+                let units: Vec<&str> = rows.iter().map(|r| r.unit.as_str()).collect();
+                assert_eq!(units, vec!["a.service", "unloaded.service"]);
+                assert_eq!(rows[1].props.unit_file_state.as_deref(), Some("disabled"));
+            }
+            other => panic!("expected UnitsLoaded, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn refresh_worker_keeps_only_units_drifting_from_their_preset() {
+        let live = Config {
+            load_filter: "all".to_string(),
+            active_filter: "all".to_string(),
+            sub_filter: "all".to_string(),
+            preset_drift: true,
+            ..sample_config()
+        };
+        let offline = Config {
+            journal: JournalSource::Root("/mnt/os".into()),
+            root: UnitFileRoot::Directory("/mnt/os".into()),
+            ..live.clone()
+        };
+        for cfg in [live, offline] {
+            let rx = spawn_refresh_worker(cfg, Vec::new(), true);
+            match rx
+                .recv_timeout(Duration::from_millis(500))
+                .expect("units msg")
+            {
+                WorkerMsg::UnitsLoaded(rows) => {
+                    let units: Vec<&str> = rows.iter().map(|r| r.unit.as_str()).collect();
+                    assert_eq!(units, vec!["unloaded.service"]);
+                    assert_eq!(rows[0].props.unit_file_state.as_deref(), Some("disabled"));
+                    assert_eq!(rows[0].props.preset.as_deref(), Some("enabled"));
+                }
+                other => panic!("expected UnitsLoaded, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn refresh_worker_names_failing_scope_and_keeps_the_other_one() {
+        let cfg = Config {
+            load_filter: "all".to_string(),
+            active_filter: "all".to_string(),
+            sub_filter: "all".to_string(),
+            both_scopes: true,
+            ..sample_config()
+        };
+        let rx = spawn_refresh_worker(cfg, Vec::new(), true);
+        match rx
+            .recv_timeout(Duration::from_millis(500))
+            .expect("scope error msg")
+        {
+            WorkerMsg::ScopeError(error) => assert_eq!(error, "user scope: systemd test error"),
+            other => panic!("expected ScopeError, got {other:?}"),
+        }
+        match rx
+            .recv_timeout(Duration::from_millis(500))
+            .expect("units msg")
+        {
+            WorkerMsg::UnitsLoaded(rows) => {
+                assert!(!rows.is_empty());
+                assert!(rows.iter().all(|row| row.scope == Scope::System));
+            }
+            other => panic!("expected UnitsLoaded, got {other:?}"),
+        }
+
+        let rx = spawn_refresh_worker(
+            Config {
+                both_scopes: true,
+                target: Target {
+                    scope: Scope::User,
+                    ..Target::default()
+                },
+                ..sample_config()
+            }
+            .for_scope(Scope::User),
+            Vec::new(),
+            true,
+        );
+        match rx
+            .recv_timeout(Duration::from_millis(500))
+            .expect("error msg")
+        {
+            WorkerMsg::Error(error) => assert_eq!(error, "systemd test error"),
+            other => panic!("expected Error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn action_resolution_worker_tags_confirmation_with_row_scope() {
+        let cfg = Config {
+            load_filter: "all".to_string(),
+            active_filter: "all".to_string(),
+            sub_filter: "all".to_string(),
+            both_scopes: true,
+            ..sample_config()
+        };
+        let rx = spawn_action_resolution_worker(
+            &cfg.for_scope(Scope::User),
+            ActionResolutionRequest::EnableDisable {
+                unit: "a.service".to_string(),
+            },
+        );
+        match rx
+            .recv_timeout(Duration::from_millis(500))
+            .expect("resolution msg")
+        {
+            WorkerMsg::ActionConfirmationReady { confirmation, .. } => {
+                assert_eq!(confirmation.scope, Scope::User);
+            }
+            other => panic!("expected ActionConfirmationReady, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn action_resolution_worker_limits_offline_root_to_enable_disable() {
+        let cfg = Config {
+            load_filter: "all".to_string(),
+            active_filter: "all".to_string(),
+            sub_filter: "all".to_string(),
+            journal: JournalSource::Image("/tmp/os.raw".into()),
+            root: UnitFileRoot::Image("/tmp/os.raw".into()),
+            ..sample_config()
+        };
+        let rx = spawn_action_resolution_worker(
+            &cfg,
+            ActionResolutionRequest::StartStop {
+                unit: "a.service".to_string(),
+            },
+        );
+        match rx
+            .recv_timeout(Duration::from_millis(500))
+            .expect("resolution msg")
+        {
+            WorkerMsg::ActionResolutionError { error, .. } => {
+                assert!(error.contains("offline roots"));
+            }
+            other => panic!("expected ActionResolutionError, got {other:?}"),
+        }
+
+        let rx = spawn_action_resolution_worker(
+            &cfg,
+            ActionResolutionRequest::EnableDisable {
+                unit: "a.service".to_string(),
+            },
+        );
+        assert!(matches!(
+            rx.recv_timeout(Duration::from_millis(500))
+                .expect("resolution msg"),
+            WorkerMsg::ActionConfirmationReady { .. }
+        ));
+
+        let rx = spawn_action_resolution_worker(
+            &cfg,
+            ActionResolutionRequest::Preset {
+                unit: "unloaded.service".to_string(),
+            },
+        );
+        match rx
+            .recv_timeout(Duration::from_millis(500))
+            .expect("resolution msg")
+        {
+            WorkerMsg::ActionConfirmationReady { confirmation, .. } => assert_eq!(
+                confirmation,
+                ConfirmationState::confirm_action(UnitAction::Preset, "unloaded.service".into())
+            ),
+            other => panic!("expected ActionConfirmationReady, got {other:?}"),
+        }
+    }
+
     #[cfg(feature = "debug_tui")]
     #[test]
     fn refresh_worker_uses_debug_source_when_enabled() {
         let cfg = Config {
-            load_filter: "loaded".to_string(),
-            active_filter: "active".to_string(),
-            sub_filter: "running".to_string(),
-            show_help: false,
-            show_version: false,
             debug_tui: true,
-            scope: Scope::User,
-            sort_mode: SortMode::Name,
+            target: Target::new(Scope::User),
+            ..sample_config()
         };
-        let rx = spawn_refresh_worker(cfg, Vec::new());
+        let rx = spawn_refresh_worker(cfg, Vec::new(), true);
         match rx
             .recv_timeout(Duration::from_millis(500))
             .expect("first msg")
@@ -661,14 +1362,9 @@ mod tests {
     #[test]
     fn action_workers_use_debug_backend_when_enabled() {
         let cfg = Config {
-            load_filter: "loaded".to_string(),
-            active_filter: "active".to_string(),
-            sub_filter: "running".to_string(),
-            show_help: false,
-            show_version: false,
             debug_tui: true,
-            scope: Scope::User,
-            sort_mode: SortMode::Name,
+            target: Target::new(Scope::User),
+            ..sample_config()
         };
 
         let resolution_rx = spawn_action_resolution_worker(
@@ -16,10 +16,15 @@
 
 //! Command-line parsing and usage text.
 
-use anyhow::{Result, anyhow};
+use anyhow::{Context, Result, anyhow};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
 
-use crate::types::{Scope, SortMode};
+use crate::config_file::{FileConfig, load_config_file};
+use crate::theme::{Theme, no_color_requested, resolve_theme};
+use crate::types::{Column, JournalSource, Scope, SortMode, SplitLayout, Target, UnitFileRoot};
 
 /// Parsed command-line configuration.
 #[derive(Debug, Clone)]
@@ -36,10 +41,172 @@ pub struct Config {
     pub show_version: bool,
     /// Whether the optional fake-data TUI debug mode is active.
     pub debug_tui: bool,
-    /// Target systemd scope (`system` or `user`).
-    pub scope: Scope,
+    /// Manager queried by `systemctl`/`journalctl` (scope and optional machine).
+    pub target: Target,
+    /// Whether system and user units are listed together, ignoring `target.scope`.
+    pub both_scopes: bool,
     /// Row sort order for the list view.
     pub sort_mode: SortMode,
+    /// Journal read by every `journalctl` call.
+    pub journal: JournalSource,
+    /// Unit files read and edited by `systemctl`.
+    pub root: UnitFileRoot,
+    /// Subprocess timeout from the config file, if any.
+    pub command_timeout: Option<Duration>,
+    /// Number of log lines fetched for the detail view.
+    pub detail_lines: usize,
+    /// Automatic list refresh interval; `None` disables it.
+    pub refresh_interval: Option<Duration>,
+    /// Key sequences per UI command name from the `[keys]` table of the config file.
+    pub key_bindings: BTreeMap<String, Vec<String>>,
+    /// Colors and state glyphs used for rendering.
+    pub theme: Theme,
+    /// Columns of the list table, in display order.
+    pub columns: Vec<Column>,
+    /// Whether only units whose enablement differs from the vendor preset are listed.
+    pub preset_drift: bool,
+    /// Whether the list sort order is reversed; toggled from the list view.
+    pub sort_reverse: bool,
+    /// Whether rows that changed since launch keep a marker after their highlight fades.
+    pub mark_changed: bool,
+    /// Placement of the log pane next to the list; cycled from the list view.
+    pub split: SplitLayout,
+    /// Boot ID whose logs are read (`journalctl -b`); `None` reads every boot.
+    pub boot: Option<String>,
+}
+
+/// Settings that decide which units a refresh lists and which log previews it reads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListQuery {
+    filters: [String; 3],
+    scopes: Vec<Scope>,
+    target: Target,
+    preset_drift: bool,
+    boot: Option<String>,
+}
+
+/// Default number of log lines fetched for the detail view.
+pub const DEFAULT_DETAIL_LINES: usize = 300;
+
+impl Config {
+    /// Return the scopes whose units are listed.
+    pub fn scopes(&self) -> Vec<Scope> {
+        if self.both_scopes {
+            vec![Scope::System, Scope::User]
+        } else {
+            vec![self.target.scope]
+        }
+    }
+
+    /// Return the list table columns, adding the scope column when both scopes are listed
+    /// and the unit-file state column for offline roots.
+    pub fn list_columns(&self) -> Vec<Column> {
+        let mut columns = self.columns.clone();
+        if self.both_scopes && !columns.contains(&Column::Scope) {
+            let at = columns
+                .iter()
+                .position(|column| *column == Column::Unit)
+                .map_or(0, |idx| idx + 1);
+            columns.insert(at, Column::Scope);
+        }
+        if self.root.is_offline() && !columns.contains(&Column::UnitFileState) {
+            let at = columns
+                .iter()
+                .rposition(|column| matches!(column, Column::Unit | Column::Scope))
+                .map_or(0, |idx| idx + 1);
+            columns.insert(at, Column::UnitFileState);
+        }
+        columns
+    }
+
+    /// Return whether only the journal is offline, so units come from the journal while
+    /// the unit files are still the live ones.
+    pub fn journal_only_offline(&self) -> bool {
+        self.journal.is_offline() && !self.root.is_offline()
+    }
+
+    /// Return the `systemctl show` properties the list columns and the sort order need.
+    pub fn unit_properties(&self) -> Vec<&'static str> {
+        let mut properties = Vec::new();
+        let needed = self.columns.iter().filter_map(|column| column.property());
+        for property in needed.chain(self.sort_mode.property()) {
+            if !properties.contains(&property) {
+                properties.push(property);
+            }
+        }
+        properties
+    }
+
+    /// Return whether any list column or the sort order needs properties from `systemctl show`.
+    pub fn needs_unit_properties(&self) -> bool {
+        !self.unit_properties().is_empty()
+    }
+
+    /// Return the query a refresh runs; rows of two refreshes are compared only when it is unchanged.
+    pub fn list_query(&self) -> ListQuery {
+        ListQuery {
+            filters: [
+                self.load_filter.clone(),
+                self.active_filter.clone(),
+                self.sub_filter.clone(),
+            ],
+            scopes: self.scopes(),
+            target: self.target.clone(),
+            preset_drift: self.preset_drift,
+            boot: self.boot.clone(),
+        }
+    }
+
+    /// Return a copy of this configuration narrowed to one scope.
+    pub fn for_scope(&self, scope: Scope) -> Config {
+        let mut config = self.clone();
+        config.target.scope = scope;
+        config.both_scopes = false;
+        config
+    }
+}
+
+/// Check that a target can be reached together with the given journal and unit-file root.
+///
+/// `both_scopes` also lists the user manager of the target. Shared by argument parsing
+/// and the scope switch in the UI.
+pub fn validate_target(
+    target: &Target,
+    both_scopes: bool,
+    journal: &JournalSource,
+    root: &UnitFileRoot,
+) -> Result<()> {
+    let offline = root.is_offline() || journal.is_offline();
+    if target.machine.is_some() && offline {
+        return Err(anyhow!(
+            "--machine cannot be combined with offline journals or roots"
+        ));
+    }
+    if target.host.is_some() && (target.machine.is_some() || offline) {
+        return Err(anyhow!(
+            "--host cannot be combined with --machine or offline journals or roots"
+        ));
+    }
+    if target.host.is_some() && target.scope == Scope::User {
+        return Err(anyhow!("--user cannot be combined with --host"));
+    }
+    if target.host.is_some() && both_scopes {
+        return Err(anyhow!("--both cannot be combined with --host"));
+    }
+    Ok(())
+}
+
+/// Parse an auto-refresh interval given in whole seconds.
+fn parse_interval(value: &str) -> Result<u64> {
+    value
+        .parse()
+        .map_err(|_| anyhow!("invalid interval: {value}; expected whole seconds"))
+}
+
+/// Parse a `--split` layout name.
+fn parse_split(value: &str) -> Result<SplitLayout> {
+    SplitLayout::from_name(value)
+        .ok_or_else(|| anyhow!("invalid --split value: {value}; allowed: off, below, beside"))
 }
 
 #[cfg(feature = "debug_tui")]
@@ -51,8 +218,22 @@ fn debug_tui_config() -> Config {
         show_help: false,
         show_version: false,
         debug_tui: true,
-        scope: Scope::System,
+        target: Target::new(Scope::System),
+        both_scopes: false,
         sort_mode: SortMode::Status,
+        journal: JournalSource::Local,
+        root: UnitFileRoot::Live,
+        command_timeout: None,
+        detail_lines: DEFAULT_DETAIL_LINES,
+        refresh_interval: None,
+        key_bindings: BTreeMap::new(),
+        theme: resolve_theme(None, &BTreeMap::new(), no_color_requested()).unwrap_or_default(),
+        columns: Column::DEFAULT.to_vec(),
+        preset_drift: false,
+        sort_reverse: false,
+        mark_changed: false,
+        split: SplitLayout::Off,
+        boot: None,
     }
 }
 
@@ -266,16 +447,36 @@ impl FromStr for SubFilter {
     }
 }
 
+/// Validate a `load`, `active`, or `sub` filter value, returning its canonical name.
+pub fn validate_filter(key: &str, value: &str) -> Result<&'static str> {
+    match key {
+        "load" => value.parse::<LoadFilter>().map(LoadFilter::as_str),
+        "active" => value.parse::<ActiveFilter>().map(ActiveFilter::as_str),
+        "sub" => value.parse::<SubFilter>().map(SubFilter::as_str),
+        _ => Err(anyhow!("unknown filter: {key}; allowed: load, active, sub")),
+    }
+}
+
+/// Return the values accepted by a `load`, `active`, or `sub` filter.
+pub fn filter_values(key: &str) -> Vec<&'static str> {
+    let allowed = match key {
+        "load" => LoadFilter::allowed_values(),
+        "active" => ActiveFilter::allowed_values(),
+        "sub" => SubFilter::allowed_values(),
+        _ => return Vec::new(),
+    };
+    allowed.split(", ").collect()
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum SortArg {
     Auto,
-    Name,
-    Status,
+    Mode(SortMode),
 }
 
 impl SortArg {
     fn allowed_values() -> &'static str {
-        "auto, name, status"
+        "auto, name, status, load, active, sub, description, log, memory, restarts"
     }
 }
 
@@ -283,15 +484,15 @@ impl FromStr for SortArg {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        match s {
-            "auto" => Ok(Self::Auto),
-            "name" => Ok(Self::Name),
-            "status" => Ok(Self::Status),
-            _ => Err(anyhow!(
+        if s == "auto" {
+            return Ok(Self::Auto);
+        }
+        SortMode::from_name(s).map(Self::Mode).ok_or_else(|| {
+            anyhow!(
                 "invalid --sort value: {s}; allowed: {}",
                 Self::allowed_values()
-            )),
-        }
+            )
+        })
     }
 }
 
@@ -321,9 +522,41 @@ Options:
                        reload, reload-post, reload-signal, reload-notify, stop, stop-watchdog,
                        stop-sigterm, stop-sigkill, stop-post, final-sigterm, final-sigkill,
                        final-watchdog, cleaning)
-      --sort <value>   Sort order for the list view (auto, name, status)
+      --sort <value>   Sort order for the list view (auto, name, status, load, active, sub,
+                       description, log, memory, restarts)
                        auto (default): status when all filters are 'all', name otherwise
   -u, --user           Show units in user instead of system scope
+      --both           Show system and user units together, labeled with their scope
+  -M, --machine <name> Show units of a local container or VM (see machinectl list);
+                       with --user, a bare name means your own user there
+  -H, --host <host>    Show units of a remote [user@]host over ssh (key-based login)
+      --journal-dir <dir>
+                       Read logs from a journal directory instead of the system journal;
+                       units are discovered from the journal itself
+      --journal-file <file>
+                       Read logs from a journal file (repeatable); units are discovered
+                       from the journal itself
+      --root <dir>     Inspect unit files below an OS tree (chroot or mounted image);
+                       only unit-file state, enable/disable, and preset are available
+      --image <path>   Inspect unit files inside a disk image;
+                       only unit-file state, enable/disable, and preset are available
+      --preset-drift   Show only units whose enablement differs from the vendor preset;
+                       implies --all unless filters are given
+      --mark-changed   Keep a * marker on units whose state or last log line changed
+                       since launch; changed rows are always highlighted briefly
+      --columns <list> Comma-separated list table columns in display order
+                       (unit, scope, load, active, sub, description, log, unit-file-state,
+                       preset, pid, memory, cpu, tasks, restarts, active-since, type);
+                       default: unit,load,active,sub,description,log
+      --interval <secs>
+                       Refresh the unit list every <secs> seconds; 0 disables (default)
+      --split <value>  Show the selected unit's logs next to the list (off, below, beside);
+                       the log pane follows the selection
+      --theme <name>   Color theme (dark, light, high-contrast, monochrome, or a custom
+                       theme from the config file); NO_COLOR forces monochrome
+      --config <path>  Read defaults from this file instead of
+                       $XDG_CONFIG_HOME/lsu/config.toml (~/.config/lsu/config.toml);
+                       command-line options override config file values
   -h, --help           Show this help text
   -v, --version        Show version and copyright"
     )
@@ -339,7 +572,56 @@ apache v2 (c) 2026 l5yth"
     )
 }
 
+fn read_file_config(explicit: Option<&Path>) -> Result<FileConfig> {
+    if let Some(path) = explicit {
+        return load_config_file(path);
+    }
+    // Tests never pick up the config file of the user running them.
+    #[cfg(not(test))]
+    if let Some(path) = crate::config_file::default_config_path()
+        && path.exists()
+    {
+        return load_config_file(&path);
+    }
+    Ok(FileConfig::default())
+}
+
+/// Parse column names, rejecting unknown and repeated columns.
+fn parse_columns<'a, I>(names: I) -> Result<Vec<Column>>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut columns = Vec::new();
+    for name in names {
+        let name = name.trim();
+        let column = Column::from_name(name).ok_or_else(|| {
+            let allowed: Vec<&str> = Column::ALL.iter().map(|c| c.name()).collect();
+            anyhow!("invalid column: {name}; allowed: {}", allowed.join(", "))
+        })?;
+        if columns.contains(&column) {
+            return Err(anyhow!("duplicate column: {name}"));
+        }
+        columns.push(column);
+    }
+    if columns.is_empty() {
+        return Err(anyhow!("at least one column is required"));
+    }
+    Ok(columns)
+}
+
+fn parse_file_value<T>(value: Option<String>) -> Result<Option<T>>
+where
+    T: FromStr<Err = anyhow::Error>,
+{
+    value
+        .map(|v| v.parse())
+        .transpose()
+        .context("invalid config file value")
+}
+
 /// Parse command-line arguments into a [`Config`].
+///
+/// Values missing on the command line fall back to the config file.
 pub fn parse_args<I, S>(args: I) -> Result<Config>
 where
     I: IntoIterator<Item = S>,
@@ -356,6 +638,21 @@ where
     let mut saw_specific_filter = false;
     let mut scope = Scope::System;
     let mut sort_arg: Option<SortArg> = None;
+    let mut journal_dir: Option<PathBuf> = None;
+    let mut journal_files: Vec<PathBuf> = Vec::new();
+    let mut root_dir: Option<PathBuf> = None;
+    let mut image: Option<PathBuf> = None;
+    let mut machine: Option<String> = None;
+    let mut host: Option<String> = None;
+    let mut both_scopes = false;
+    let mut saw_user = false;
+    let mut config_path: Option<PathBuf> = None;
+    let mut theme_name: Option<String> = None;
+    let mut columns: Option<Vec<Column>> = None;
+    let mut preset_drift = false;
+    let mut interval: Option<u64> = None;
+    let mut mark_changed = false;
+    let mut split: Option<SplitLayout> = None;
 
     let mut it = args.into_iter();
     let _program = it.next();
@@ -398,6 +695,76 @@ where
             }
             "-u" | "--user" => {
                 scope = Scope::User;
+                saw_user = true;
+            }
+            "--both" => both_scopes = true,
+            "--preset-drift" => preset_drift = true,
+            "--mark-changed" => mark_changed = true,
+            "--journal-dir" => {
+                let value = it
+                    .next()
+                    .ok_or_else(|| anyhow!("missing value for {arg}\n\n{}", usage()))?;
+                journal_dir = Some(PathBuf::from(value));
+            }
+            "--journal-file" => {
+                let value = it
+                    .next()
+                    .ok_or_else(|| anyhow!("missing value for {arg}\n\n{}", usage()))?;
+                journal_files.push(PathBuf::from(value));
+            }
+            "--root" => {
+                let value = it
+                    .next()
+                    .ok_or_else(|| anyhow!("missing value for {arg}\n\n{}", usage()))?;
+                root_dir = Some(PathBuf::from(value));
+            }
+            "--image" => {
+                let value = it
+                    .next()
+                    .ok_or_else(|| anyhow!("missing value for {arg}\n\n{}", usage()))?;
+                image = Some(PathBuf::from(value));
+            }
+            "--config" => {
+                let value = it
+                    .next()
+                    .ok_or_else(|| anyhow!("missing value for {arg}\n\n{}", usage()))?;
+                config_path = Some(PathBuf::from(value));
+            }
+            "--columns" => {
+                let value = it
+                    .next()
+                    .ok_or_else(|| anyhow!("missing value for {arg}\n\n{}", usage()))?;
+                columns = Some(parse_columns(value.split(','))?);
+            }
+            "--theme" => {
+                let value = it
+                    .next()
+                    .ok_or_else(|| anyhow!("missing value for {arg}\n\n{}", usage()))?;
+                theme_name = Some(value);
+            }
+            "--interval" => {
+                let value = it
+                    .next()
+                    .ok_or_else(|| anyhow!("missing value for {arg}\n\n{}", usage()))?;
+                interval = Some(parse_interval(&value)?);
+            }
+            "--split" => {
+                let value = it
+                    .next()
+                    .ok_or_else(|| anyhow!("missing value for {arg}\n\n{}", usage()))?;
+                split = Some(parse_split(&value)?);
+            }
+            "-M" | "--machine" => {
+                let value = it
+                    .next()
+                    .ok_or_else(|| anyhow!("missing value for {arg}\n\n{}", usage()))?;
+                machine = Some(value);
+            }
+            "-H" | "--host" => {
+                let value = it
+                    .next()
+                    .ok_or_else(|| anyhow!("missing value for {arg}\n\n{}", usage()))?;
+                host = Some(value);
             }
             _ => {
                 if let Some(value) = arg.strip_prefix("--load=") {
@@ -411,6 +778,28 @@ where
                     saw_specific_filter = true;
                 } else if let Some(value) = arg.strip_prefix("--sort=") {
                     sort_arg = Some(value.parse()?);
+                } else if let Some(value) = arg.strip_prefix("--journal-dir=") {
+                    journal_dir = Some(PathBuf::from(value));
+                } else if let Some(value) = arg.strip_prefix("--journal-file=") {
+                    journal_files.push(PathBuf::from(value));
+                } else if let Some(value) = arg.strip_prefix("--root=") {
+                    root_dir = Some(PathBuf::from(value));
+                } else if let Some(value) = arg.strip_prefix("--image=") {
+                    image = Some(PathBuf::from(value));
+                } else if let Some(value) = arg.strip_prefix("--machine=") {
+                    machine = Some(value.to_string());
+                } else if let Some(value) = arg.strip_prefix("--host=") {
+                    host = Some(value.to_string());
+                } else if let Some(value) = arg.strip_prefix("--config=") {
+                    config_path = Some(PathBuf::from(value));
+                } else if let Some(value) = arg.strip_prefix("--columns=") {
+                    columns = Some(parse_columns(value.split(','))?);
+                } else if let Some(value) = arg.strip_prefix("--theme=") {
+                    theme_name = Some(value.to_string());
+                } else if let Some(value) = arg.strip_prefix("--interval=") {
+                    interval = Some(parse_interval(value)?);
+                } else if let Some(value) = arg.strip_prefix("--split=") {
+                    split = Some(parse_split(value)?);
                 } else {
                     return Err(anyhow!("unknown argument: {arg}\n\n{}", usage()));
                 }
@@ -425,6 +814,139 @@ where
         ));
     }
 
+    let file = if show_help || show_version {
+        FileConfig::default()
+    } else {
+        read_file_config(config_path.as_deref())?
+    };
+    if !saw_all && !saw_specific_filter {
+        saw_all = file.all.unwrap_or(false);
+        load_filter = parse_file_value(file.load)?;
+        active_filter = parse_file_value(file.active)?;
+        sub_filter = parse_file_value(file.sub)?;
+        saw_specific_filter =
+            load_filter.is_some() || active_filter.is_some() || sub_filter.is_some();
+        if saw_all && saw_specific_filter {
+            return Err(anyhow!(
+                "config file: all cannot be combined with load, active, or sub"
+            ));
+        }
+    }
+    if sort_arg.is_none() {
+        sort_arg = parse_file_value(file.sort)?;
+    }
+    preset_drift = preset_drift || file.preset_drift.unwrap_or(false);
+    mark_changed = mark_changed || file.mark_changed.unwrap_or(false);
+    if split.is_none() {
+        split = file
+            .split
+            .as_deref()
+            .map(parse_split)
+            .transpose()
+            .context("invalid config file value")?;
+    }
+    if !saw_user && !both_scopes {
+        match file.scope.as_deref() {
+            None | Some("system") => {}
+            Some("user") => scope = Scope::User,
+            Some("both") => both_scopes = true,
+            Some(other) => {
+                return Err(anyhow!(
+                    "invalid config file value: invalid scope value: {other}; allowed: system, user, both"
+                ));
+            }
+        }
+    }
+    // Transport, journal, and root options replace the file's values as a group so a
+    // command-line --host never gets combined with a machine from the file.
+    if machine.is_none() && host.is_none() {
+        machine = file.machine;
+        host = file.host;
+    }
+    if journal_dir.is_none() && journal_files.is_empty() {
+        journal_dir = file.journal_dir;
+        journal_files = file.journal_files.unwrap_or_default();
+    }
+    if root_dir.is_none() && image.is_none() {
+        root_dir = file.root;
+        image = file.image;
+    }
+    let command_timeout = match file.command_timeout {
+        Some(0) => {
+            return Err(anyhow!(
+                "invalid config file value: command_timeout must be positive"
+            ));
+        }
+        secs => secs.map(Duration::from_secs),
+    };
+    let detail_lines = match file.detail_lines {
+        Some(0) => {
+            return Err(anyhow!(
+                "invalid config file value: detail_lines must be positive"
+            ));
+        }
+        lines => lines.unwrap_or(DEFAULT_DETAIL_LINES),
+    };
+    let refresh_interval = interval
+        .or(file.refresh_interval)
+        .filter(|secs| *secs > 0)
+        .map(Duration::from_secs);
+    let columns = match (columns, file.columns) {
+        (Some(columns), _) => columns,
+        (None, Some(names)) => {
+            parse_columns(names.iter().map(String::as_str)).context("invalid config file value")?
+        }
+        (None, None) => Column::DEFAULT.to_vec(),
+    };
+    let theme = resolve_theme(
+        theme_name.as_deref().or(file.theme.as_deref()),
+        &file.themes.unwrap_or_default(),
+        no_color_requested(),
+    )?;
+
+    if saw_user && both_scopes {
+        return Err(anyhow!(
+            "--user cannot be combined with --both\n\n{}",
+            usage()
+        ));
+    }
+
+    let journal = match (journal_dir, journal_files.is_empty()) {
+        (Some(_), false) => {
+            return Err(anyhow!(
+                "--journal-dir cannot be combined with --journal-file\n\n{}",
+                usage()
+            ));
+        }
+        (Some(dir), true) => JournalSource::Directory(dir),
+        (None, false) => JournalSource::Files(journal_files),
+        (None, true) => JournalSource::Local,
+    };
+
+    let root = match (root_dir, image) {
+        (Some(_), Some(_)) => {
+            return Err(anyhow!(
+                "--root cannot be combined with --image\n\n{}",
+                usage()
+            ));
+        }
+        (Some(dir), None) => UnitFileRoot::Directory(dir),
+        (None, Some(image)) => UnitFileRoot::Image(image),
+        (None, None) => UnitFileRoot::Live,
+    };
+    // Without an explicit journal, read the journal that belongs to the offline root.
+    let journal = match (&journal, root.journal_source()) {
+        (JournalSource::Local, Some(root_journal)) => root_journal,
+        _ => journal,
+    };
+    let target = Target {
+        scope,
+        machine,
+        host,
+    };
+    validate_target(&target, both_scopes, &journal, &root)
+        .map_err(|err| anyhow!("{err}\n\n{}", usage()))?;
+
     let (load, active, sub) = if saw_all {
         (LoadFilter::All, ActiveFilter::All, SubFilter::All)
     } else if saw_specific_filter {
@@ -433,13 +955,15 @@ where
             active_filter.unwrap_or(ActiveFilter::All),
             sub_filter.unwrap_or(SubFilter::All),
         )
+    } else if preset_drift {
+        // Drifted units are usually not running, so drift alone looks at every unit.
+        (LoadFilter::All, ActiveFilter::All, SubFilter::All)
     } else {
         (LoadFilter::Loaded, ActiveFilter::Active, SubFilter::Running)
     };
 
     let sort_mode = match sort_arg.unwrap_or(SortArg::Auto) {
-        SortArg::Name => SortMode::Name,
-        SortArg::Status => SortMode::Status,
+        SortArg::Mode(mode) => mode,
         SortArg::Auto => {
             if load == LoadFilter::All && active == ActiveFilter::All && sub == SubFilter::All {
                 SortMode::Status
@@ -456,8 +980,22 @@ where
         show_help,
         show_version,
         debug_tui: false,
-        scope,
+        target,
+        both_scopes,
         sort_mode,
+        journal,
+        root,
+        command_timeout,
+        detail_lines,
+        refresh_interval,
+        key_bindings: file.keys.unwrap_or_default(),
+        theme,
+        columns,
+        preset_drift,
+        sort_reverse: false,
+        mark_changed,
+        split: split.unwrap_or_default(),
+        boot: None,
     })
 }
 
@@ -523,10 +1061,10 @@ mod tests {
     #[test]
     fn parse_args_user_scope_flag() {
         let cfg = parse_args(vec!["lsu", "--user"]).expect("user scope should parse");
-        assert!(matches!(cfg.scope, Scope::User));
+        assert!(matches!(cfg.target.scope, Scope::User));
 
         let cfg = parse_args(vec!["lsu", "-u"]).expect("short user scope should parse");
-        assert!(matches!(cfg.scope, Scope::User));
+        assert!(matches!(cfg.target.scope, Scope::User));
     }
 
     #[test]
@@ -572,6 +1110,132 @@ mod tests {
         assert!(usage().contains(&format!("lsu v{}", env!("CARGO_PKG_VERSION"))));
     }
 
+    #[test]
+    fn parse_args_machine_flag() {
+        let cfg = parse_args(vec!["lsu", "--machine", "web"]).expect("machine should parse");
+        assert_eq!(cfg.target.machine.as_deref(), Some("web"));
+        assert_eq!(cfg.target.scope, Scope::System);
+
+        let cfg = parse_args(vec!["lsu", "-u", "-M", "dev@web"]).expect("short machine");
+        assert_eq!(
+            cfg.target,
+            Target {
+                scope: Scope::User,
+                machine: Some("dev@web".to_string()),
+                host: None,
+            }
+        );
+
+        let cfg = parse_args(vec!["lsu", "--machine=db"]).expect("machine= should parse");
+        assert_eq!(cfg.target.machine.as_deref(), Some("db"));
+        assert!(
+            parse_args(vec!["lsu"])
+                .expect("defaults")
+                .target
+                .machine
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn parse_args_both_scopes_flag() {
+        let cfg = parse_args(vec!["lsu", "--both"]).expect("both should parse");
+        assert!(cfg.both_scopes);
+        assert_eq!(cfg.scopes(), vec![Scope::System, Scope::User]);
+        let user_cfg = cfg.for_scope(Scope::User);
+        assert!(!user_cfg.both_scopes);
+        assert_eq!(user_cfg.target.scope, Scope::User);
+        assert_eq!(user_cfg.scopes(), vec![Scope::User]);
+
+        let err = parse_args(vec!["lsu", "--user", "--both"]).expect_err("user+both");
+        assert!(
+            err.to_string()
+                .contains("--user cannot be combined with --both")
+        );
+        assert!(!parse_args(vec!["lsu"]).expect("defaults").both_scopes);
+    }
+
+    #[test]
+    fn parse_args_preset_drift_lists_all_units_unless_filtered() {
+        let cfg = parse_args(vec!["lsu", "--preset-drift"]).expect("drift should parse");
+        assert!(cfg.preset_drift);
+        assert_eq!(cfg.load_filter, "all");
+        assert_eq!(cfg.active_filter, "all");
+        assert_eq!(cfg.sub_filter, "all");
+        assert_eq!(cfg.sort_mode, SortMode::Status);
+
+        let cfg = parse_args(vec!["lsu", "--preset-drift", "--active", "failed"])
+            .expect("drift with filter should parse");
+        assert!(cfg.preset_drift);
+        assert_eq!(cfg.active_filter, "failed");
+        assert!(!parse_args(vec!["lsu"]).expect("defaults").preset_drift);
+    }
+
+    #[test]
+    fn parse_args_mark_changed_flag_and_config_file() {
+        assert!(!parse_args(vec!["lsu"]).expect("defaults").mark_changed);
+        assert!(
+            parse_args(vec!["lsu", "--mark-changed"])
+                .expect("flag")
+                .mark_changed
+        );
+        let path = write_config("mark-changed", "mark_changed = true\n");
+        let cfg = parse_args(vec!["lsu", "--config", &path]).expect("config should apply");
+        assert!(cfg.mark_changed);
+    }
+
+    #[test]
+    fn parse_args_host_flag() {
+        let cfg = parse_args(vec!["lsu", "--host", "ops@db1"]).expect("host should parse");
+        assert_eq!(cfg.target.host.as_deref(), Some("ops@db1"));
+        let cfg = parse_args(vec!["lsu", "-H", "db2"]).expect("short host should parse");
+        assert_eq!(cfg.target.host.as_deref(), Some("db2"));
+        let cfg = parse_args(vec!["lsu", "--host=db3"]).expect("host= should parse");
+        assert_eq!(cfg.target.host.as_deref(), Some("db3"));
+    }
+
+    #[test]
+    fn parse_args_rejects_host_with_machine_or_offline_sources() {
+        let err = parse_args(vec!["lsu", "-H", "db1", "-M", "web"]).expect_err("host+machine");
+        assert!(
+            err.to_string()
+                .contains("--host cannot be combined with --machine")
+        );
+        assert!(parse_args(vec!["lsu", "-H", "db1", "--image", "os.raw"]).is_err());
+        let err = parse_args(vec!["lsu", "--user", "-H", "db1"]).expect_err("user+host");
+        assert!(
+            err.to_string()
+                .contains("--user cannot be combined with --host")
+        );
+        let err = parse_args(vec!["lsu", "--both", "-H", "db1"]).expect_err("both+host");
+        assert!(
+            err.to_string()
+                .contains("--both cannot be combined with --host")
+        );
+        let path = write_config("both-host", "scope = \"both\"\n");
+        let err =
+            parse_args(vec!["lsu", "--config", &path, "-H", "db1"]).expect_err("file both+host");
+        assert!(
+            err.to_string()
+                .contains("--both cannot be combined with --host")
+        );
+        let err = parse_args(vec!["lsu", "--host"]).expect_err("missing host");
+        assert!(err.to_string().contains("missing value for --host"));
+    }
+
+    #[test]
+    fn parse_args_rejects_machine_with_offline_sources() {
+        let err = parse_args(vec!["lsu", "--machine", "web", "--root", "/mnt"])
+            .expect_err("machine with root");
+        assert!(
+            err.to_string()
+                .contains("--machine cannot be combined with offline journals or roots")
+        );
+        assert!(parse_args(vec!["lsu", "-M", "web", "--journal-dir", "/tmp/j"]).is_err());
+        let err = parse_args(vec!["lsu", "--machine"]).expect_err("missing machine");
+        assert!(err.to_string().contains("missing value for --machine"));
+    }
+
     #[test]
     fn usage_mentions_user_scope_flag() {
         assert!(usage().contains("--user"));
@@ -748,7 +1412,7 @@ mod tests {
         assert_eq!(cfg.sub_filter, "all");
         assert!(!cfg.show_help);
         assert!(!cfg.show_version);
-        assert!(matches!(cfg.scope, Scope::System));
+        assert!(matches!(cfg.target.scope, Scope::System));
         assert_eq!(cfg.sort_mode, SortMode::Status);
     }
 
@@ -778,6 +1442,18 @@ mod tests {
         assert_eq!(cfg.sort_mode, SortMode::Status);
     }
 
+    #[test]
+    fn parse_args_sort_by_column() {
+        let cfg = parse_args(vec!["lsu", "--sort", "memory"]).expect("memory sort should parse");
+        assert_eq!(cfg.sort_mode, SortMode::Memory);
+        assert!(cfg.needs_unit_properties());
+        assert!(!cfg.sort_reverse);
+
+        let cfg = parse_args(vec!["lsu", "--sort=log"]).expect("log sort should parse");
+        assert_eq!(cfg.sort_mode, SortMode::LastLog);
+        assert!(!cfg.needs_unit_properties());
+    }
+
     #[test]
     fn parse_args_sort_auto_resolves_based_on_filters() {
         let cfg = parse_args(vec!["lsu", "--all", "--sort", "auto"])
@@ -837,6 +1513,308 @@ mod tests {
         assert!(usage().contains("--sort"));
     }
 
+    #[test]
+    fn parse_args_journal_source_defaults_to_local() {
+        let cfg = parse_args(vec!["lsu"]).expect("default args should parse");
+        assert_eq!(cfg.journal, JournalSource::Local);
+    }
+
+    #[test]
+    fn parse_args_journal_dir_in_both_forms() {
+        let cfg = parse_args(vec!["lsu", "--journal-dir", "/tmp/j"]).expect("dir should parse");
+        assert_eq!(
+            cfg.journal,
+            JournalSource::Directory(PathBuf::from("/tmp/j"))
+        );
+
+        let cfg = parse_args(vec!["lsu", "--journal-dir=/tmp/k"]).expect("dir should parse");
+        assert_eq!(
+            cfg.journal,
+            JournalSource::Directory(PathBuf::from("/tmp/k"))
+        );
+    }
+
+    #[test]
+    fn parse_args_journal_file_is_repeatable() {
+        let cfg = parse_args(vec![
+            "lsu",
+            "--journal-file",
+            "a.journal",
+            "--journal-file=b.journal",
+        ])
+        .expect("files should parse");
+        assert_eq!(
+            cfg.journal,
+            JournalSource::Files(vec![PathBuf::from("a.journal"), PathBuf::from("b.journal")])
+        );
+    }
+
+    #[test]
+    fn parse_args_rejects_journal_dir_with_journal_file() {
+        let err = parse_args(vec![
+            "lsu",
+            "--journal-dir",
+            "/tmp/j",
+            "--journal-file",
+            "a.journal",
+        ])
+        .expect_err("must reject mixed journal sources");
+        assert!(
+            err.to_string()
+                .contains("--journal-dir cannot be combined with --journal-file")
+        );
+    }
+
+    #[test]
+    fn parse_args_rejects_missing_journal_values() {
+        let err = parse_args(vec!["lsu", "--journal-dir"]).expect_err("missing dir");
+        assert!(err.to_string().contains("missing value for --journal-dir"));
+
+        let err = parse_args(vec!["lsu", "--journal-file"]).expect_err("missing file");
+        assert!(err.to_string().contains("missing value for --journal-file"));
+    }
+
+    #[test]
+    fn parse_args_root_and_image_select_offline_unit_files() {
+        let cfg = parse_args(vec!["lsu", "--root", "/mnt/os"]).expect("root should parse");
+        assert_eq!(cfg.root, UnitFileRoot::Directory(PathBuf::from("/mnt/os")));
+        assert_eq!(cfg.journal, JournalSource::Root(PathBuf::from("/mnt/os")));
+
+        let cfg = parse_args(vec!["lsu", "--image=os.raw"]).expect("image should parse");
+        assert_eq!(cfg.root, UnitFileRoot::Image(PathBuf::from("os.raw")));
+        assert_eq!(cfg.journal, JournalSource::Image(PathBuf::from("os.raw")));
+
+        let cfg = parse_args(vec!["lsu"]).expect("default args should parse");
+        assert_eq!(cfg.root, UnitFileRoot::Live);
+    }
+
+    #[test]
+    fn parse_args_root_keeps_explicit_journal_source() {
+        let cfg = parse_args(vec!["lsu", "--root=/mnt/os", "--journal-dir", "/tmp/j"])
+            .expect("root with journal dir should parse");
+        assert_eq!(cfg.root, UnitFileRoot::Directory(PathBuf::from("/mnt/os")));
+        assert_eq!(
+            cfg.journal,
+            JournalSource::Directory(PathBuf::from("/tmp/j"))
+        );
+    }
+
+    #[test]
+    fn parse_args_rejects_root_with_image_and_missing_values() {
+        let err = parse_args(vec!["lsu", "--root", "/mnt", "--image", "os.raw"])
+            .expect_err("must reject root with image");
+        assert!(
+            err.to_string()
+                .contains("--root cannot be combined with --image")
+        );
+
+        let err = parse_args(vec!["lsu", "--root"]).expect_err("missing root");
+        assert!(err.to_string().contains("missing value for --root"));
+
+        let err = parse_args(vec!["lsu", "--image"]).expect_err("missing image");
+        assert!(err.to_string().contains("missing value for --image"));
+    }
+
+    #[test]
+    fn usage_mentions_journal_flags() {
+        assert!(usage().contains("--journal-dir <dir>"));
+        assert!(usage().contains("--journal-file <file>"));
+    }
+
+    fn write_config(label: &str, text: &str) -> String {
+        let n = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("time should be monotonic")
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("lsu-config-{label}-{n}.toml"));
+        std::fs::write(&path, text).expect("write config");
+        path.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn parse_args_uses_config_file_defaults() {
+        let path = write_config(
+            "defaults",
+            "active = \"failed\"\nscope = \"both\"\nsort = \"status\"\nmachine = \"db1\"\n\
+             command_timeout = 9\ndetail_lines = 1000\nrefresh_interval = 0\npreset_drift = true\n",
+        );
+        let cfg = parse_args(vec!["lsu", "--config", &path]).expect("config should apply");
+        assert_eq!(cfg.load_filter, "all");
+        assert_eq!(cfg.active_filter, "failed");
+        assert_eq!(cfg.sub_filter, "all");
+        assert!(cfg.both_scopes);
+        assert_eq!(cfg.sort_mode, SortMode::Status);
+        assert_eq!(cfg.target.machine.as_deref(), Some("db1"));
+        assert_eq!(cfg.command_timeout, Some(Duration::from_secs(9)));
+        assert_eq!(cfg.detail_lines, 1000);
+        assert_eq!(cfg.refresh_interval, None);
+        assert!(cfg.preset_drift);
+    }
+
+    #[test]
+    fn parse_args_flags_override_config_file() {
+        let path = write_config(
+            "override",
+            "all = true\nscope = \"both\"\nmachine = \"web\"\nrefresh_interval = 3\n",
+        );
+        let config_arg = format!("--config={path}");
+        let cfg = parse_args(vec![
+            "lsu",
+            &config_arg,
+            "--sub",
+            "dead",
+            "--user",
+            "-M",
+            "db1",
+        ])
+        .expect("flags should override");
+        assert_eq!(cfg.load_filter, "all");
+        assert_eq!(cfg.sub_filter, "dead");
+        assert!(!cfg.both_scopes);
+        assert_eq!(cfg.target.scope, Scope::User);
+        assert_eq!(cfg.target.machine.as_deref(), Some("db1"));
+        assert_eq!(cfg.target.host, None);
+        assert_eq!(cfg.refresh_interval, Some(Duration::from_secs(3)));
+        assert_eq!(cfg.detail_lines, DEFAULT_DETAIL_LINES);
+    }
+
+    #[test]
+    fn parse_args_interval_overrides_config_file() {
+        let cfg = parse_args(vec!["lsu", "--interval", "5"]).expect("interval should parse");
+        assert_eq!(cfg.refresh_interval, Some(Duration::from_secs(5)));
+
+        let path = write_config("interval", "refresh_interval = 3\n");
+        let cfg = parse_args(vec!["lsu", "--config", &path, "--interval=0"])
+            .expect("zero interval should parse");
+        assert_eq!(cfg.refresh_interval, None);
+
+        let err = parse_args(vec!["lsu", "--interval", "soon"]).expect_err("should fail");
+        assert!(err.to_string().contains("invalid interval: soon"));
+    }
+
+    #[test]
+    fn parse_args_split_from_flag_and_config_file() {
+        assert_eq!(
+            parse_args(vec!["lsu"]).expect("defaults").split,
+            SplitLayout::Off
+        );
+        let cfg = parse_args(vec!["lsu", "--split", "below"]).expect("split should parse");
+        assert_eq!(cfg.split, SplitLayout::Below);
+
+        let path = write_config("split", "split = \"beside\"\n");
+        let cfg = parse_args(vec!["lsu", "--config", &path]).expect("file split");
+        assert_eq!(cfg.split, SplitLayout::Beside);
+        let cfg = parse_args(vec!["lsu", "--config", &path, "--split=off"])
+            .expect("flag overrides file split");
+        assert_eq!(cfg.split, SplitLayout::Off);
+
+        let err = parse_args(vec!["lsu", "--split", "left"]).expect_err("invalid split");
+        assert!(err.to_string().contains("invalid --split value: left"));
+    }
+
+    #[test]
+    fn validate_filter_checks_values_per_key() {
+        assert_eq!(validate_filter("active", "failed").unwrap(), "failed");
+        assert_eq!(
+            validate_filter("sub", "auto-restart").unwrap(),
+            "auto-restart"
+        );
+        assert!(validate_filter("load", "running").is_err());
+        assert!(
+            validate_filter("state", "running")
+                .unwrap_err()
+                .to_string()
+                .contains("unknown filter: state")
+        );
+        assert!(filter_values("load").contains(&"masked"));
+        assert!(filter_values("state").is_empty());
+    }
+
+    #[test]
+    fn parse_args_selects_builtin_and_config_file_themes() {
+        let cfg = parse_args(vec!["lsu"]).expect("default theme");
+        assert_eq!(cfg.theme, Theme::dark());
+        let cfg = parse_args(vec!["lsu", "--theme", "high-contrast"]).expect("builtin");
+        assert_eq!(cfg.theme, Theme::high_contrast());
+
+        let path = write_config(
+            "theme",
+            "theme = \"mine\"\n[themes.mine]\nbase = \"monochrome\"\nglyphs = false\n",
+        );
+        let cfg = parse_args(vec!["lsu", "--config", &path]).expect("custom theme");
+        assert!(!cfg.theme.glyphs);
+        let cfg = parse_args(vec!["lsu", "--config", &path, "--theme=light"])
+            .expect("flag overrides file theme");
+        assert_eq!(cfg.theme, Theme::light());
+
+        let err = parse_args(vec!["lsu", "--theme", "neon"]).expect_err("unknown theme");
+        assert!(err.to_string().contains("unknown theme: neon"));
+    }
+
+    #[test]
+    fn parse_args_columns_from_flag_and_config_file() {
+        let cfg = parse_args(vec!["lsu"]).expect("default columns");
+        assert_eq!(cfg.columns, Column::DEFAULT.to_vec());
+        assert!(!cfg.needs_unit_properties());
+
+        let cfg = parse_args(vec!["lsu", "--columns", "unit,memory,cpu,log", "--both"])
+            .expect("columns flag");
+        assert_eq!(
+            cfg.list_columns(),
+            vec![
+                Column::Unit,
+                Column::Scope,
+                Column::Memory,
+                Column::Cpu,
+                Column::Log
+            ]
+        );
+        assert!(cfg.needs_unit_properties());
+
+        assert_eq!(cfg.unit_properties(), vec!["MemoryCurrent", "CPUUsageNSec"]);
+
+        let cfg = parse_args(vec!["lsu", "--root", "/mnt/rescue"]).expect("offline root");
+        assert_eq!(cfg.list_columns()[1], Column::UnitFileState);
+        assert!(!cfg.needs_unit_properties());
+
+        let path = write_config("columns", "columns = [\"pid\", \"unit\"]\n");
+        let cfg = parse_args(vec!["lsu", "--config", &path]).expect("file columns");
+        assert_eq!(cfg.columns, vec![Column::MainPid, Column::Unit]);
+        let cfg = parse_args(vec!["lsu", "--config", &path, "--columns=type"])
+            .expect("flag overrides file");
+        assert_eq!(cfg.columns, vec![Column::Type]);
+
+        let err = parse_args(vec!["lsu", "--columns", "unit,rss"]).expect_err("unknown");
+        assert!(
+            err.to_string()
+                .starts_with("invalid column: rss; allowed: unit, scope")
+        );
+        let err = parse_args(vec!["lsu", "--columns", "unit,unit"]).expect_err("duplicate");
+        assert_eq!(err.to_string(), "duplicate column: unit");
+    }
+
+    #[test]
+    fn parse_args_rejects_invalid_config_file_values() {
+        let path = write_config("bad-load", "load = \"weird\"\n");
+        let err = parse_args(vec!["lsu", "--config", &path]).expect_err("bad load");
+        assert!(format!("{err:#}").contains("invalid --load value: weird"));
+
+        let path = write_config("bad-scope", "scope = \"all\"\n");
+        let err = parse_args(vec!["lsu", "--config", &path]).expect_err("bad scope");
+        assert!(err.to_string().contains("invalid scope value: all"));
+
+        let path = write_config("bad-lines", "detail_lines = 0\n");
+        let err = parse_args(vec!["lsu", "--config", &path]).expect_err("zero lines");
+        assert!(err.to_string().contains("detail_lines must be positive"));
+
+        let err = parse_args(vec!["lsu", "--config", "/nonexistent/lsu.toml"])
+            .expect_err("explicit config must exist");
+        assert!(err.to_string().contains("/nonexistent/lsu.toml"));
+
+        let err = parse_args(vec!["lsu", "--config"]).expect_err("missing config path");
+        assert!(err.to_string().contains("missing value for --config"));
+    }
+
     #[cfg(not(feature = "debug_tui"))]
     #[test]
     fn parse_args_rejects_debug_tui_flag_without_feature() {
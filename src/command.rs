@@ -22,16 +22,19 @@
 use anyhow::{Result, anyhow, bail};
 use std::collections::{HashSet, hash_map::DefaultHasher};
 use std::env;
+use std::ffi::{OsStr, OsString};
 use std::hash::{Hash, Hasher};
 use std::io::Read;
 use std::path::{Path, PathBuf};
 use std::process::{Command, ExitStatus, Stdio};
+use std::sync::OnceLock;
 use std::thread;
 use std::time::{Duration, Instant};
 
-const ALLOWED_BINARIES: [&str; 2] = ["systemctl", "journalctl"];
+const ALLOWED_BINARIES: [&str; 4] = ["systemctl", "journalctl", "machinectl", "ssh"];
 const TRUSTED_DIRS: [&str; 5] = ["/usr/bin", "/bin", "/usr/sbin", "/sbin", "/usr/local/bin"];
 const DEFAULT_CMD_TIMEOUT_SECS: u64 = 5;
+static CONFIGURED_CMD_TIMEOUT: OnceLock<Duration> = OnceLock::new();
 
 /// Structured subprocess failure modes.
 #[derive(Debug)]
@@ -102,14 +105,54 @@ impl From<std::io::Error> for CommandExecError {
     }
 }
 
+/// Set the subprocess timeout used when `LSU_CMD_TIMEOUT_SECS` is not set.
+///
+/// Only the first call has an effect.
+pub fn set_configured_command_timeout(timeout: Duration) {
+    let _ = CONFIGURED_CMD_TIMEOUT.set(timeout);
+}
+
 /// Return the global subprocess timeout.
 pub fn command_timeout() -> Duration {
-    let secs = env::var("LSU_CMD_TIMEOUT_SECS")
-        .ok()
+    resolve_command_timeout(
+        env::var("LSU_CMD_TIMEOUT_SECS").ok(),
+        CONFIGURED_CMD_TIMEOUT.get().copied(),
+    )
+}
+
+fn resolve_command_timeout(env_value: Option<String>, configured: Option<Duration>) -> Duration {
+    env_value
         .and_then(|v| v.parse::<u64>().ok())
         .filter(|v| *v > 0)
-        .unwrap_or(DEFAULT_CMD_TIMEOUT_SECS);
-    Duration::from_secs(secs)
+        .map(Duration::from_secs)
+        .or(configured)
+        .unwrap_or(Duration::from_secs(DEFAULT_CMD_TIMEOUT_SECS))
+}
+
+/// Quote one argument for the POSIX shell that runs an `ssh` remote command.
+pub fn shell_quote(arg: &OsStr) -> String {
+    let arg = arg.to_string_lossy();
+    if !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./=:@,+".contains(c))
+    {
+        return arg.into_owned();
+    }
+    format!("'{}'", arg.replace('\'', r"'\''"))
+}
+
+/// Build a command that runs `program` with `args` on a remote host through `ssh`.
+///
+/// `ssh` joins its trailing arguments into one remote shell command line, so each
+/// argument is quoted. `BatchMode` keeps password prompts from drawing over the TUI;
+/// key-based authentication is expected.
+pub fn ssh_command(host: &str, program: &str, args: &[OsString]) -> Result<Command> {
+    let ssh = resolve_trusted_binary("ssh")?;
+    let mut cmd = Command::new(ssh);
+    cmd.args(["-o", "BatchMode=yes", "-T", "--", host, program]);
+    cmd.args(args.iter().map(|arg| shell_quote(arg)));
+    Ok(cmd)
 }
 
 /// Run a command and return UTF-8 decoded stdout on success.
@@ -262,11 +305,10 @@ fn resolve_trusted_binary_in(
         }
     }
 
-    if binary == "systemctl" {
-        Err(anyhow!("no systemctl command found, do use systemd?"))
+    if binary == "ssh" {
+        Err(anyhow!("no ssh command found, it is required for --host"))
     } else {
-        // ALLOWED_BINARIES gate above leaves only journalctl here.
-        Err(anyhow!("no journalctl command found, do use systemd?"))
+        Err(anyhow!("no {binary} command found, do use systemd?"))
     }
 }
 
@@ -447,6 +489,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn resolve_command_timeout_prefers_env_then_configured_value() {
+        let configured = Some(Duration::from_secs(12));
+        assert_eq!(
+            resolve_command_timeout(Some("3".to_string()), configured),
+            Duration::from_secs(3)
+        );
+        assert_eq!(
+            resolve_command_timeout(Some("0".to_string()), configured),
+            Duration::from_secs(12)
+        );
+        assert_eq!(
+            resolve_command_timeout(None, configured),
+            Duration::from_secs(12)
+        );
+        assert_eq!(resolve_command_timeout(None, None), Duration::from_secs(5));
+    }
+
     #[test]
     fn command_timeout_uses_positive_env_value() {
         let _guard = ENV_LOCK.lock().expect("lock env");
@@ -596,6 +656,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn resolve_trusted_binary_errors_name_missing_machinectl() {
+        let err = resolve_trusted_binary_in("machinectl", None, &[]).expect_err("must fail");
+        assert!(
+            err.to_string()
+                .contains("no machinectl command found, do use systemd?")
+        );
+    }
+
+    #[test]
+    fn shell_quote_leaves_plain_words_and_quotes_the_rest() {
+        assert_eq!(shell_quote(OsStr::new("sshd.service")), "sshd.service");
+        assert_eq!(shell_quote(OsStr::new("--user")), "--user");
+        assert_eq!(shell_quote(OsStr::new("")), "''");
+        assert_eq!(shell_quote(OsStr::new("a b")), "'a b'");
+        assert_eq!(shell_quote(OsStr::new("it's;rm")), r"'it'\''s;rm'");
+    }
+
+    #[test]
+    fn resolve_trusted_binary_errors_name_missing_ssh() {
+        let err = resolve_trusted_binary_in("ssh", None, &[]).expect_err("must fail");
+        assert!(err.to_string().contains("no ssh command found"));
+    }
+
     #[test]
     fn resolve_trusted_binary_rejects_symlink_to_different_binary_name() {
         #[cfg(not(unix))]
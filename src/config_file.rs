@@ -0,0 +1,198 @@
+/*
+   Copyright (C) 2026 l5yth
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Optional TOML config file with defaults for command-line options.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+use crate::theme::ThemeSpec;
+
+/// Defaults read from `config.toml`; every field is optional.
+///
+/// Filter, scope, and sort values use the same spelling as their CLI flags.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FileConfig {
+    /// Same as `--all`.
+    pub all: Option<bool>,
+    /// Same as `--load`.
+    pub load: Option<String>,
+    /// Same as `--active`.
+    pub active: Option<String>,
+    /// Same as `--sub`.
+    pub sub: Option<String>,
+    /// `system`, `user` (same as `--user`), or `both` (same as `--both`).
+    pub scope: Option<String>,
+    /// Same as `--sort`.
+    pub sort: Option<String>,
+    /// Same as `--preset-drift`.
+    pub preset_drift: Option<bool>,
+    /// Same as `--mark-changed`.
+    pub mark_changed: Option<bool>,
+    /// Same as `--split`.
+    pub split: Option<String>,
+    /// Same as `--machine`.
+    pub machine: Option<String>,
+    /// Same as `--host`.
+    pub host: Option<String>,
+    /// Same as `--journal-dir`.
+    pub journal_dir: Option<PathBuf>,
+    /// Same as repeating `--journal-file`.
+    pub journal_files: Option<Vec<PathBuf>>,
+    /// Same as `--root`.
+    pub root: Option<PathBuf>,
+    /// Same as `--image`.
+    pub image: Option<PathBuf>,
+    /// Subprocess timeout in seconds; `LSU_CMD_TIMEOUT_SECS` still wins.
+    pub command_timeout: Option<u64>,
+    /// Number of log lines fetched for the detail view.
+    pub detail_lines: Option<usize>,
+    /// Automatic list refresh interval in seconds; `0` disables it.
+    pub refresh_interval: Option<u64>,
+    /// Key sequences per UI command, replacing the default keys of that command.
+    pub keys: Option<BTreeMap<String, Vec<String>>>,
+    /// Same as `--columns`, as a list of column names.
+    pub columns: Option<Vec<String>>,
+    /// Same as `--theme`.
+    pub theme: Option<String>,
+    /// Custom themes selectable by name.
+    pub themes: Option<BTreeMap<String, ThemeSpec>>,
+}
+
+/// Parse config file contents.
+pub fn parse_config_file(text: &str) -> Result<FileConfig> {
+    Ok(toml::from_str(text)?)
+}
+
+/// Resolve the config path from `$XDG_CONFIG_HOME`, falling back to `$HOME/.config`.
+pub fn config_path_from(
+    xdg_config_home: Option<OsString>,
+    home: Option<OsString>,
+) -> Option<PathBuf> {
+    let base = match xdg_config_home.filter(|v| !v.is_empty()) {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(home.filter(|v| !v.is_empty())?).join(".config"),
+    };
+    Some(base.join("lsu").join("config.toml"))
+}
+
+/// Return the default config path for the current user.
+pub fn default_config_path() -> Option<PathBuf> {
+    config_path_from(
+        std::env::var_os("XDG_CONFIG_HOME"),
+        std::env::var_os("HOME"),
+    )
+}
+
+/// Read and parse a config file.
+pub fn load_config_file(path: &Path) -> Result<FileConfig> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read config file {}", path.display()))?;
+    parse_config_file(&text).with_context(|| format!("invalid config file {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_config_file_reads_all_fields() {
+        let cfg = parse_config_file(
+            r##"
+load = "loaded"
+active = "failed"
+sub = "dead"
+scope = "both"
+sort = "status"
+preset_drift = true
+mark_changed = true
+split = "beside"
+host = "admin@db1"
+journal_files = ["/tmp/a.journal", "/tmp/b.journal"]
+command_timeout = 12
+detail_lines = 1000
+refresh_interval = 5
+
+theme = "mine"
+
+[keys]
+move_down = ["j", "down"]
+quit = ["Z Z"]
+
+[themes.mine]
+base = "light"
+failed = "#ff0000"
+"##,
+        )
+        .expect("config should parse");
+        assert_eq!(cfg.active.as_deref(), Some("failed"));
+        assert_eq!(cfg.scope.as_deref(), Some("both"));
+        assert_eq!(cfg.preset_drift, Some(true));
+        assert_eq!(cfg.mark_changed, Some(true));
+        assert_eq!(cfg.split.as_deref(), Some("beside"));
+        assert_eq!(cfg.host.as_deref(), Some("admin@db1"));
+        assert_eq!(
+            cfg.journal_files,
+            Some(vec![
+                PathBuf::from("/tmp/a.journal"),
+                PathBuf::from("/tmp/b.journal")
+            ])
+        );
+        assert_eq!(cfg.command_timeout, Some(12));
+        assert_eq!(cfg.detail_lines, Some(1000));
+        assert_eq!(cfg.refresh_interval, Some(5));
+        assert_eq!(cfg.all, None);
+        let keys = cfg.keys.expect("keys table");
+        assert_eq!(keys["move_down"], vec!["j", "down"]);
+        assert_eq!(keys["quit"], vec!["Z Z"]);
+        assert_eq!(cfg.theme.as_deref(), Some("mine"));
+        let themes = cfg.themes.expect("themes table");
+        assert_eq!(themes["mine"].base.as_deref(), Some("light"));
+        assert_eq!(themes["mine"].failed.as_deref(), Some("#ff0000"));
+    }
+
+    #[test]
+    fn parse_config_file_accepts_empty_and_rejects_unknown_keys() {
+        assert_eq!(parse_config_file("").expect("empty"), FileConfig::default());
+        let err = parse_config_file("colour = \"red\"").expect_err("unknown key");
+        assert!(err.to_string().contains("colour"));
+        assert!(parse_config_file("detail_lines = \"many\"").is_err());
+    }
+
+    #[test]
+    fn config_path_prefers_xdg_and_falls_back_to_home() {
+        assert_eq!(
+            config_path_from(Some("/xdg".into()), Some("/home/me".into())),
+            Some(PathBuf::from("/xdg/lsu/config.toml"))
+        );
+        assert_eq!(
+            config_path_from(Some("".into()), Some("/home/me".into())),
+            Some(PathBuf::from("/home/me/.config/lsu/config.toml"))
+        );
+        assert_eq!(config_path_from(None, None), None);
+    }
+
+    #[test]
+    fn load_config_file_reports_path_on_errors() {
+        let err = load_config_file(Path::new("/nonexistent/lsu/config.toml"))
+            .expect_err("missing file should fail");
+        assert!(err.to_string().contains("/nonexistent/lsu/config.toml"));
+    }
+}
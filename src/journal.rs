@@ -23,6 +23,9 @@
 use anyhow::Result;
 #[cfg(not(test))]
 use anyhow::{Context, bail};
+#[cfg(not(test))]
+use chrono::Local;
+use chrono::{DateTime, FixedOffset, TimeZone};
 use std::collections::{HashMap, HashSet};
 #[cfg(not(test))]
 use std::io::{BufRead, BufReader, Read};
@@ -36,8 +39,12 @@ use std::thread;
 use std::time::{Duration, Instant};
 
 #[cfg(not(test))]
-use crate::command::{CommandExecError, cmd_stdout, command_timeout, resolve_trusted_binary};
-use crate::types::{DetailLogEntry, Scope};
+use crate::command::{
+    CommandExecError, cmd_stdout, command_timeout, resolve_trusted_binary, ssh_command,
+};
+use crate::types::{Boot, DetailLogEntry, JournalEntry, JournalSource, LogPreview, Scope, Target};
+#[cfg(not(test))]
+use std::ffi::OsString;
 
 const BATCH_MIN_LINES: usize = 200;
 const BATCH_PER_UNIT_LINES: usize = 20;
@@ -45,19 +52,49 @@ const BATCH_MAX_LINES: usize = 4000;
 #[cfg(not(test))]
 const BATCH_MAX_ATTEMPTS: usize = 3;
 
+/// Build a `journalctl` command bound to one target and journal source.
+///
+/// Remote targets run `journalctl` through `ssh`, since `journalctl` has no `--host` transport.
+#[cfg(not(test))]
+fn journalctl_command<I, S>(target: &Target, source: &JournalSource, args: I) -> Result<Command>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<OsString>,
+{
+    let mut all_args: Vec<OsString> = vec![target.scope.as_systemd_arg().into()];
+    all_args.extend(target.machine_args().into_iter().map(OsString::from));
+    all_args.extend(source.as_journalctl_args());
+    all_args.extend(args.into_iter().map(Into::into));
+    match &target.host {
+        Some(host) => ssh_command(host, "journalctl", &all_args),
+        None => {
+            let mut cmd = Command::new(resolve_trusted_binary("journalctl")?);
+            cmd.args(all_args);
+            Ok(cmd)
+        }
+    }
+}
+
+/// Return the `journalctl` arguments that limit output to `boot`, if one is chosen.
+pub fn boot_args(boot: Option<&str>) -> Vec<&str> {
+    boot.map_or_else(Vec::new, |id| vec!["-b", id])
+}
+
 /// Fetch the latest log message text for one systemd unit.
 #[cfg(not(test))]
-pub fn last_log_line(scope: Scope, unit: &str) -> Result<String> {
-    let journalctl = resolve_trusted_binary("journalctl")?;
-    let mut cmd = Command::new(journalctl);
-    cmd.arg(scope.as_systemd_arg())
-        .arg("-u")
-        .arg(unit)
-        .arg("-n")
-        .arg("1")
-        .arg("--no-pager")
-        .arg("-o")
-        .arg("cat");
+pub fn last_log_line(
+    target: &Target,
+    source: &JournalSource,
+    boot: Option<&str>,
+    unit: &str,
+) -> Result<String> {
+    let mut cmd = journalctl_command(
+        target,
+        source,
+        ["-u", unit, "-n", "1", "--no-pager", "-o", "cat"]
+            .into_iter()
+            .chain(boot_args(boot)),
+    )?;
     let mut line = match cmd_stdout(&mut cmd) {
         Ok(line) => line,
         Err(CommandExecError::Timeout { .. }) => {
@@ -75,16 +112,133 @@ pub fn last_log_line(scope: Scope, unit: &str) -> Result<String> {
 
 #[cfg(test)]
 /// Test-build stub for one-line log lookup.
-pub fn last_log_line(_scope: Scope, _unit: &str) -> Result<String> {
+pub fn last_log_line(
+    _target: &Target,
+    _source: &JournalSource,
+    _boot: Option<&str>,
+    _unit: &str,
+) -> Result<String> {
     Ok(String::new())
 }
 
+/// Parse `journalctl --list-boots` output, skipping the header and malformed lines.
+pub fn parse_boot_list(output: &str) -> Vec<Boot> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim_start();
+            let (index, rest) = line.split_once(char::is_whitespace)?;
+            let index = index.parse().ok()?;
+            let rest = rest.trim_start();
+            let (id, span) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+            if id.len() != 32 || !id.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return None;
+            }
+            Some(Boot {
+                index,
+                id: id.to_string(),
+                span: span.trim().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// List the boots recorded in the journal.
+#[cfg(not(test))]
+pub fn fetch_boots(target: &Target, source: &JournalSource) -> Result<Vec<Boot>> {
+    let mut cmd = journalctl_command(target, source, ["--list-boots", "--no-pager"])?;
+    let output = match cmd_stdout(&mut cmd) {
+        Ok(output) => output,
+        Err(CommandExecError::Timeout { .. }) => {
+            bail!(
+                "journalctl boot listing timed out after {}s",
+                command_timeout().as_secs()
+            )
+        }
+        Err(e) => return Err(e).context("journalctl boot listing failed"),
+    };
+    Ok(parse_boot_list(&output))
+}
+
+#[cfg(test)]
+/// Test-build stub for boot listing.
+pub fn fetch_boots(target: &Target, _source: &JournalSource) -> Result<Vec<Boot>> {
+    if matches!(target.scope, Scope::User) {
+        return Err(anyhow::anyhow!("boot list test error"));
+    }
+    Ok(vec![
+        Boot {
+            index: -1,
+            id: "0123456789abcdef0123456789abcdef".to_string(),
+            span: "Mon 2026-02-23 08:00:00 UTC Mon 2026-02-23 20:00:00 UTC".to_string(),
+        },
+        Boot {
+            index: 0,
+            id: "fedcba9876543210fedcba9876543210".to_string(),
+            span: "Tue 2026-02-24 08:00:00 UTC Tue 2026-02-24 12:00:00 UTC".to_string(),
+        },
+    ])
+}
+
+/// Return the journal field naming the owning service unit for a scope.
+pub fn discovery_field(scope: Scope) -> &'static str {
+    match scope {
+        Scope::System => "_SYSTEMD_UNIT",
+        Scope::User => "_SYSTEMD_USER_UNIT",
+    }
+}
+
+/// Parse `journalctl -F <field>` output into a sorted, de-duplicated list of service units.
+pub fn parse_journal_service_units(output: &str) -> Vec<String> {
+    let mut units: Vec<String> = output
+        .lines()
+        .map(str::trim)
+        .filter(|unit| unit.ends_with(".service"))
+        .map(str::to_string)
+        .collect();
+    units.sort();
+    units.dedup();
+    units
+}
+
+/// Discover service units from the distinct unit values recorded in the journal.
+///
+/// Used when no live `systemctl` state describes the journal, e.g. for `--journal-dir`.
+#[cfg(not(test))]
+pub fn fetch_journal_units(target: &Target, source: &JournalSource) -> Result<Vec<String>> {
+    let mut cmd = journalctl_command(
+        target,
+        source,
+        ["--no-pager", "-F", discovery_field(target.scope)],
+    )?;
+    let output = match cmd_stdout(&mut cmd) {
+        Ok(output) => output,
+        Err(CommandExecError::Timeout { .. }) => {
+            bail!(
+                "journalctl unit discovery timed out after {}s",
+                command_timeout().as_secs()
+            )
+        }
+        Err(e) => return Err(e).context("journalctl unit discovery failed"),
+    };
+    Ok(parse_journal_service_units(&output))
+}
+
+#[cfg(test)]
+/// Test-build stub for journal unit discovery.
+pub fn fetch_journal_units(target: &Target, _source: &JournalSource) -> Result<Vec<String>> {
+    if matches!(target.scope, Scope::User) {
+        return Err(anyhow::anyhow!("journal discovery test error"));
+    }
+    Ok(vec!["a.service".to_string(), "offline.service".to_string()])
+}
+
 /// Parse newline-delimited `journalctl -o json` output and pick the latest non-empty message per unit.
 pub fn parse_latest_logs_from_journal_json(
     scope: Scope,
     output: &str,
     wanted: &HashSet<String>,
-) -> HashMap<String, String> {
+) -> HashMap<String, LogPreview> {
     parse_latest_logs_lines(scope, output.lines(), wanted, usize::MAX)
 }
 
@@ -92,7 +246,7 @@ fn absorb_latest_log_line(
     scope: Scope,
     line: &str,
     wanted: &HashSet<String>,
-    latest: &mut HashMap<String, String>,
+    latest: &mut HashMap<String, LogPreview>,
 ) {
     let Ok(value) = serde_json::from_str::<serde_json::Value>(line) else {
         return;
@@ -116,7 +270,11 @@ fn absorb_latest_log_line(
         return;
     }
 
-    latest.insert(unit.to_string(), message);
+    let usec = value
+        .get("__REALTIME_TIMESTAMP")
+        .and_then(|v| v.as_str())
+        .and_then(|v| v.parse().ok());
+    latest.insert(unit.to_string(), LogPreview { message, usec });
 }
 
 /// Parse line-delimited journal JSON with an explicit max-line budget.
@@ -125,7 +283,7 @@ pub fn parse_latest_logs_lines<'a, I>(
     lines: I,
     wanted: &HashSet<String>,
     max_lines: usize,
-) -> HashMap<String, String>
+) -> HashMap<String, LogPreview>
 where
     I: IntoIterator<Item = &'a str>,
 {
@@ -140,7 +298,8 @@ where
     latest
 }
 
-fn unit_from_journal_entry_for_scope(scope: Scope, value: &serde_json::Value) -> Option<&str> {
+/// Return the journal fields that may name the unit of an entry, most specific first.
+fn unit_fields(scope: Scope) -> &'static [&'static str] {
     const SYSTEM_UNIT_FIELDS: [&str; 7] = [
         "_SYSTEMD_UNIT",
         "UNIT",
@@ -160,24 +319,43 @@ fn unit_from_journal_entry_for_scope(scope: Scope, value: &serde_json::Value) ->
         "COREDUMP_UNIT",
         "_SYSTEMD_UNIT",
     ];
-    let fields: &[&str] = match scope {
+    match scope {
         Scope::System => &SYSTEM_UNIT_FIELDS,
         Scope::User => &USER_UNIT_FIELDS,
-    };
-    for field in fields {
-        if let Some(unit) = value.get(field).and_then(|v| v.as_str()) {
-            return Some(unit);
-        }
     }
-    None
+}
+
+fn unit_from_journal_entry_for_scope(scope: Scope, value: &serde_json::Value) -> Option<&str> {
+    unit_fields(scope)
+        .iter()
+        .find_map(|field| value.get(field).and_then(|v| v.as_str()))
+}
+
+/// Return the unit of a timeline entry, preferring a field that names one of `wanted`.
+///
+/// Messages the service manager logs about a unit carry its own unit as well, so
+/// the first unit field alone may name a unit outside the timeline.
+fn timeline_unit_of_entry<'a>(
+    scope: Scope,
+    fields: &'a [(String, String)],
+    wanted: &[String],
+) -> Option<&'a str> {
+    let mut named = unit_fields(scope)
+        .iter()
+        .filter_map(|field| entry_field(fields, field));
+    named
+        .clone()
+        .find(|unit| wanted.iter().any(|w| w == unit))
+        .or_else(|| named.next())
 }
 
 /// Build a journal query by repeating `-u <unit>` to preserve journalctl's native
 /// unit matching semantics, including manager-generated entries tied to a unit.
 #[cfg(not(test))]
-fn append_unit_matches(cmd: &mut Command, unit_names: &[String]) {
+fn append_unit_matches(args: &mut Vec<String>, unit_names: &[String]) {
     for unit in unit_names {
-        cmd.arg("-u").arg(unit);
+        args.push("-u".to_string());
+        args.push(unit.clone());
     }
 }
 
@@ -227,21 +405,22 @@ fn wait_child_with_timeout(mut child: std::process::Child, deadline: Instant) ->
 
 #[cfg(not(test))]
 fn stream_batch_latest_logs(
-    scope: Scope,
+    target: &Target,
+    source: &JournalSource,
+    boot: Option<&str>,
     unit_names: &[String],
     line_budget: usize,
-) -> Result<HashMap<String, String>> {
+) -> Result<HashMap<String, LogPreview>> {
+    let scope = target.scope;
     let wanted: HashSet<String> = unit_names.iter().cloned().collect();
-    let journalctl = resolve_trusted_binary("journalctl")?;
-    let mut cmd = Command::new(journalctl);
-    cmd.arg(scope.as_systemd_arg())
-        .arg("--no-pager")
-        .arg("-o")
-        .arg("json")
-        .arg("-r")
-        .arg("-n")
-        .arg(line_budget.to_string());
-    append_unit_matches(&mut cmd, unit_names);
+    let mut args: Vec<String> = ["--no-pager", "-o", "json", "-r", "-n"]
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+    args.push(line_budget.to_string());
+    args.extend(boot_args(boot).into_iter().map(str::to_string));
+    append_unit_matches(&mut args, unit_names);
+    let mut cmd = journalctl_command(target, source, args)?;
 
     let mut child = cmd.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn()?;
     let stdout = child.stdout.take().context("missing stdout pipe")?;
@@ -324,9 +503,11 @@ fn stream_batch_latest_logs(
 /// Fetch latest logs for a batch of units, with per-unit fallback for missing/empty results.
 #[cfg(not(test))]
 pub fn latest_log_lines_batch(
-    scope: Scope,
+    target: &Target,
+    source: &JournalSource,
+    boot: Option<&str>,
     unit_names: &[String],
-) -> Result<HashMap<String, String>> {
+) -> Result<HashMap<String, LogPreview>> {
     if unit_names.is_empty() {
         return Ok(HashMap::new());
     }
@@ -339,23 +520,26 @@ pub fn latest_log_lines_batch(
             break;
         }
         let budget = batch_line_budget(unresolved.len(), attempt);
-        let partial = match stream_batch_latest_logs(scope, &unresolved, budget) {
+        let partial = match stream_batch_latest_logs(target, source, boot, &unresolved, budget) {
             Ok(partial) => partial,
             Err(_) => break,
         };
-        for (unit, message) in partial {
-            if !message.trim().is_empty() {
-                out.insert(unit, message);
+        for (unit, preview) in partial {
+            if !preview.message.trim().is_empty() {
+                out.insert(unit, preview);
             }
         }
         unresolved.retain(|unit| !out.contains_key(unit));
     }
 
     for unit in unresolved {
-        if out.get(&unit).is_none_or(|v| v.trim().is_empty()) {
+        if out.get(&unit).is_none_or(|v| v.message.trim().is_empty()) {
             out.insert(
                 unit.clone(),
-                last_log_line(scope, &unit).unwrap_or_default(),
+                LogPreview {
+                    message: last_log_line(target, source, boot, &unit).unwrap_or_default(),
+                    usec: None,
+                },
             );
         }
     }
@@ -366,64 +550,196 @@ pub fn latest_log_lines_batch(
 #[cfg(test)]
 /// Test-build stub for batched log lookup.
 pub fn latest_log_lines_batch(
-    _scope: Scope,
+    _target: &Target,
+    _source: &JournalSource,
+    _boot: Option<&str>,
     _unit_names: &[String],
-) -> Result<HashMap<String, String>> {
+) -> Result<HashMap<String, LogPreview>> {
     if _unit_names.iter().any(|u| u == "journal-error.service") {
         return Err(anyhow::anyhow!("journal test error"));
     }
     Ok(_unit_names
         .iter()
-        .map(|u| (u.clone(), format!("log: {u}")))
+        .map(|u| {
+            (
+                u.clone(),
+                LogPreview {
+                    message: format!("log: {u}"),
+                    usec: None,
+                },
+            )
+        })
         .collect())
 }
 
-/// Parse `journalctl -o short-iso` output into `{time, log}` rows.
-pub fn parse_journal_short_iso(output: &str) -> Vec<DetailLogEntry> {
-    output
-        .lines()
-        .filter_map(|line| {
-            let trimmed = line.trim();
-            if trimmed.is_empty() {
-                return None;
-            }
-            if let Some((time, log)) = trimmed.split_once(' ') {
-                Some(DetailLogEntry {
-                    time: time.to_string(),
-                    log: log.trim_start().to_string(),
-                })
-            } else {
-                Some(DetailLogEntry {
-                    time: String::new(),
-                    log: trimmed.to_string(),
-                })
-            }
-        })
-        .collect()
+/// Journal fields needed to render detail lines, tell unit runs and boots apart
+/// and report how runs ended. `__CURSOR` and `__REALTIME_TIMESTAMP` always come along.
+#[cfg(not(test))]
+const DETAIL_OUTPUT_FIELDS: &str = "--output-fields=MESSAGE,_HOSTNAME,SYSLOG_IDENTIFIER,_COMM,SYSLOG_PID,_PID,_BOOT_ID,_SYSTEMD_INVOCATION_ID,INVOCATION_ID,USER_INVOCATION_ID,MESSAGE_ID,EXIT_CODE,EXIT_STATUS,UNIT_RESULT";
+
+/// JSON output arguments that keep long fields.
+///
+/// Without `--all`, `journalctl -o json` writes fields of 4096 bytes or more as `null`.
+#[cfg(not(test))]
+const FULL_JSON_OUTPUT: [&str; 3] = ["-o", "json", "--all"];
+
+/// `MESSAGE_ID` systemd logs when a unit deactivates successfully.
+const UNIT_SUCCESS_MESSAGE_ID: &str = "7ad2d189f7e94e70a38c781354912448";
+
+/// Return the invocation ID of the unit run an entry belongs to.
+///
+/// Service output carries `_SYSTEMD_INVOCATION_ID`; messages the service manager
+/// logs about the unit carry `INVOCATION_ID` or, for user units, `USER_INVOCATION_ID`.
+fn entry_invocation(fields: &[(String, String)]) -> Option<&str> {
+    [
+        "_SYSTEMD_INVOCATION_ID",
+        "INVOCATION_ID",
+        "USER_INVOCATION_ID",
+    ]
+    .into_iter()
+    .find_map(|name| entry_field(fields, name))
+    .filter(|id| !id.is_empty())
+}
+
+/// Describe the exit result an entry records, such as `code=exited, status=1`.
+fn entry_exit(fields: &[(String, String)]) -> Option<String> {
+    let mut parts = Vec::new();
+    if let Some(code) = entry_field(fields, "EXIT_CODE") {
+        parts.push(format!("code={code}"));
+    }
+    if let Some(status) = entry_field(fields, "EXIT_STATUS") {
+        parts.push(format!("status={status}"));
+    }
+    if let Some(result) = entry_field(fields, "UNIT_RESULT") {
+        parts.push(format!("result={result}"));
+    } else if entry_field(fields, "MESSAGE_ID") == Some(UNIT_SUCCESS_MESSAGE_ID) {
+        parts.push("result=success".to_string());
+    }
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+/// Return the `short-iso` time of an entry, or an empty string without a timestamp.
+fn entry_time(fields: &[(String, String)]) -> String {
+    entry_field(fields, "__REALTIME_TIMESTAMP")
+        .and_then(|usec| usec.parse().ok())
+        .map(format_local_short_iso)
+        .unwrap_or_default()
+}
+
+/// Render the `host identifier[pid]:` prefix `short-iso` output puts before a message.
+fn entry_prefix(fields: &[(String, String)]) -> String {
+    let mut prefix = String::new();
+    if let Some(host) = entry_field(fields, "_HOSTNAME") {
+        prefix.push_str(host);
+        prefix.push(' ');
+    }
+    prefix.push_str(
+        entry_field(fields, "SYSLOG_IDENTIFIER")
+            .or_else(|| entry_field(fields, "_COMM"))
+            .unwrap_or("unknown"),
+    );
+    if let Some(pid) = entry_field(fields, "SYSLOG_PID").or_else(|| entry_field(fields, "_PID")) {
+        prefix.push('[');
+        prefix.push_str(pid);
+        prefix.push(']');
+    }
+    prefix.push(':');
+    prefix
+}
+
+/// Push one detail line per line of `message`, the first one carrying `prefix`.
+///
+/// Continuation lines keep the boot, run and cursor of `entry` but, as in
+/// `short-iso` output, show no time; the exit result is only counted once.
+fn push_message_lines(
+    logs: &mut Vec<DetailLogEntry>,
+    entry: DetailLogEntry,
+    prefix: &str,
+    message: &str,
+) {
+    for (idx, text) in message.trim_end().split('\n').enumerate() {
+        if idx == 0 {
+            let log = match (prefix, text) {
+                ("", _) => text.to_string(),
+                (_, "") => prefix.to_string(),
+                _ => format!("{prefix} {text}"),
+            };
+            logs.push(DetailLogEntry {
+                log,
+                ..entry.clone()
+            });
+        } else {
+            logs.push(DetailLogEntry {
+                time: String::new(),
+                log: text.to_string(),
+                exit: None,
+                ..entry.clone()
+            });
+        }
+    }
+}
+
+/// Parse newest-first `journalctl -o json` output of one unit into detail lines.
+///
+/// Lines read like `short-iso` output and keep the boot, invocation and cursor
+/// of the entry they come from.
+pub fn parse_detail_json(output: &str) -> Vec<DetailLogEntry> {
+    let mut logs = Vec::new();
+    for fields in parse_journal_entry_fields(output) {
+        let entry = DetailLogEntry {
+            time: entry_time(&fields),
+            boot: entry_field(&fields, "_BOOT_ID").map(str::to_string),
+            invocation: entry_invocation(&fields).map(str::to_string),
+            exit: entry_exit(&fields),
+            cursor: entry_field(&fields, "__CURSOR").map(str::to_string),
+            ..DetailLogEntry::default()
+        };
+        push_message_lines(
+            &mut logs,
+            entry,
+            &entry_prefix(&fields),
+            entry_field(&fields, "MESSAGE").unwrap_or(""),
+        );
+    }
+    logs
 }
 
 /// Fetch timestamped detail logs for a single unit.
 #[cfg(not(test))]
-pub fn fetch_unit_logs(scope: Scope, unit: &str, max_lines: usize) -> Result<Vec<DetailLogEntry>> {
-    let journalctl = resolve_trusted_binary("journalctl")?;
-    let mut cmd = Command::new(journalctl);
-    cmd.arg(scope.as_systemd_arg())
-        .arg("-u")
-        .arg(unit)
-        .arg("-n")
-        .arg(max_lines.to_string())
-        .arg("--no-pager")
-        .arg("-o")
-        .arg("short-iso")
-        .arg("-r");
+pub fn fetch_unit_logs(
+    target: &Target,
+    source: &JournalSource,
+    boot: Option<&str>,
+    unit: &str,
+    max_lines: usize,
+) -> Result<Vec<DetailLogEntry>> {
+    let max_lines = max_lines.to_string();
+    let mut cmd = journalctl_command(
+        target,
+        source,
+        [
+            "-u",
+            unit,
+            "-n",
+            &max_lines,
+            "--no-pager",
+            DETAIL_OUTPUT_FIELDS,
+            "-r",
+        ]
+        .into_iter()
+        .chain(FULL_JSON_OUTPUT)
+        .chain(boot_args(boot)),
+    )?;
     let output = cmd_stdout(&mut cmd)?;
-    Ok(parse_journal_short_iso(&output))
+    Ok(parse_detail_json(&output))
 }
 
 #[cfg(test)]
 /// Test-build stub for detail log fetching.
 pub fn fetch_unit_logs(
-    _scope: Scope,
+    _target: &Target,
+    _source: &JournalSource,
+    _boot: Option<&str>,
     _unit: &str,
     _max_lines: usize,
 ) -> Result<Vec<DetailLogEntry>> {
@@ -433,22 +749,300 @@ pub fn fetch_unit_logs(
     Ok(vec![DetailLogEntry {
         time: "t".to_string(),
         log: format!("detail: {_unit}"),
+        ..DetailLogEntry::default()
     }])
 }
 
+/// Journal fields read for a merged timeline of several units.
+#[cfg(not(test))]
+const TIMELINE_OUTPUT_FIELDS: &str = "--output-fields=MESSAGE,_BOOT_ID,_SYSTEMD_UNIT,UNIT,USER_UNIT,OBJECT_SYSTEMD_UNIT,OBJECT_SYSTEMD_USER_UNIT,COREDUMP_UNIT,COREDUMP_USER_UNIT,_SYSTEMD_USER_UNIT";
+
+/// Fetch the newest `max_lines` entries of all `units` as one newest-first timeline.
+///
+/// A single `journalctl -u a -u b ...` query interleaves the units the way the
+/// journal stored them; JSON output tells which unit logged each entry.
+#[cfg(not(test))]
+pub fn fetch_timeline_logs(
+    target: &Target,
+    source: &JournalSource,
+    boot: Option<&str>,
+    units: &[String],
+    max_lines: usize,
+) -> Result<Vec<DetailLogEntry>> {
+    let mut args: Vec<String> = [
+        "-n",
+        &max_lines.to_string(),
+        "--no-pager",
+        TIMELINE_OUTPUT_FIELDS,
+        "-r",
+    ]
+    .into_iter()
+    .chain(FULL_JSON_OUTPUT)
+    .chain(boot_args(boot))
+    .map(str::to_string)
+    .collect();
+    append_unit_matches(&mut args, units);
+    let mut cmd = journalctl_command(target, source, args)?;
+    let output = match cmd_stdout(&mut cmd) {
+        Ok(output) => output,
+        Err(CommandExecError::Timeout { .. }) => {
+            bail!(
+                "journalctl timeline timed out after {}s",
+                command_timeout().as_secs()
+            )
+        }
+        Err(e) => return Err(e).context("journalctl timeline failed"),
+    };
+    Ok(parse_timeline_json(target.scope, &output, units))
+}
+
+#[cfg(test)]
+/// Test-build stub for merged timeline fetching.
+pub fn fetch_timeline_logs(
+    _target: &Target,
+    _source: &JournalSource,
+    _boot: Option<&str>,
+    units: &[String],
+    _max_lines: usize,
+) -> Result<Vec<DetailLogEntry>> {
+    if units.iter().any(|unit| unit == "error.service") {
+        return Err(anyhow::anyhow!("timeline journal test error"));
+    }
+    Ok(units
+        .iter()
+        .map(|unit| DetailLogEntry {
+            time: "t".to_string(),
+            log: format!("timeline: {unit}"),
+            unit: Some(unit.clone()),
+            ..DetailLogEntry::default()
+        })
+        .collect())
+}
+
+/// Parse newest-first `journalctl -o json` output of several units into timeline entries.
+///
+/// Times are shown in local time, as in the detail view. Multi-line messages
+/// continue on untimed entries of the same unit, as in `short-iso` output.
+pub fn parse_timeline_json(scope: Scope, output: &str, units: &[String]) -> Vec<DetailLogEntry> {
+    let mut logs = Vec::new();
+    for fields in parse_journal_entry_fields(output) {
+        let entry = DetailLogEntry {
+            time: entry_time(&fields),
+            boot: entry_field(&fields, "_BOOT_ID").map(str::to_string),
+            unit: timeline_unit_of_entry(scope, &fields, units).map(str::to_string),
+            cursor: entry_field(&fields, "__CURSOR").map(str::to_string),
+            ..DetailLogEntry::default()
+        };
+        push_message_lines(
+            &mut logs,
+            entry,
+            "",
+            entry_field(&fields, "MESSAGE").unwrap_or(""),
+        );
+    }
+    logs
+}
+
+/// Format a `__REALTIME_TIMESTAMP` in microseconds as a `short-iso` time in `zone`.
+fn format_short_iso_in<Tz: TimeZone>(usec: u64, zone: &Tz) -> String
+where
+    Tz::Offset: std::fmt::Display,
+{
+    i64::try_from(usec)
+        .ok()
+        .and_then(DateTime::from_timestamp_micros)
+        .map(|time| {
+            time.with_timezone(zone)
+                .format("%Y-%m-%dT%H:%M:%S%z")
+                .to_string()
+        })
+        .unwrap_or_default()
+}
+
+/// Format a `__REALTIME_TIMESTAMP` in microseconds as a local `short-iso` time.
+#[cfg(not(test))]
+pub fn format_local_short_iso(usec: u64) -> String {
+    format_short_iso_in(usec, &Local)
+}
+
+#[cfg(test)]
+/// Test-build stub: tests format times in UTC.
+pub fn format_local_short_iso(usec: u64) -> String {
+    format_short_iso(usec, 0)
+}
+
+/// Format a `__REALTIME_TIMESTAMP` in microseconds as a `short-iso` time at `offset` seconds east of UTC.
+pub fn format_short_iso(usec: u64, offset: i32) -> String {
+    FixedOffset::east_opt(offset)
+        .map(|zone| format_short_iso_in(usec, &zone))
+        .unwrap_or_default()
+}
+
+/// Parse `journalctl -o json` output into the fields of each entry, sorted by name.
+///
+/// Fields that occur several times in an entry are listed once per value; binary
+/// values are decoded lossily as UTF-8.
+pub fn parse_journal_entry_fields(output: &str) -> Vec<Vec<(String, String)>> {
+    output
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .filter_map(|value| match value {
+            serde_json::Value::Object(map) => Some(map),
+            _ => None,
+        })
+        .map(|map| {
+            let mut fields = Vec::new();
+            for (name, value) in map {
+                match value {
+                    serde_json::Value::String(text) => fields.push((name, text)),
+                    serde_json::Value::Array(items)
+                        if items.iter().all(serde_json::Value::is_number) =>
+                    {
+                        let bytes: Vec<u8> = items
+                            .iter()
+                            .filter_map(|item| item.as_u64().map(|byte| byte as u8))
+                            .collect();
+                        fields.push((name, String::from_utf8_lossy(&bytes).into_owned()));
+                    }
+                    serde_json::Value::Array(items) => {
+                        for item in items {
+                            if let Some(text) = item.as_str() {
+                                fields.push((name.clone(), text.to_string()));
+                            }
+                        }
+                    }
+                    serde_json::Value::Null => {}
+                    other => fields.push((name, other.to_string())),
+                }
+            }
+            fields.sort();
+            fields
+        })
+        .collect()
+}
+
+/// Return the value of `name` among entry `fields`.
+pub fn entry_field<'a>(fields: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    fields
+        .iter()
+        .find(|(field, _)| field == name)
+        .map(|(_, value)| value.as_str())
+}
+
+/// Fill the `@FIELD@` placeholders of a `--dump-catalog` entry from entry `fields`, as `journalctl -x` does.
+///
+/// Returns `None` when the dump holds no catalog text.
+pub fn catalog_text(dump: &str, fields: &[(String, String)]) -> Option<String> {
+    // The dump starts with a `-- <message id>` header line.
+    let body = dump
+        .lines()
+        .skip_while(|line| line.trim().is_empty())
+        .skip_while(|line| line.starts_with("-- "))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let mut text = String::new();
+    let mut rest = body.as_str();
+    while let Some(start) = rest.find('@') {
+        text.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        match after.find('@') {
+            Some(end)
+                if end > 0
+                    && after[..end]
+                        .chars()
+                        .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_') =>
+            {
+                match entry_field(fields, &after[..end]) {
+                    Some(value) => text.push_str(value),
+                    None => text.push_str(&rest[start..start + end + 2]),
+                }
+                rest = &after[end + 1..];
+            }
+            _ => {
+                text.push('@');
+                rest = after;
+            }
+        }
+    }
+    text.push_str(rest);
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// Fetch every field of the journal entry at `cursor`, plus its catalog text.
+#[cfg(not(test))]
+pub fn fetch_log_entry(
+    target: &Target,
+    source: &JournalSource,
+    cursor: &str,
+) -> Result<JournalEntry> {
+    let cursor_arg = format!("--cursor={cursor}");
+    let mut cmd = journalctl_command(
+        target,
+        source,
+        [cursor_arg.as_str(), "-n", "1", "--no-pager"]
+            .into_iter()
+            .chain(FULL_JSON_OUTPUT),
+    )?;
+    let output = cmd_stdout(&mut cmd).context("journalctl entry lookup failed")?;
+    let fields = parse_journal_entry_fields(&output)
+        .into_iter()
+        .next()
+        .context("journal entry not found")?;
+
+    // The explanation is optional; entries without a catalog entry show none.
+    let catalog = entry_field(&fields, "MESSAGE_ID").and_then(|id| {
+        let mut cmd =
+            journalctl_command(target, source, ["--no-pager", "--dump-catalog", id]).ok()?;
+        cmd_stdout(&mut cmd)
+            .ok()
+            .and_then(|dump| catalog_text(&dump, &fields))
+    });
+    Ok(JournalEntry { fields, catalog })
+}
+
+#[cfg(test)]
+/// Test-build stub for journal entry lookup.
+pub fn fetch_log_entry(
+    _target: &Target,
+    _source: &JournalSource,
+    cursor: &str,
+) -> Result<JournalEntry> {
+    if cursor == "error" {
+        return Err(anyhow::anyhow!("entry journal test error"));
+    }
+    Ok(JournalEntry {
+        fields: vec![
+            ("MESSAGE".to_string(), "test entry".to_string()),
+            ("__CURSOR".to_string(), cursor.to_string()),
+        ],
+        catalog: None,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn parses_latest_logs_per_unit_from_json_lines() {
-        let output = r#"{"_SYSTEMD_UNIT":"a.service","MESSAGE":"newest a"}
+        let output = r#"{"_SYSTEMD_UNIT":"a.service","MESSAGE":"newest a","__REALTIME_TIMESTAMP":"1760000000000000"}
 {"_SYSTEMD_UNIT":"b.service","MESSAGE":"newest b"}
 {"_SYSTEMD_UNIT":"a.service","MESSAGE":"older a"}"#;
         let wanted = HashSet::from(["a.service".to_string(), "b.service".to_string()]);
         let logs = parse_latest_logs_from_journal_json(Scope::System, output, &wanted);
-        assert_eq!(logs.get("a.service").map(String::as_str), Some("newest a"));
-        assert_eq!(logs.get("b.service").map(String::as_str), Some("newest b"));
+        assert_eq!(
+            logs["a.service"],
+            LogPreview {
+                message: "newest a".to_string(),
+                usec: Some(1_760_000_000_000_000),
+            }
+        );
+        assert_eq!(
+            logs.get("b.service").map(|log| log.message.as_str()),
+            Some("newest b")
+        );
+        assert_eq!(logs["b.service"].usec, None);
     }
 
     #[test]
@@ -459,36 +1053,71 @@ mod tests {
 {"_SYSTEMD_UNIT":"a.service","MESSAGE":"ok"}"#;
         let wanted = HashSet::from(["a.service".to_string()]);
         let logs = parse_latest_logs_from_journal_json(Scope::System, output, &wanted);
-        assert_eq!(logs.get("a.service").map(String::as_str), Some("ok"));
+        assert_eq!(
+            logs.get("a.service").map(|log| log.message.as_str()),
+            Some("ok")
+        );
     }
 
     #[test]
     fn latest_log_lines_batch_empty_input_returns_empty_map() {
-        let logs = latest_log_lines_batch(Scope::System, &[]).expect("stub should succeed");
+        let logs = latest_log_lines_batch(
+            &Target::new(Scope::System),
+            &JournalSource::Local,
+            None,
+            &[],
+        )
+        .expect("stub should succeed");
         assert!(logs.is_empty());
     }
 
     #[test]
     fn last_log_line_test_stub_returns_empty_string() {
-        let line = last_log_line(Scope::System, "unit").expect("stub should succeed");
+        let line = last_log_line(
+            &Target::new(Scope::System),
+            &JournalSource::Local,
+            None,
+            "unit",
+        )
+        .expect("stub should succeed");
         assert_eq!(line, "");
     }
 
     #[test]
     fn fetch_unit_logs_test_stub_returns_empty_vec() {
-        let rows = fetch_unit_logs(Scope::System, "unit", 10).expect("stub should succeed");
+        let rows = fetch_unit_logs(
+            &Target::new(Scope::System),
+            &JournalSource::Local,
+            None,
+            "unit",
+            10,
+        )
+        .expect("stub should succeed");
         assert_eq!(rows.len(), 1);
     }
 
     #[test]
-    fn parse_journal_short_iso_extracts_time_and_message() {
-        let out = "2026-02-24T10:00:00+0000 one log line\nraw-without-timestamp";
-        let rows = parse_journal_short_iso(out);
-        assert_eq!(rows.len(), 2);
-        assert_eq!(rows[0].time, "2026-02-24T10:00:00+0000");
-        assert_eq!(rows[0].log, "one log line");
-        assert_eq!(rows[1].time, "");
-        assert_eq!(rows[1].log, "raw-without-timestamp");
+    fn parse_detail_json_renders_short_iso_lines_with_cursors() {
+        let out = r#"{"__CURSOR":"c2","__REALTIME_TIMESTAMP":"1771927200000000","_HOSTNAME":"host","SYSLOG_IDENTIFIER":"sshd","_PID":"42","MESSAGE":"one log line"}
+{"__CURSOR":"c1","_COMM":"app","SYSLOG_PID":"7","_PID":"8","MESSAGE":""}
+{"__CURSOR":"c0","MESSAGE":"bare"}"#;
+        let rows = parse_detail_json(out);
+        let summary: Vec<(&str, &str, Option<&str>)> = rows
+            .iter()
+            .map(|row| (row.time.as_str(), row.log.as_str(), row.cursor.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "2026-02-24T10:00:00+0000",
+                    "host sshd[42]: one log line",
+                    Some("c2")
+                ),
+                ("", "app[7]:", Some("c1")),
+                ("", "unknown: bare", Some("c0")),
+            ]
+        );
     }
 
     #[test]
@@ -542,7 +1171,10 @@ mod tests {
 {"_SYSTEMD_UNIT":"x.service","MESSAGE":"system msg"}"#;
         let wanted = HashSet::from(["x.service".to_string()]);
         let logs = parse_latest_logs_from_journal_json(Scope::User, output, &wanted);
-        assert_eq!(logs.get("x.service").map(String::as_str), Some("x msg"));
+        assert_eq!(
+            logs.get("x.service").map(|log| log.message.as_str()),
+            Some("x msg")
+        );
     }
 
     #[test]
@@ -552,11 +1184,11 @@ mod tests {
         let wanted = HashSet::from(["x.service".to_string(), "y.service".to_string()]);
         let logs = parse_latest_logs_from_journal_json(Scope::System, output, &wanted);
         assert_eq!(
-            logs.get("x.service").map(String::as_str),
+            logs.get("x.service").map(|log| log.message.as_str()),
             Some("unit field")
         );
         assert_eq!(
-            logs.get("y.service").map(String::as_str),
+            logs.get("y.service").map(|log| log.message.as_str()),
             Some("object field")
         );
     }
@@ -568,11 +1200,11 @@ mod tests {
         let wanted = HashSet::from(["x.service".to_string(), "y.service".to_string()]);
         let logs = parse_latest_logs_from_journal_json(Scope::User, output, &wanted);
         assert_eq!(
-            logs.get("x.service").map(String::as_str),
+            logs.get("x.service").map(|log| log.message.as_str()),
             Some("unit field")
         );
         assert_eq!(
-            logs.get("y.service").map(String::as_str),
+            logs.get("y.service").map(|log| log.message.as_str()),
             Some("object user field")
         );
     }
@@ -589,29 +1221,29 @@ mod tests {
         ]);
         let system_logs = parse_latest_logs_from_journal_json(Scope::System, output, &wanted);
         assert_eq!(
-            system_logs.get("a.service").map(String::as_str),
+            system_logs.get("a.service").map(|log| log.message.as_str()),
             Some("system coredump")
         );
         assert_eq!(
-            system_logs.get("b.service").map(String::as_str),
+            system_logs.get("b.service").map(|log| log.message.as_str()),
             Some("user coredump")
         );
         assert_eq!(
-            system_logs.get("c.service").map(String::as_str),
+            system_logs.get("c.service").map(|log| log.message.as_str()),
             Some("user unit")
         );
 
         let user_logs = parse_latest_logs_from_journal_json(Scope::User, output, &wanted);
         assert_eq!(
-            user_logs.get("a.service").map(String::as_str),
+            user_logs.get("a.service").map(|log| log.message.as_str()),
             Some("system coredump")
         );
         assert_eq!(
-            user_logs.get("b.service").map(String::as_str),
+            user_logs.get("b.service").map(|log| log.message.as_str()),
             Some("user coredump")
         );
         assert_eq!(
-            user_logs.get("c.service").map(String::as_str),
+            user_logs.get("c.service").map(|log| log.message.as_str()),
             Some("user unit")
         );
     }
@@ -619,7 +1251,9 @@ mod tests {
     #[test]
     fn latest_log_lines_batch_stub_stays_ok_for_non_empty_input() {
         let logs = latest_log_lines_batch(
-            Scope::System,
+            &Target::new(Scope::System),
+            &JournalSource::Local,
+            None,
             &["a.service".to_string(), "b.service".to_string()],
         )
         .expect("stub should not fail");
@@ -631,20 +1265,272 @@ mod tests {
         // This documents the intended behavior: per-unit fallback errors must not abort the batch.
         // In test builds fallback stubs are always successful, so the function returns Ok.
         let unit_names = ["broken.service".to_string()];
-        assert!(latest_log_lines_batch(Scope::System, &unit_names).is_ok());
+        assert!(
+            latest_log_lines_batch(
+                &Target::new(Scope::System),
+                &JournalSource::Local,
+                None,
+                &unit_names
+            )
+            .is_ok()
+        );
     }
 
     #[test]
     fn latest_log_lines_batch_stub_can_return_error_for_sentinel_unit() {
-        let err = latest_log_lines_batch(Scope::System, &["journal-error.service".to_string()])
-            .expect_err("sentinel should fail");
+        let err = latest_log_lines_batch(
+            &Target::new(Scope::System),
+            &JournalSource::Local,
+            None,
+            &["journal-error.service".to_string()],
+        )
+        .expect_err("sentinel should fail");
         assert!(err.to_string().contains("journal test error"));
     }
 
+    #[test]
+    fn format_short_iso_applies_the_offset() {
+        assert_eq!(format_short_iso(0, 0), "1970-01-01T00:00:00+0000");
+        assert_eq!(
+            format_short_iso(1_771_927_203_500_000, 0),
+            "2026-02-24T10:00:03+0000"
+        );
+        assert_eq!(
+            format_short_iso(1_771_927_203_500_000, 3600),
+            "2026-02-24T11:00:03+0100"
+        );
+        assert_eq!(
+            format_short_iso(1_771_927_203_500_000, -16_200),
+            "2026-02-24T05:30:03-0430"
+        );
+        assert_eq!(
+            format_short_iso(951_782_400_000_000, 0),
+            "2000-02-29T00:00:00+0000"
+        );
+        assert_eq!(
+            format_short_iso(4_102_444_799_000_000, 0),
+            "2099-12-31T23:59:59+0000"
+        );
+    }
+
+    #[test]
+    fn parse_timeline_json_names_units_and_splits_multi_line_messages() {
+        let units = vec!["web.service".to_string(), "db.service".to_string()];
+        let output = r#"{"__REALTIME_TIMESTAMP":"1771927205000000","MESSAGE":"query failed\ndetail","_SYSTEMD_UNIT":"db.service","_BOOT_ID":"b1"}
+not json
+{"__REALTIME_TIMESTAMP":"1771927203000000","MESSAGE":"Started web.service.","_SYSTEMD_UNIT":"init.scope","UNIT":"web.service"}
+{"__REALTIME_TIMESTAMP":"1771927202000000","MESSAGE":[98,105,110,255],"_SYSTEMD_UNIT":"web.service"}"#;
+        let logs = parse_timeline_json(Scope::System, output, &units);
+        let summary: Vec<(&str, &str, Option<&str>, Option<&str>)> = logs
+            .iter()
+            .map(|log| {
+                (
+                    log.time.as_str(),
+                    log.log.as_str(),
+                    log.unit.as_deref(),
+                    log.boot.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "2026-02-24T10:00:05+0000",
+                    "query failed",
+                    Some("db.service"),
+                    Some("b1")
+                ),
+                ("", "detail", Some("db.service"), Some("b1")),
+                (
+                    "2026-02-24T10:00:03+0000",
+                    "Started web.service.",
+                    Some("web.service"),
+                    None
+                ),
+                (
+                    "2026-02-24T10:00:02+0000",
+                    "bin\u{fffd}",
+                    Some("web.service"),
+                    None
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn fetch_timeline_logs_stub_supports_success_and_error() {
+        let units = vec!["a.service".to_string(), "b.service".to_string()];
+        let logs = fetch_timeline_logs(
+            &Target::new(Scope::System),
+            &JournalSource::Local,
+            None,
+            &units,
+            10,
+        )
+        .expect("stub should succeed");
+        assert_eq!(logs.len(), 2);
+        assert_eq!(logs[1].unit.as_deref(), Some("b.service"));
+        let err = fetch_timeline_logs(
+            &Target::new(Scope::System),
+            &JournalSource::Local,
+            None,
+            &["error.service".to_string()],
+            10,
+        )
+        .expect_err("sentinel should fail");
+        assert!(err.to_string().contains("timeline journal test error"));
+    }
+
+    #[test]
+    fn parse_boot_list_skips_header_and_keeps_span() {
+        let output = "IDX BOOT ID                          FIRST ENTRY                 LAST ENTRY
+ -1 0123456789abcdef0123456789abcdef Mon 2026-02-23 08:00:00 UTC Mon 2026-02-23 20:00:00 UTC
+  0 fedcba9876543210fedcba9876543210 Tue 2026-02-24 08:00:00 UTC Tue 2026-02-24 12:00:00 UTC
+  1 not-a-boot-id
+";
+        assert_eq!(
+            parse_boot_list(output),
+            vec![
+                Boot {
+                    index: -1,
+                    id: "0123456789abcdef0123456789abcdef".to_string(),
+                    span: "Mon 2026-02-23 08:00:00 UTC Mon 2026-02-23 20:00:00 UTC".to_string(),
+                },
+                Boot {
+                    index: 0,
+                    id: "fedcba9876543210fedcba9876543210".to_string(),
+                    span: "Tue 2026-02-24 08:00:00 UTC Tue 2026-02-24 12:00:00 UTC".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn boot_args_select_one_boot_or_none() {
+        assert!(boot_args(None).is_empty());
+        assert_eq!(boot_args(Some("abc")), vec!["-b", "abc"]);
+    }
+
     #[test]
     fn fetch_unit_logs_stub_can_return_error_for_sentinel_unit() {
-        let err =
-            fetch_unit_logs(Scope::System, "error.service", 20).expect_err("sentinel should fail");
+        let err = fetch_unit_logs(
+            &Target::new(Scope::System),
+            &JournalSource::Local,
+            None,
+            "error.service",
+            20,
+        )
+        .expect_err("sentinel should fail");
         assert!(err.to_string().contains("detail journal test error"));
     }
+
+    #[test]
+    fn discovery_field_matches_scope() {
+        assert_eq!(discovery_field(Scope::System), "_SYSTEMD_UNIT");
+        assert_eq!(discovery_field(Scope::User), "_SYSTEMD_USER_UNIT");
+    }
+
+    #[test]
+    fn parse_journal_service_units_keeps_sorted_unique_services() {
+        let out = "sshd.service\ninit.scope\n\ncron.service\nsshd.service\nsession-3.scope\n";
+        assert_eq!(
+            parse_journal_service_units(out),
+            vec!["cron.service".to_string(), "sshd.service".to_string()]
+        );
+    }
+
+    #[test]
+    fn fetch_journal_units_stub_supports_success_and_error() {
+        let units = fetch_journal_units(&Target::new(Scope::System), &JournalSource::Local)
+            .expect("stub should succeed");
+        assert_eq!(units.len(), 2);
+        let err = fetch_journal_units(&Target::new(Scope::User), &JournalSource::Local)
+            .expect_err("stub should fail");
+        assert!(err.to_string().contains("journal discovery test error"));
+    }
+
+    #[test]
+    fn parse_detail_json_keeps_entries_whose_message_is_null() {
+        // journalctl writes long fields as null unless `--all` is passed.
+        let out = r#"{"__CURSOR":"c1","_COMM":"app","_PID":"8","MESSAGE":null}"#;
+        let rows = parse_detail_json(out);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].log, "app[8]:");
+        assert_eq!(rows[0].cursor.as_deref(), Some("c1"));
+    }
+
+    #[test]
+    fn parse_journal_entry_fields_decodes_every_value_kind() {
+        let output = r#"{"MESSAGE":"hello","_PID":"42","BINARY":[104,105],"TAG":["a","b"],"EMPTY":null,"N":7}
+not-json"#;
+        let entries = parse_journal_entry_fields(output);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(
+            entries[0],
+            vec![
+                ("BINARY".to_string(), "hi".to_string()),
+                ("MESSAGE".to_string(), "hello".to_string()),
+                ("N".to_string(), "7".to_string()),
+                ("TAG".to_string(), "a".to_string()),
+                ("TAG".to_string(), "b".to_string()),
+                ("_PID".to_string(), "42".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_detail_json_keeps_runs_boots_and_exit_results() {
+        let json = r#"{"__REALTIME_TIMESTAMP":"1771927203500000","MESSAGE":"app.service: Failed with result 'exit-code'.","INVOCATION_ID":"a","UNIT_RESULT":"exit-code"}
+{"__REALTIME_TIMESTAMP":"1771927203000000","MESSAGE":"app.service: Main process exited, code=exited, status=1/FAILURE","INVOCATION_ID":"a","EXIT_CODE":"exited","EXIT_STATUS":"1"}
+{"__REALTIME_TIMESTAMP":"1771927202000000","MESSAGE":"crash\ntrace","_SYSTEMD_INVOCATION_ID":"a","_BOOT_ID":"new","__CURSOR":"c"}
+{"__REALTIME_TIMESTAMP":"1771927201000000","MESSAGE":"Deactivated successfully.","INVOCATION_ID":"z","MESSAGE_ID":"7ad2d189f7e94e70a38c781354912448","_BOOT_ID":"old"}
+{"__REALTIME_TIMESTAMP":"1771923600000000","MESSAGE":"no run"}"#;
+        let logs = parse_detail_json(json);
+        let annotated: Vec<(Option<&str>, Option<&str>)> = logs
+            .iter()
+            .map(|log| (log.invocation.as_deref(), log.exit.as_deref()))
+            .collect();
+        assert_eq!(
+            annotated,
+            vec![
+                (Some("a"), Some("result=exit-code")),
+                (Some("a"), Some("code=exited, status=1")),
+                (Some("a"), None),
+                (Some("a"), None),
+                (Some("z"), Some("result=success")),
+                (None, None),
+            ]
+        );
+        let boots: Vec<Option<&str>> = logs.iter().map(|log| log.boot.as_deref()).collect();
+        assert_eq!(
+            boots,
+            vec![None, None, Some("new"), Some("new"), Some("old"), None]
+        );
+        assert_eq!((logs[3].time.as_str(), logs[3].log.as_str()), ("", "trace"));
+        assert_eq!(logs[3].cursor.as_deref(), Some("c"));
+    }
+
+    #[test]
+    fn catalog_text_drops_header_and_fills_placeholders() {
+        let dump = "-- 39f53479d3a045ac8e11786248231fbf\nSubject: A start job for unit @UNIT@ has finished\nDefined-By: systemd\n\nThe job for @UNIT@ finished with @JOB_RESULT@ (user@host).\n";
+        let fields = vec![("UNIT".to_string(), "cron.service".to_string())];
+        assert_eq!(
+            catalog_text(dump, &fields).as_deref(),
+            Some(
+                "Subject: A start job for unit cron.service has finished\nDefined-By: systemd\n\nThe job for cron.service finished with @JOB_RESULT@ (user@host)."
+            )
+        );
+        assert_eq!(catalog_text("-- 39f53479\n", &fields), None);
+    }
+
+    #[test]
+    fn fetch_log_entry_stub_supports_success_and_error() {
+        let found = fetch_log_entry(&Target::new(Scope::System), &JournalSource::Local, "c1")
+            .expect("stub should succeed");
+        assert_eq!(entry_field(&found.fields, "__CURSOR"), Some("c1"));
+        let err = fetch_log_entry(&Target::new(Scope::System), &JournalSource::Local, "error")
+            .expect_err("sentinel should fail");
+        assert!(err.to_string().contains("entry journal test error"));
+    }
 }
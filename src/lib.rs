@@ -20,9 +20,11 @@
 pub mod app;
 pub mod cli;
 pub mod command;
+pub mod config_file;
 pub mod journal;
 pub mod rows;
 pub mod systemd;
+pub mod theme;
 pub mod types;
 
 pub use app::run;
@@ -16,18 +16,83 @@
 
 //! Transform and sort logic for list-table rows.
 
-use ratatui::prelude::{Color, Style};
-
-use crate::types::{SortMode, SystemctlUnit, UnitRow};
-
-/// Select status indicator glyph and color based on active/sub state.
-pub fn status_dot(active: &str, sub: &str) -> (char, Style) {
-    match (active, sub) {
-        ("active", "running") => ('●', Style::default().fg(Color::Green)),
-        ("active", _) => ('●', Style::default().fg(Color::Yellow)),
-        ("inactive", _) => ('●', Style::default().fg(Color::DarkGray)),
-        ("failed", _) => ('●', Style::default().fg(Color::Red)),
-        _ => ('●', Style::default().fg(Color::Blue)),
+use ratatui::prelude::Style;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::theme::Theme;
+use crate::types::{
+    Column, LogPreview, Scope, SortMode, SystemctlUnit, UnitKey, UnitProperties, UnitRow,
+};
+
+/// Select status indicator glyph and style based on active/sub state.
+///
+/// Themes with `glyphs` set use a distinct glyph per state so state stays
+/// readable without color.
+pub fn status_dot(theme: &Theme, active: &str, sub: &str) -> (char, Style) {
+    let (glyph, style) = match (active, sub) {
+        ("active", "running") => ('●', theme.running),
+        ("active", _) => ('◐', theme.active),
+        ("inactive", _) => ('○', theme.inactive),
+        ("failed", _) => ('✗', theme.failed),
+        _ => ('◌', theme.other),
+    };
+    (if theme.glyphs { glyph } else { '●' }, style)
+}
+
+/// Return the cell text of `row` in `column`; unknown properties render as `-`.
+pub fn column_text(row: &UnitRow, column: Column) -> String {
+    let props = &row.props;
+    let text = match column {
+        Column::Unit => Some(row.unit.clone()),
+        Column::Scope => Some(row.scope.label().to_string()),
+        Column::Load => Some(row.load.clone()),
+        Column::Active => Some(row.active.clone()),
+        Column::Sub => Some(row.sub.clone()),
+        Column::Description => Some(row.description.clone()),
+        Column::Log => Some(row.last_log.clone()),
+        Column::UnitFileState => props.unit_file_state.clone(),
+        Column::Preset => props.preset.clone(),
+        Column::MainPid => props.main_pid.map(|pid| pid.to_string()),
+        Column::Memory => props.memory_bytes.map(format_bytes),
+        Column::Cpu => props.cpu_nsec.map(format_cpu_time),
+        Column::Tasks => props.tasks.map(|tasks| tasks.to_string()),
+        Column::Restarts => props.restarts.map(|restarts| restarts.to_string()),
+        Column::ActiveSince => props.active_since.clone(),
+        Column::Type => props.service_type.clone(),
+    };
+    text.unwrap_or_else(|| "-".to_string())
+}
+
+/// Format a byte count with binary units, e.g. `23.4M`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "K", "M", "G", "T"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes}B")
+    } else {
+        format!("{value:.1}{}", UNITS[unit])
+    }
+}
+
+/// Format CPU time in nanoseconds, e.g. `850ms`, `12.5s`, `3min 4s`, `2h 5min`.
+pub fn format_cpu_time(nsec: u64) -> String {
+    let ms = nsec / 1_000_000;
+    let secs = ms / 1000;
+    if ms < 1000 {
+        format!("{ms}ms")
+    } else if secs < 60 {
+        format!("{:.1}s", ms as f64 / 1000.0)
+    } else if secs < 3600 {
+        format!("{}min {}s", secs / 60, secs % 60)
+    } else {
+        format!("{}h {}min", secs / 3600, (secs % 3600) / 60)
     }
 }
 
@@ -59,72 +124,195 @@ pub fn sub_rank(sub: &str) -> u8 {
     }
 }
 
-/// Build render rows from raw systemctl units.
-pub fn build_rows(units: Vec<SystemctlUnit>) -> Vec<UnitRow> {
+/// Build render rows from raw systemctl units of one scope.
+pub fn build_rows(units: Vec<SystemctlUnit>, scope: Scope, theme: &Theme) -> Vec<UnitRow> {
     units
         .into_iter()
         .map(|u| {
-            let (dot, dot_style) = status_dot(&u.active, &u.sub);
+            let (dot, dot_style) = status_dot(theme, &u.active, &u.sub);
             UnitRow {
                 dot,
                 dot_style,
                 unit: u.unit,
+                scope,
                 load: u.load,
                 active: u.active,
                 sub: u.sub,
                 description: u.description,
                 last_log: String::new(),
+                last_log_usec: None,
+                changed_at: None,
+                changed_since_launch: false,
+                marked: false,
+                props: UnitProperties {
+                    unit_file_state: u.unit_file_state,
+                    preset: u.preset,
+                    ..UnitProperties::default()
+                },
             }
         })
         .collect()
 }
 
-/// Sort rows according to the chosen sort mode.
-pub fn sort_rows(rows: &mut [UnitRow], sort_mode: SortMode) {
+/// Sort rows according to the chosen sort mode, optionally in reverse.
+///
+/// Ties are broken by unit name. Rows without a value for the sorted property
+/// (no memory reading, no log yet) stay at the end in either direction.
+pub fn sort_rows(rows: &mut [UnitRow], sort_mode: SortMode, reverse: bool) {
+    rows.sort_by(|a, b| {
+        let missing = |row: &UnitRow| match sort_mode {
+            SortMode::LastLog => row.last_log_usec.is_none(),
+            SortMode::Memory => row.props.memory_bytes.is_none(),
+            SortMode::Restarts => row.props.restarts.is_none(),
+            _ => false,
+        };
+        missing(a).cmp(&missing(b)).then_with(|| {
+            let order = compare_rows(a, b, sort_mode).then_with(|| a.unit.cmp(&b.unit));
+            if reverse { order.reverse() } else { order }
+        })
+    });
+}
+
+fn compare_rows(a: &UnitRow, b: &UnitRow, sort_mode: SortMode) -> Ordering {
     match sort_mode {
-        SortMode::Status => {
-            rows.sort_by(|a, b| {
-                (
-                    load_rank(&a.load),
-                    active_rank(&a.active),
-                    sub_rank(&a.sub),
-                    a.unit.as_str(),
-                )
-                    .cmp(&(
-                        load_rank(&b.load),
-                        active_rank(&b.active),
-                        sub_rank(&b.sub),
-                        b.unit.as_str(),
-                    ))
-            });
-        }
-        SortMode::Name => {
-            rows.sort_by(|a, b| a.unit.cmp(&b.unit));
-        }
+        SortMode::Name => Ordering::Equal,
+        SortMode::Status => (load_rank(&a.load), active_rank(&a.active), sub_rank(&a.sub)).cmp(&(
+            load_rank(&b.load),
+            active_rank(&b.active),
+            sub_rank(&b.sub),
+        )),
+        SortMode::Load => a.load.cmp(&b.load),
+        SortMode::Active => a.active.cmp(&b.active),
+        SortMode::Sub => a.sub.cmp(&b.sub),
+        SortMode::Description => a.description.cmp(&b.description),
+        SortMode::LastLog => a.last_log_usec.cmp(&b.last_log_usec),
+        SortMode::Memory => a.props.memory_bytes.cmp(&b.props.memory_bytes),
+        SortMode::Restarts => a.props.restarts.cmp(&b.props.restarts),
     }
 }
 
-/// Carry over previously shown log cells by unit name.
-pub fn seed_logs_from_previous(new_rows: &mut [UnitRow], previous_rows: &[UnitRow]) {
-    let previous_logs: std::collections::HashMap<&str, &str> = previous_rows
+/// Map each row key to its index in `rows`.
+pub fn index_rows(rows: &[UnitRow]) -> HashMap<UnitKey, usize> {
+    rows.iter()
+        .enumerate()
+        .map(|(idx, row)| (row.key(), idx))
+        .collect()
+}
+
+/// Re-sort rows in place, keeping the selected unit selected.
+pub fn resort_rows(
+    rows: &mut [UnitRow],
+    sort_mode: SortMode,
+    reverse: bool,
+    selected_idx: &mut usize,
+) {
+    let selected = rows.get(*selected_idx).map(UnitRow::key);
+    sort_rows(rows, sort_mode, reverse);
+    preserve_selection(selected, rows, selected_idx);
+}
+
+/// How long a row stays highlighted after a refresh saw it change.
+pub const CHANGE_HIGHLIGHT: Duration = Duration::from_secs(3);
+
+/// Carry over log cells and change markers from the previous refresh by scope and unit name.
+///
+/// When `same_query` is set, rows whose active or sub state differs from the previous
+/// refresh, and units that were not listed before, are marked as changed at `now`.
+/// After a filter, scope, or boot change the previous rows answered another query, so
+/// only the markers and marks carry over and the log cells are fetched afresh.
+pub fn seed_from_previous(
+    new_rows: &mut [UnitRow],
+    previous_rows: &[UnitRow],
+    same_query: bool,
+    now: Instant,
+) {
+    let previous: HashMap<(Scope, &str), &UnitRow> = previous_rows
         .iter()
-        .map(|r| (r.unit.as_str(), r.last_log.as_str()))
+        .map(|r| ((r.scope, r.unit.as_str()), r))
         .collect();
     for row in new_rows.iter_mut() {
-        if let Some(old_log) = previous_logs.get(row.unit.as_str()) {
-            row.last_log = (*old_log).to_string();
+        match previous.get(&(row.scope, row.unit.as_str())) {
+            Some(old) => {
+                row.changed_at = old.changed_at;
+                row.changed_since_launch = old.changed_since_launch;
+                row.marked = old.marked;
+                if same_query {
+                    row.last_log = old.last_log.clone();
+                    row.last_log_usec = old.last_log_usec;
+                    if row.active != old.active || row.sub != old.sub {
+                        mark_changed(row, now);
+                    }
+                }
+            }
+            None if same_query && !previous.is_empty() => mark_changed(row, now),
+            None => {}
         }
     }
 }
 
+/// Show a fetched log preview, marking the row changed when it replaces an earlier line.
+pub fn apply_log_preview(row: &mut UnitRow, log: LogPreview, now: Instant) {
+    let replaces_shown_line = !row.last_log.is_empty() || row.last_log_usec.is_some();
+    if replaces_shown_line && (row.last_log != log.message || row.last_log_usec != log.usec) {
+        mark_changed(row, now);
+    }
+    row.last_log = log.message;
+    row.last_log_usec = log.usec;
+}
+
+/// Return whether the row changed within [`CHANGE_HIGHLIGHT`] before `now`.
+pub fn recently_changed(row: &UnitRow, now: Instant) -> bool {
+    row.changed_at
+        .is_some_and(|at| now.saturating_duration_since(at) < CHANGE_HIGHLIGHT)
+}
+
+fn mark_changed(row: &mut UnitRow, now: Instant) {
+    row.changed_at = Some(now);
+    row.changed_since_launch = true;
+}
+
+/// Return the units to merge into a timeline: the marked rows, or every listed row when none is marked.
+///
+/// A journal query reads one scope. Marked rows are merged in the selected row's
+/// scope when it has marked rows and in the scope of the first marked row otherwise;
+/// without marks, the selected row's scope is listed. The count of marked units
+/// left out for being in the other scope comes last; `None` when no unit is left.
+pub fn timeline_units(
+    rows: &[UnitRow],
+    selected_idx: usize,
+) -> Option<(Scope, Vec<String>, usize)> {
+    let selected = rows.get(selected_idx)?.scope;
+    let marked: Vec<&UnitRow> = rows.iter().filter(|r| r.marked).collect();
+    let Some(first) = marked.first() else {
+        let units: Vec<String> = rows
+            .iter()
+            .filter(|r| r.scope == selected)
+            .map(|r| r.unit.clone())
+            .collect();
+        return Some((selected, units, 0));
+    };
+    let scope = if marked.iter().any(|r| r.scope == selected) {
+        selected
+    } else {
+        first.scope
+    };
+    let units: Vec<String> = marked
+        .iter()
+        .filter(|r| r.scope == scope)
+        .map(|r| r.unit.clone())
+        .collect();
+    let skipped = marked.len() - units.len();
+    Some((scope, units, skipped))
+}
+
 /// Keep current row selection stable across refreshes and reorders.
-pub fn preserve_selection(prev_unit: Option<String>, rows: &[UnitRow], selected_idx: &mut usize) {
+pub fn preserve_selection(prev_key: Option<UnitKey>, rows: &[UnitRow], selected_idx: &mut usize) {
     if rows.is_empty() {
         *selected_idx = 0;
         return;
     }
-    if let Some(unit) = prev_unit
-        && let Some(idx) = rows.iter().position(|r| r.unit == unit)
+    if let Some((scope, unit)) = prev_key
+        && let Some(idx) = rows.iter().position(|r| r.scope == scope && r.unit == unit)
     {
         *selected_idx = idx;
         return;
@@ -137,28 +325,81 @@ pub fn preserve_selection(prev_unit: Option<String>, rows: &[UnitRow], selected_
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ratatui::prelude::Color;
 
     #[test]
     fn status_dot_maps_expected_colors() {
-        let (dot, style) = status_dot("active", "running");
+        let (dot, style) = status_dot(&Theme::dark(), "active", "running");
         assert_eq!(dot, '●');
         assert_eq!(style, Style::default().fg(Color::Green));
 
-        let (dot, style) = status_dot("failed", "dead");
+        let (dot, style) = status_dot(&Theme::dark(), "failed", "dead");
         assert_eq!(dot, '●');
         assert_eq!(style, Style::default().fg(Color::Red));
 
-        let (dot, style) = status_dot("inactive", "dead");
+        let (dot, style) = status_dot(&Theme::dark(), "inactive", "dead");
         assert_eq!(dot, '●');
         assert_eq!(style, Style::default().fg(Color::DarkGray));
 
-        let (_, style) = status_dot("active", "exited");
+        let (_, style) = status_dot(&Theme::dark(), "active", "exited");
         assert_eq!(style, Style::default().fg(Color::Yellow));
 
-        let (_, style) = status_dot("reloading", "foo");
+        let (_, style) = status_dot(&Theme::dark(), "reloading", "foo");
         assert_eq!(style, Style::default().fg(Color::Blue));
     }
 
+    #[test]
+    fn column_text_formats_properties_and_placeholders() {
+        let mut row = UnitRow {
+            dot: '●',
+            dot_style: Style::default(),
+            unit: "a.service".to_string(),
+            scope: Scope::User,
+            load: "loaded".to_string(),
+            active: "active".to_string(),
+            sub: "running".to_string(),
+            description: "A".to_string(),
+            last_log: "hello".to_string(),
+            last_log_usec: None,
+            changed_at: None,
+            changed_since_launch: false,
+            marked: false,
+            props: Default::default(),
+        };
+        assert_eq!(column_text(&row, Column::Scope), "user");
+        assert_eq!(column_text(&row, Column::Log), "hello");
+        assert_eq!(column_text(&row, Column::Memory), "-");
+        row.props.memory_bytes = Some(5 * 1024 * 1024 + 512 * 1024);
+        row.props.cpu_nsec = Some(185_000_000_000);
+        row.props.main_pid = Some(812);
+        assert_eq!(column_text(&row, Column::Memory), "5.5M");
+        assert_eq!(column_text(&row, Column::Cpu), "3min 5s");
+        assert_eq!(column_text(&row, Column::MainPid), "812");
+    }
+
+    #[test]
+    fn format_helpers_pick_readable_units() {
+        assert_eq!(format_bytes(512), "512B");
+        assert_eq!(format_bytes(1536), "1.5K");
+        assert_eq!(format_bytes(3 * 1024 * 1024 * 1024), "3.0G");
+        assert_eq!(format_cpu_time(850_000_000), "850ms");
+        assert_eq!(format_cpu_time(12_500_000_000), "12.5s");
+        assert_eq!(format_cpu_time(7_500_000_000_000), "2h 5min");
+    }
+
+    #[test]
+    fn status_dot_uses_state_glyphs_for_glyph_themes() {
+        let mono = Theme::monochrome();
+        assert_eq!(
+            status_dot(&mono, "active", "running"),
+            ('●', Style::default())
+        );
+        assert_eq!(status_dot(&mono, "active", "exited").0, '◐');
+        assert_eq!(status_dot(&mono, "inactive", "dead").0, '○');
+        assert_eq!(status_dot(&mono, "failed", "failed").0, '✗');
+        assert_eq!(status_dot(&mono, "reloading", "reload").0, '◌');
+    }
+
     #[test]
     fn ranks_for_all_sort_order_match_spec() {
         assert!(load_rank("loaded") < load_rank("not-found"));
@@ -179,35 +420,53 @@ mod tests {
                 dot: '●',
                 dot_style: Style::default(),
                 unit: "z.service".to_string(),
+                scope: Scope::System,
                 load: "not-found".to_string(),
                 active: "inactive".to_string(),
                 sub: "dead".to_string(),
                 description: String::new(),
                 last_log: String::new(),
+                last_log_usec: None,
+                changed_at: None,
+                changed_since_launch: false,
+                marked: false,
+                props: Default::default(),
             },
             UnitRow {
                 dot: '●',
                 dot_style: Style::default(),
                 unit: "a.service".to_string(),
+                scope: Scope::System,
                 load: "loaded".to_string(),
                 active: "active".to_string(),
                 sub: "running".to_string(),
                 description: String::new(),
                 last_log: String::new(),
+                last_log_usec: None,
+                changed_at: None,
+                changed_since_launch: false,
+                marked: false,
+                props: Default::default(),
             },
             UnitRow {
                 dot: '●',
                 dot_style: Style::default(),
                 unit: "m.service".to_string(),
+                scope: Scope::System,
                 load: "masked".to_string(),
                 active: "failed".to_string(),
                 sub: "auto-restart".to_string(),
                 description: String::new(),
                 last_log: String::new(),
+                last_log_usec: None,
+                changed_at: None,
+                changed_since_launch: false,
+                marked: false,
+                props: Default::default(),
             },
         ];
 
-        sort_rows(&mut rows, SortMode::Status);
+        sort_rows(&mut rows, SortMode::Status, false);
         assert_eq!(rows[0].unit, "a.service");
         assert_eq!(rows[1].unit, "z.service");
         assert_eq!(rows[2].unit, "m.service");
@@ -220,39 +479,57 @@ mod tests {
                 dot: '●',
                 dot_style: Style::default(),
                 unit: "z.service".to_string(),
+                scope: Scope::System,
                 load: "loaded".to_string(),
                 active: "active".to_string(),
                 sub: "running".to_string(),
                 description: String::new(),
                 last_log: String::new(),
+                last_log_usec: None,
+                changed_at: None,
+                changed_since_launch: false,
+                marked: false,
+                props: Default::default(),
             },
             UnitRow {
                 dot: '●',
                 dot_style: Style::default(),
                 unit: "a.service".to_string(),
+                scope: Scope::System,
                 load: "not-found".to_string(),
                 active: "failed".to_string(),
                 sub: "dead".to_string(),
                 description: String::new(),
                 last_log: String::new(),
+                last_log_usec: None,
+                changed_at: None,
+                changed_since_launch: false,
+                marked: false,
+                props: Default::default(),
             },
         ];
-        sort_rows(&mut rows, SortMode::Name);
+        sort_rows(&mut rows, SortMode::Name, false);
         assert_eq!(rows[0].unit, "a.service");
         assert_eq!(rows[1].unit, "z.service");
     }
 
     #[test]
-    fn seed_logs_from_previous_preserves_known_logs_by_unit() {
+    fn seed_from_previous_preserves_known_logs_by_unit() {
         let previous = vec![UnitRow {
             dot: '●',
             dot_style: Style::default(),
             unit: "a.service".to_string(),
+            scope: Scope::System,
             load: "loaded".to_string(),
             active: "active".to_string(),
             sub: "running".to_string(),
             description: String::new(),
             last_log: "old message".to_string(),
+            last_log_usec: None,
+            changed_at: None,
+            changed_since_launch: false,
+            marked: true,
+            props: Default::default(),
         }];
 
         let mut new_rows = vec![
@@ -260,27 +537,269 @@ mod tests {
                 dot: '●',
                 dot_style: Style::default(),
                 unit: "a.service".to_string(),
+                scope: Scope::System,
                 load: "loaded".to_string(),
                 active: "active".to_string(),
                 sub: "running".to_string(),
                 description: String::new(),
                 last_log: String::new(),
+                last_log_usec: None,
+                changed_at: None,
+                changed_since_launch: false,
+                marked: false,
+                props: Default::default(),
             },
             UnitRow {
                 dot: '●',
                 dot_style: Style::default(),
                 unit: "b.service".to_string(),
+                scope: Scope::System,
                 load: "loaded".to_string(),
                 active: "active".to_string(),
                 sub: "running".to_string(),
                 description: String::new(),
                 last_log: String::new(),
+                last_log_usec: None,
+                changed_at: None,
+                changed_since_launch: false,
+                marked: false,
+                props: Default::default(),
             },
         ];
 
-        seed_logs_from_previous(&mut new_rows, &previous);
+        seed_from_previous(&mut new_rows, &previous, true, Instant::now());
         assert_eq!(new_rows[0].last_log, "old message");
+        assert!(new_rows[0].marked);
         assert_eq!(new_rows[1].last_log, "");
+        assert!(!new_rows[1].marked);
+    }
+
+    #[test]
+    fn timeline_units_prefer_marked_rows_and_count_skipped_ones() {
+        let row = |unit: &str, scope: Scope, marked: bool| UnitRow {
+            dot: '●',
+            dot_style: Style::default(),
+            unit: unit.to_string(),
+            scope,
+            load: "loaded".to_string(),
+            active: "active".to_string(),
+            sub: "running".to_string(),
+            description: String::new(),
+            last_log: String::new(),
+            last_log_usec: None,
+            changed_at: None,
+            changed_since_launch: false,
+            marked,
+            props: Default::default(),
+        };
+        let mut rows = vec![
+            row("web.service", Scope::System, false),
+            row("worker.service", Scope::System, false),
+            row("sync.service", Scope::User, false),
+        ];
+        assert_eq!(
+            timeline_units(&rows, 0),
+            Some((
+                Scope::System,
+                vec!["web.service".to_string(), "worker.service".to_string()],
+                0
+            ))
+        );
+        rows[1].marked = true;
+        rows[2].marked = true;
+        assert_eq!(
+            timeline_units(&rows, 0),
+            Some((Scope::System, vec!["worker.service".to_string()], 1))
+        );
+        assert_eq!(
+            timeline_units(&rows, 2),
+            Some((Scope::User, vec!["sync.service".to_string()], 1))
+        );
+        rows[1].marked = false;
+        assert_eq!(
+            timeline_units(&rows, 0),
+            Some((Scope::User, vec!["sync.service".to_string()], 0))
+        );
+        assert_eq!(timeline_units(&[], 0), None);
+    }
+
+    #[test]
+    fn sort_rows_by_column_reverses_and_keeps_missing_values_last() {
+        let row = |unit: &str, memory: Option<u64>, usec: Option<u64>| UnitRow {
+            dot: '●',
+            dot_style: Style::default(),
+            unit: unit.to_string(),
+            scope: Scope::System,
+            load: "loaded".to_string(),
+            active: "active".to_string(),
+            sub: "running".to_string(),
+            description: String::new(),
+            last_log: String::new(),
+            last_log_usec: usec,
+            changed_at: None,
+            changed_since_launch: false,
+            marked: false,
+            props: UnitProperties {
+                memory_bytes: memory,
+                ..UnitProperties::default()
+            },
+        };
+        let mut rows = vec![
+            row("a.service", Some(300), Some(2)),
+            row("b.service", None, Some(1)),
+            row("c.service", Some(100), None),
+            row("d.service", Some(300), Some(3)),
+        ];
+        let units =
+            |rows: &[UnitRow]| -> Vec<String> { rows.iter().map(|r| r.unit.clone()).collect() };
+
+        sort_rows(&mut rows, SortMode::Memory, false);
+        assert_eq!(
+            units(&rows),
+            ["c.service", "a.service", "d.service", "b.service"]
+        );
+        sort_rows(&mut rows, SortMode::Memory, true);
+        assert_eq!(
+            units(&rows),
+            ["d.service", "a.service", "c.service", "b.service"]
+        );
+        sort_rows(&mut rows, SortMode::LastLog, true);
+        assert_eq!(
+            units(&rows),
+            ["d.service", "a.service", "b.service", "c.service"]
+        );
+
+        let mut selected = 0;
+        resort_rows(&mut rows, SortMode::Name, false, &mut selected);
+        assert_eq!(rows[selected].unit, "d.service");
+        assert_eq!(
+            index_rows(&rows)[&(Scope::System, "d.service".to_string())],
+            3
+        );
+    }
+
+    #[test]
+    fn seed_from_previous_and_selection_distinguish_scopes() {
+        let row = |scope: Scope, last_log: &str| UnitRow {
+            dot: '●',
+            dot_style: Style::default(),
+            unit: "dbus.service".to_string(),
+            scope,
+            load: "loaded".to_string(),
+            active: "active".to_string(),
+            sub: "running".to_string(),
+            description: String::new(),
+            last_log: last_log.to_string(),
+            last_log_usec: None,
+            changed_at: None,
+            changed_since_launch: false,
+            marked: false,
+            props: Default::default(),
+        };
+        let previous = vec![row(Scope::User, "user bus")];
+        let mut new_rows = vec![row(Scope::System, ""), row(Scope::User, "")];
+        seed_from_previous(&mut new_rows, &previous, true, Instant::now());
+        assert_eq!(new_rows[0].last_log, "");
+        assert_eq!(new_rows[1].last_log, "user bus");
+
+        let mut idx = 0;
+        preserve_selection(
+            Some((Scope::User, "dbus.service".to_string())),
+            &new_rows,
+            &mut idx,
+        );
+        assert_eq!(idx, 1);
+    }
+
+    #[test]
+    fn seed_from_previous_and_log_previews_mark_changed_rows() {
+        let row = |unit: &str, sub: &str| UnitRow {
+            dot: '●',
+            dot_style: Style::default(),
+            unit: unit.to_string(),
+            scope: Scope::System,
+            load: "loaded".to_string(),
+            active: "active".to_string(),
+            sub: sub.to_string(),
+            description: String::new(),
+            last_log: String::new(),
+            last_log_usec: None,
+            changed_at: None,
+            changed_since_launch: false,
+            marked: false,
+            props: Default::default(),
+        };
+        let start = Instant::now();
+        let mut first = vec![row("a.service", "running"), row("b.service", "running")];
+        seed_from_previous(&mut first, &[], true, start);
+        assert!(first.iter().all(|r| r.changed_at.is_none()));
+        apply_log_preview(
+            &mut first[0],
+            LogPreview {
+                message: "ready".to_string(),
+                usec: Some(1),
+            },
+            start,
+        );
+        assert!(!first[0].changed_since_launch);
+
+        let later = start + Duration::from_secs(10);
+        let mut second = vec![
+            row("a.service", "running"),
+            row("b.service", "exited"),
+            row("c.service", "running"),
+        ];
+        seed_from_previous(&mut second, &first, true, later);
+        assert!(!second[0].changed_since_launch);
+        assert_eq!(second[0].last_log, "ready");
+        assert!(recently_changed(&second[1], later));
+        assert!(recently_changed(&second[2], later));
+
+        apply_log_preview(
+            &mut second[0],
+            LogPreview {
+                message: "reloaded".to_string(),
+                usec: Some(2),
+            },
+            later,
+        );
+        assert!(recently_changed(&second[0], later));
+        assert!(!recently_changed(&second[0], later + CHANGE_HIGHLIGHT));
+        assert!(second[0].changed_since_launch);
+
+        let mut third = vec![row("b.service", "exited")];
+        seed_from_previous(&mut third, &second, true, later + CHANGE_HIGHLIGHT);
+        assert!(third[0].changed_since_launch);
+        assert!(!recently_changed(&third[0], later + CHANGE_HIGHLIGHT));
+    }
+
+    #[test]
+    fn seed_from_previous_ignores_differences_after_a_query_change() {
+        let row = |unit: &str, sub: &str| UnitRow {
+            dot: '●',
+            dot_style: Style::default(),
+            unit: unit.to_string(),
+            scope: Scope::System,
+            load: "loaded".to_string(),
+            active: "active".to_string(),
+            sub: sub.to_string(),
+            description: String::new(),
+            last_log: String::new(),
+            last_log_usec: None,
+            changed_at: None,
+            changed_since_launch: false,
+            marked: false,
+            props: Default::default(),
+        };
+        let now = Instant::now();
+        let mut previous = row("a.service", "running");
+        previous.last_log = "ready".to_string();
+        previous.marked = true;
+        let mut rows = vec![row("a.service", "exited"), row("b.service", "dead")];
+        seed_from_previous(&mut rows, &[previous], false, now);
+        assert!(rows.iter().all(|r| !recently_changed(r, now)));
+        assert!(rows[0].marked);
+        assert_eq!(rows[0].last_log, "");
     }
 
     #[test]
@@ -290,32 +809,52 @@ mod tests {
                 dot: '●',
                 dot_style: Style::default(),
                 unit: "a.service".to_string(),
+                scope: Scope::System,
                 load: "loaded".to_string(),
                 active: "active".to_string(),
                 sub: "running".to_string(),
                 description: String::new(),
                 last_log: String::new(),
+                last_log_usec: None,
+                changed_at: None,
+                changed_since_launch: false,
+                marked: false,
+                props: Default::default(),
             },
             UnitRow {
                 dot: '●',
                 dot_style: Style::default(),
                 unit: "b.service".to_string(),
+                scope: Scope::System,
                 load: "loaded".to_string(),
                 active: "active".to_string(),
                 sub: "running".to_string(),
                 description: String::new(),
                 last_log: String::new(),
+                last_log_usec: None,
+                changed_at: None,
+                changed_since_launch: false,
+                marked: false,
+                props: Default::default(),
             },
         ];
         let mut idx = 0;
-        preserve_selection(Some("b.service".to_string()), &rows, &mut idx);
+        preserve_selection(
+            Some((Scope::System, "b.service".to_string())),
+            &rows,
+            &mut idx,
+        );
         assert_eq!(idx, 1);
     }
 
     #[test]
     fn preserve_selection_handles_empty_rows() {
         let mut idx = 5;
-        preserve_selection(Some("b.service".to_string()), &[], &mut idx);
+        preserve_selection(
+            Some((Scope::System, "b.service".to_string())),
+            &[],
+            &mut idx,
+        );
         assert_eq!(idx, 0);
     }
 
@@ -325,11 +864,17 @@ mod tests {
             dot: '●',
             dot_style: Style::default(),
             unit: "only.service".to_string(),
+            scope: Scope::System,
             load: "loaded".to_string(),
             active: "active".to_string(),
             sub: "running".to_string(),
             description: String::new(),
             last_log: String::new(),
+            last_log_usec: None,
+            changed_at: None,
+            changed_since_launch: false,
+            marked: false,
+            props: Default::default(),
         }];
         let mut idx = 9;
         preserve_selection(None, &rows, &mut idx);
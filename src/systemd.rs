@@ -26,13 +26,15 @@ use std::process::{Command, Stdio};
 use crate::command::{
     CommandExecError, cmd_stdout, cmd_wait, command_timeout, resolve_trusted_binary,
 };
-use std::collections::HashSet;
+use std::collections::HashMap;
 
 #[cfg(test)]
-use crate::types::SortMode;
+use crate::types::{JournalSource, SortMode};
 use crate::{
     cli::Config,
-    types::{Scope, SystemctlUnit, UnitAction, UnitFileEntry},
+    types::{
+        Scope, SystemctlUnit, Target, UnitAction, UnitFileEntry, UnitFileRoot, UnitProperties,
+    },
 };
 
 /// Match one state value against a filter value (`all` means wildcard).
@@ -73,7 +75,8 @@ pub fn action_for_start_stop_states(active_state: &str, load_state: &str) -> Res
         UnitAction::Restart
         | UnitAction::Enable
         | UnitAction::Disable
-        | UnitAction::DisableRuntime => unreachable!(),
+        | UnitAction::DisableRuntime
+        | UnitAction::Preset => unreachable!(),
     }
 }
 
@@ -89,6 +92,19 @@ pub fn action_for_unit_file_state(unit_file_state: &str) -> Result<UnitAction> {
     }
 }
 
+/// Return whether a unit's enablement differs from its vendor preset.
+///
+/// Only plain `enabled`/`disabled` states are compared; static, masked, and runtime
+/// states are left alone by `systemctl preset`.
+pub fn preset_drift(unit_file_state: Option<&str>, preset: Option<&str>) -> bool {
+    match (unit_file_state, preset) {
+        (Some(state @ ("enabled" | "disabled")), Some(preset @ ("enabled" | "disabled"))) => {
+            state != preset
+        }
+        _ => false,
+    }
+}
+
 fn parse_start_stop_properties(output: &str) -> Result<(String, String)> {
     let mut active_state = None;
     let mut load_state = None;
@@ -111,12 +127,73 @@ fn parse_start_stop_properties(output: &str) -> Result<(String, String)> {
     Ok((active_state, load_state))
 }
 
+/// Look up the unit-file state of one unit in `list-unit-files` output.
+pub fn unit_file_state_for(entries: &[UnitFileEntry], unit: &str) -> Result<String> {
+    entries
+        .iter()
+        .find(|entry| entry.unit_file == unit)
+        .map(|entry| entry.state.clone())
+        .ok_or_else(|| anyhow!("no unit file found for {unit}"))
+}
+
+/// Look up the vendor preset of one unit in `list-unit-files` output.
+pub fn unit_file_preset_for(entries: &[UnitFileEntry], unit: &str) -> Result<String> {
+    let entry = entries
+        .iter()
+        .find(|entry| entry.unit_file == unit)
+        .ok_or_else(|| anyhow!("no unit file found for {unit}"))?;
+    entry
+        .preset
+        .clone()
+        .ok_or_else(|| anyhow!("unit {unit} has no vendor preset"))
+}
+
+/// Read the vendor preset of one unit from its `UnitFileState`/`UnitFilePreset` properties.
+fn parse_unit_file_preset(output: &str, unit: &str) -> Result<String> {
+    let mut unit_file_state = "";
+    let mut preset = "";
+    for line in output.lines() {
+        match line.split_once('=') {
+            Some(("UnitFileState", value)) => unit_file_state = value.trim(),
+            Some(("UnitFilePreset", value)) => preset = value.trim(),
+            _ => {}
+        }
+    }
+    if unit_file_state.is_empty() {
+        return Err(anyhow!("no unit file found for {unit}"));
+    }
+    if preset.is_empty() {
+        return Err(anyhow!("unit {unit} has no vendor preset"));
+    }
+    Ok(preset.to_string())
+}
+
+/// Check that `systemctl preset` has a vendor preset to apply to a unit.
+///
+/// Offline roots have no manager to `show` properties, so the preset is read from
+/// `list-unit-files` instead.
+pub fn validate_preset_action(target: &Target, root: &UnitFileRoot, unit: &str) -> Result<()> {
+    if root.is_offline() {
+        unit_file_preset_for(&fetch_unit_files(target, root)?, unit).map(|_| ())
+    } else {
+        fetch_unit_file_preset(target, unit).map(|_| ())
+    }
+}
+
+/// Build a `systemctl` command routed to a target's manager.
 #[cfg(not(test))]
-fn fetch_unit_property(scope: Scope, unit: &str, property: &str) -> Result<String> {
+fn systemctl_command(target: &Target) -> Result<Command> {
     let systemctl = resolve_trusted_binary("systemctl")?;
     let mut cmd = Command::new(systemctl);
+    cmd.args(target.transport_args());
+    Ok(cmd)
+}
+
+#[cfg(not(test))]
+fn fetch_unit_property(target: &Target, unit: &str, property: &str) -> Result<String> {
+    let mut cmd = systemctl_command(target)?;
     cmd.arg("show")
-        .arg(scope.as_systemd_arg())
+        .arg(target.scope.as_systemd_arg())
         .arg("--property")
         .arg(property)
         .arg("--value")
@@ -127,11 +204,29 @@ fn fetch_unit_property(scope: Scope, unit: &str, property: &str) -> Result<Strin
 }
 
 #[cfg(not(test))]
-fn fetch_start_stop_states(scope: Scope, unit: &str) -> Result<(String, String)> {
-    let systemctl = resolve_trusted_binary("systemctl")?;
-    let mut cmd = Command::new(systemctl);
+fn fetch_unit_file_preset(target: &Target, unit: &str) -> Result<String> {
+    let mut cmd = systemctl_command(target)?;
+    cmd.arg("show")
+        .arg(target.scope.as_systemd_arg())
+        .arg("--property=UnitFileState,UnitFilePreset")
+        .arg("--")
+        .arg(unit);
+    let output =
+        cmd_stdout(&mut cmd).context("systemctl show UnitFileState,UnitFilePreset failed")?;
+    parse_unit_file_preset(&output, unit)
+}
+
+/// Test-build stub for `fetch_unit_file_preset`, answering from the unit-file stub.
+#[cfg(test)]
+fn fetch_unit_file_preset(target: &Target, unit: &str) -> Result<String> {
+    unit_file_preset_for(&fetch_unit_files(target, &UnitFileRoot::Live)?, unit)
+}
+
+#[cfg(not(test))]
+fn fetch_start_stop_states(target: &Target, unit: &str) -> Result<(String, String)> {
+    let mut cmd = systemctl_command(target)?;
     cmd.arg("show")
-        .arg(scope.as_systemd_arg())
+        .arg(target.scope.as_systemd_arg())
         .arg("--property=ActiveState,LoadState")
         .arg(unit);
     let output = cmd_stdout(&mut cmd).context("systemctl show ActiveState,LoadState failed")?;
@@ -140,26 +235,46 @@ fn fetch_start_stop_states(scope: Scope, unit: &str) -> Result<(String, String)>
 
 /// Determine whether a start or stop action should be offered for a unit.
 #[cfg(not(test))]
-pub fn select_start_stop_action(scope: Scope, unit: &str) -> Result<UnitAction> {
-    let (active_state, load_state) = fetch_start_stop_states(scope, unit)?;
+pub fn select_start_stop_action(target: &Target, unit: &str) -> Result<UnitAction> {
+    let (active_state, load_state) = fetch_start_stop_states(target, unit)?;
     action_for_start_stop_states(&active_state, &load_state)
 }
 
 /// Determine whether an enable or disable action should be offered for a unit.
+///
+/// Offline roots have no manager to `show` properties, so the state is read from
+/// `list-unit-files` instead.
 #[cfg(not(test))]
-pub fn select_enable_disable_action(scope: Scope, unit: &str) -> Result<UnitAction> {
-    let unit_file_state = fetch_unit_property(scope, unit, "UnitFileState")?;
+pub fn select_enable_disable_action(
+    target: &Target,
+    root: &UnitFileRoot,
+    unit: &str,
+) -> Result<UnitAction> {
+    let unit_file_state = if root.is_offline() {
+        unit_file_state_for(&fetch_unit_files(target, root)?, unit)?
+    } else {
+        fetch_unit_property(target, unit, "UnitFileState")?
+    };
     action_for_unit_file_state(&unit_file_state)
 }
 
-fn unit_action_args(scope: Scope, unit: &str, action: UnitAction) -> Vec<String> {
+fn unit_action_args(
+    scope: Scope,
+    root: &UnitFileRoot,
+    unit: &str,
+    action: UnitAction,
+) -> Vec<String> {
     let mut args = vec![action.as_systemctl_arg().to_string()];
     if action.uses_runtime_flag() {
         args.push("--runtime".to_string());
     }
+    // Offline roots only edit symlinks; there is no job queue to skip waiting for.
+    if !root.is_offline() {
+        args.push("--no-block".to_string());
+    }
     args.extend([
-        "--no-block".to_string(),
-        scope.as_systemd_arg().to_string(),
+        root.scope_arg(scope).to_string(),
+        "--".to_string(),
         unit.to_string(),
     ]);
     args
@@ -167,10 +282,15 @@ fn unit_action_args(scope: Scope, unit: &str, action: UnitAction) -> Vec<String>
 
 /// Queue one non-blocking start/stop/enable/disable action for a unit.
 #[cfg(not(test))]
-pub fn run_unit_action(scope: Scope, unit: &str, action: UnitAction) -> Result<()> {
-    let systemctl = resolve_trusted_binary("systemctl")?;
-    let mut cmd = Command::new(systemctl);
-    for arg in unit_action_args(scope, unit, action) {
+pub fn run_unit_action(
+    target: &Target,
+    root: &UnitFileRoot,
+    unit: &str,
+    action: UnitAction,
+) -> Result<()> {
+    let mut cmd = systemctl_command(target)?;
+    cmd.args(root.as_systemctl_args());
+    for arg in unit_action_args(target.scope, root, unit, action) {
         cmd.arg(arg);
     }
     cmd.stdin(Stdio::inherit());
@@ -181,11 +301,10 @@ pub fn run_unit_action(scope: Scope, unit: &str, action: UnitAction) -> Result<(
 
 /// Query service units via `systemctl` JSON output.
 #[cfg(not(test))]
-pub fn fetch_services(scope: Scope, show_all: bool) -> Result<Vec<SystemctlUnit>> {
-    let systemctl = resolve_trusted_binary("systemctl")?;
-    let mut cmd = Command::new(systemctl);
+pub fn fetch_services(target: &Target, show_all: bool) -> Result<Vec<SystemctlUnit>> {
+    let mut cmd = systemctl_command(target)?;
     cmd.arg("list-units")
-        .arg(scope.as_systemd_arg())
+        .arg(target.scope.as_systemd_arg())
         .arg("--no-pager")
         .arg("--plain")
         .arg("--type=service")
@@ -214,11 +333,11 @@ pub fn fetch_services(scope: Scope, show_all: bool) -> Result<Vec<SystemctlUnit>
 
 /// Query unit files via `systemctl list-unit-files --type=service --output=json`.
 #[cfg(not(test))]
-pub fn fetch_unit_files(scope: Scope) -> Result<Vec<UnitFileEntry>> {
-    let systemctl = resolve_trusted_binary("systemctl")?;
-    let mut cmd = Command::new(systemctl);
+pub fn fetch_unit_files(target: &Target, root: &UnitFileRoot) -> Result<Vec<UnitFileEntry>> {
+    let mut cmd = systemctl_command(target)?;
     cmd.arg("list-unit-files")
-        .arg(scope.as_systemd_arg())
+        .arg(root.scope_arg(target.scope))
+        .args(root.as_systemctl_args())
         .arg("--no-pager")
         .arg("--type=service")
         .arg("--output=json");
@@ -240,8 +359,8 @@ pub fn fetch_unit_files(scope: Scope) -> Result<Vec<UnitFileEntry>> {
 
 #[cfg(test)]
 /// Test-build stub for `fetch_services`; runtime I/O path is tested in integration environments.
-pub fn fetch_services(scope: Scope, show_all: bool) -> Result<Vec<SystemctlUnit>> {
-    if matches!(scope, Scope::User) {
+pub fn fetch_services(target: &Target, show_all: bool) -> Result<Vec<SystemctlUnit>> {
+    if matches!(target.scope, Scope::User) {
         return Err(anyhow!("systemd test error"));
     }
     if show_all {
@@ -252,6 +371,8 @@ pub fn fetch_services(scope: Scope, show_all: bool) -> Result<Vec<SystemctlUnit>
                 active: "active".to_string(),
                 sub: "running".to_string(),
                 description: "A".to_string(),
+                unit_file_state: None,
+                preset: None,
             },
             SystemctlUnit {
                 unit: "journal-error.service".to_string(),
@@ -259,6 +380,8 @@ pub fn fetch_services(scope: Scope, show_all: bool) -> Result<Vec<SystemctlUnit>
                 active: "inactive".to_string(),
                 sub: "dead".to_string(),
                 description: "Err".to_string(),
+                unit_file_state: None,
+                preset: None,
             },
         ]);
     }
@@ -267,7 +390,7 @@ pub fn fetch_services(scope: Scope, show_all: bool) -> Result<Vec<SystemctlUnit>
 
 /// Determine whether a start or stop action should be offered for a unit.
 #[cfg(test)]
-pub fn select_start_stop_action(_scope: Scope, unit: &str) -> Result<UnitAction> {
+pub fn select_start_stop_action(_target: &Target, unit: &str) -> Result<UnitAction> {
     if unit == "state-error.service" {
         return Err(anyhow!("active state test error"));
     }
@@ -284,7 +407,11 @@ pub fn select_start_stop_action(_scope: Scope, unit: &str) -> Result<UnitAction>
 
 /// Determine whether an enable or disable action should be offered for a unit.
 #[cfg(test)]
-pub fn select_enable_disable_action(_scope: Scope, unit: &str) -> Result<UnitAction> {
+pub fn select_enable_disable_action(
+    _target: &Target,
+    _root: &UnitFileRoot,
+    unit: &str,
+) -> Result<UnitAction> {
     if unit == "state-error.service" {
         return Err(anyhow!("unit file state test error"));
     }
@@ -303,7 +430,12 @@ pub fn select_enable_disable_action(_scope: Scope, unit: &str) -> Result<UnitAct
 
 /// Queue one non-blocking start/stop/enable/disable action for a unit.
 #[cfg(test)]
-pub fn run_unit_action(_scope: Scope, unit: &str, _action: UnitAction) -> Result<()> {
+pub fn run_unit_action(
+    _target: &Target,
+    _root: &UnitFileRoot,
+    unit: &str,
+    _action: UnitAction,
+) -> Result<()> {
     if unit == "action-error.service" {
         return Err(anyhow!("unit action test error"));
     }
@@ -312,8 +444,8 @@ pub fn run_unit_action(_scope: Scope, unit: &str, _action: UnitAction) -> Result
 
 /// Test-build stub for `fetch_unit_files`.
 #[cfg(test)]
-pub fn fetch_unit_files(scope: Scope) -> Result<Vec<UnitFileEntry>> {
-    if matches!(scope, Scope::User) {
+pub fn fetch_unit_files(target: &Target, _root: &UnitFileRoot) -> Result<Vec<UnitFileEntry>> {
+    if matches!(target.scope, Scope::User) {
         return Err(anyhow!("unit-files test error"));
     }
     Ok(vec![
@@ -325,33 +457,221 @@ pub fn fetch_unit_files(scope: Scope) -> Result<Vec<UnitFileEntry>> {
         UnitFileEntry {
             unit_file: "unloaded.service".to_string(),
             state: "disabled".to_string(),
-            preset: None,
+            preset: Some("enabled".to_string()),
         },
     ])
 }
 
+/// Query the given column properties for many units with one `systemctl show` call.
+#[cfg(not(test))]
+pub fn fetch_unit_properties(
+    target: &Target,
+    units: &[String],
+    properties: &[&str],
+) -> Result<HashMap<String, UnitProperties>> {
+    if units.is_empty() || properties.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let mut cmd = systemctl_command(target)?;
+    cmd.arg("show")
+        .arg(target.scope.as_systemd_arg())
+        .arg(format!("--property=Id,{}", properties.join(",")))
+        .arg("--")
+        .args(units);
+    let output = match cmd_stdout(&mut cmd) {
+        Ok(s) => s,
+        Err(CommandExecError::Timeout { .. }) => {
+            bail!(
+                "systemctl show timed out after {}s",
+                command_timeout().as_secs()
+            )
+        }
+        Err(e) => return Err(e).context("systemctl show failed"),
+    };
+    Ok(parse_unit_properties(&output))
+}
+
+/// Test-build stub for `fetch_unit_properties`.
+#[cfg(test)]
+pub fn fetch_unit_properties(
+    target: &Target,
+    units: &[String],
+    properties: &[&str],
+) -> Result<HashMap<String, UnitProperties>> {
+    if matches!(target.scope, Scope::User) {
+        return Err(anyhow!("unit properties test error"));
+    }
+    let asked = |property: &str| properties.contains(&property);
+    Ok(units
+        .iter()
+        .map(|unit| {
+            let props = if unit == "a.service" {
+                UnitProperties {
+                    unit_file_state: asked("UnitFileState").then(|| "enabled".to_string()),
+                    main_pid: asked("MainPID").then_some(42),
+                    memory_bytes: asked("MemoryCurrent").then_some(3 * 1024 * 1024),
+                    ..UnitProperties::default()
+                }
+            } else {
+                UnitProperties::default()
+            };
+            (unit.clone(), props)
+        })
+        .collect())
+}
+
+/// Parse multi-unit `systemctl show` output (blank-line separated `Key=Value` blocks).
+pub fn parse_unit_properties(output: &str) -> HashMap<String, UnitProperties> {
+    fn text(value: &str) -> Option<String> {
+        (!value.is_empty() && value != "[not set]").then(|| value.to_string())
+    }
+    // systemd reports unset counters as UINT64_MAX or `[not set]`.
+    fn number<T: std::str::FromStr>(value: &str) -> Option<T> {
+        if value == u64::MAX.to_string() {
+            return None;
+        }
+        value.parse().ok()
+    }
+
+    let mut units = HashMap::new();
+    for block in output.split("\n\n") {
+        let mut id = None;
+        let mut props = UnitProperties::default();
+        for line in block.lines() {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            match key {
+                "Id" => id = text(value),
+                "UnitFileState" => props.unit_file_state = text(value),
+                "UnitFilePreset" => props.preset = text(value),
+                "MainPID" => props.main_pid = number(value).filter(|pid| *pid != 0),
+                "MemoryCurrent" => props.memory_bytes = number(value),
+                "CPUUsageNSec" => props.cpu_nsec = number(value),
+                "TasksCurrent" => props.tasks = number(value),
+                "NRestarts" => props.restarts = number(value),
+                "ActiveEnterTimestamp" => props.active_since = text(strip_weekday_and_zone(value)),
+                "Type" => props.service_type = text(value),
+                _ => {}
+            }
+        }
+        if let Some(id) = id {
+            units.insert(id, props);
+        }
+    }
+    units
+}
+
+/// Shorten `Sat 2026-10-17 12:00:01 UTC` to `2026-10-17 12:00:01`.
+fn strip_weekday_and_zone(timestamp: &str) -> &str {
+    let mut value = timestamp.trim();
+    if let Some((first, rest)) = value.split_once(' ')
+        && first.chars().all(|c| c.is_ascii_alphabetic())
+    {
+        value = rest;
+    }
+    if let Some((rest, last)) = value.rsplit_once(' ')
+        && last
+            .chars()
+            .all(|c| c.is_ascii_alphabetic() || c == '/' || c == '_')
+    {
+        value = rest;
+    }
+    value
+}
+
 /// Merge unit-file entries into existing units, adding synthetic stubs for new ones.
+///
+/// Existing units pick up the unit-file state and vendor preset of their entry.
 pub fn merge_unit_file_entries(
     existing: Vec<SystemctlUnit>,
     unit_files: Vec<UnitFileEntry>,
 ) -> Vec<SystemctlUnit> {
-    let known: HashSet<String> = existing.iter().map(|u| u.unit.clone()).collect();
+    let known: HashMap<String, usize> = existing
+        .iter()
+        .enumerate()
+        .map(|(idx, u)| (u.unit.clone(), idx))
+        .collect();
     let mut merged = existing;
     for entry in unit_files {
-        if !known.contains(&entry.unit_file) {
+        if let Some(&idx) = known.get(&entry.unit_file) {
+            merged[idx].unit_file_state = Some(entry.state);
+            merged[idx].preset = entry.preset;
+        } else {
             merged.push(SystemctlUnit {
                 unit: entry.unit_file,
                 load: "stub".to_string(),
                 active: "inactive".to_string(),
                 sub: "dead".to_string(),
                 description: String::new(),
+                unit_file_state: Some(entry.state),
+                preset: entry.preset,
             });
         }
     }
     merged
 }
 
-/// Apply CLI load/active/sub filters to fetched units.
+/// Parse `machinectl list --no-legend` output into machine names.
+pub fn parse_machine_list(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .map(str::to_string)
+        .collect()
+}
+
+/// List local containers and VMs registered with `systemd-machined`.
+#[cfg(not(test))]
+pub fn fetch_machines() -> Result<Vec<String>> {
+    let machinectl = resolve_trusted_binary("machinectl")?;
+    let mut cmd = Command::new(machinectl);
+    cmd.arg("list").arg("--no-legend").arg("--no-pager");
+    let output = cmd_stdout(&mut cmd).context("machinectl list failed")?;
+    Ok(parse_machine_list(&output))
+}
+
+/// Test-build stub for `fetch_machines`.
+#[cfg(test)]
+pub fn fetch_machines() -> Result<Vec<String>> {
+    Ok(vec!["web".to_string(), "db".to_string()])
+}
+
+/// Build rows for an offline root, where only unit-file state is known.
+pub fn unit_file_only_units(unit_files: Vec<UnitFileEntry>) -> Vec<SystemctlUnit> {
+    unit_files
+        .into_iter()
+        .map(|entry| SystemctlUnit {
+            unit: entry.unit_file,
+            load: "-".to_string(),
+            active: "-".to_string(),
+            sub: "-".to_string(),
+            description: String::new(),
+            unit_file_state: Some(entry.state),
+            preset: entry.preset,
+        })
+        .collect()
+}
+
+/// Build placeholder units for names discovered in an offline journal.
+///
+/// No live manager describes these units, so their states are rendered as `-`.
+pub fn journal_only_units(unit_names: Vec<String>) -> Vec<SystemctlUnit> {
+    unit_names
+        .into_iter()
+        .map(|unit| SystemctlUnit {
+            unit,
+            load: "-".to_string(),
+            active: "-".to_string(),
+            sub: "-".to_string(),
+            description: String::new(),
+            unit_file_state: None,
+            preset: None,
+        })
+        .collect()
+}
+
+/// Apply CLI load/active/sub and preset-drift filters to fetched units.
 pub fn filter_services(units: Vec<SystemctlUnit>, cfg: &Config) -> Vec<SystemctlUnit> {
     units
         .into_iter()
@@ -359,6 +679,8 @@ pub fn filter_services(units: Vec<SystemctlUnit>, cfg: &Config) -> Vec<Systemctl
             filter_matches(&u.load, &cfg.load_filter)
                 && filter_matches(&u.active, &cfg.active_filter)
                 && filter_matches(&u.sub, &cfg.sub_filter)
+                && (!cfg.preset_drift
+                    || preset_drift(u.unit_file_state.as_deref(), u.preset.as_deref()))
         })
         .collect()
 }
@@ -398,8 +720,22 @@ mod tests {
             show_help: false,
             show_version: false,
             debug_tui: false,
-            scope: Scope::System,
+            target: Target::new(Scope::System),
+            both_scopes: false,
             sort_mode: SortMode::Name,
+            journal: JournalSource::Local,
+            root: UnitFileRoot::Live,
+            command_timeout: None,
+            detail_lines: 300,
+            refresh_interval: None,
+            key_bindings: Default::default(),
+            theme: Default::default(),
+            columns: crate::types::Column::DEFAULT.to_vec(),
+            preset_drift: false,
+            sort_reverse: false,
+            mark_changed: false,
+            split: crate::types::SplitLayout::Off,
+            boot: None,
         };
         let units = vec![
             SystemctlUnit {
@@ -408,6 +744,8 @@ mod tests {
                 active: "active".to_string(),
                 sub: "running".to_string(),
                 description: String::new(),
+                unit_file_state: None,
+                preset: None,
             },
             SystemctlUnit {
                 unit: "b.service".to_string(),
@@ -415,6 +753,8 @@ mod tests {
                 active: "inactive".to_string(),
                 sub: "dead".to_string(),
                 description: String::new(),
+                unit_file_state: None,
+                preset: None,
             },
         ];
         let out = filter_services(units, &cfg);
@@ -511,13 +851,19 @@ mod tests {
 
     #[test]
     fn unit_action_args_use_non_blocking_flag() {
-        let args = unit_action_args(Scope::System, "demo.service", UnitAction::Restart);
+        let args = unit_action_args(
+            Scope::System,
+            &UnitFileRoot::Live,
+            "demo.service",
+            UnitAction::Restart,
+        );
         assert_eq!(
             args,
             vec![
                 "restart".to_string(),
                 "--no-block".to_string(),
                 "--system".to_string(),
+                "--".to_string(),
                 "demo.service".to_string(),
             ]
         );
@@ -525,7 +871,12 @@ mod tests {
 
     #[test]
     fn unit_action_args_add_runtime_flag_for_runtime_disable() {
-        let args = unit_action_args(Scope::User, "demo.service", UnitAction::DisableRuntime);
+        let args = unit_action_args(
+            Scope::User,
+            &UnitFileRoot::Live,
+            "demo.service",
+            UnitAction::DisableRuntime,
+        );
         assert_eq!(
             args,
             vec![
@@ -533,11 +884,131 @@ mod tests {
                 "--runtime".to_string(),
                 "--no-block".to_string(),
                 "--user".to_string(),
+                "--".to_string(),
                 "demo.service".to_string(),
             ]
         );
     }
 
+    #[test]
+    fn unit_action_args_for_offline_root_skip_no_block_and_use_global_user_scope() {
+        let root = UnitFileRoot::Directory("/mnt/os".into());
+        assert_eq!(
+            unit_action_args(Scope::System, &root, "demo.service", UnitAction::Enable),
+            vec![
+                "enable".to_string(),
+                "--system".to_string(),
+                "--".to_string(),
+                "demo.service".to_string(),
+            ]
+        );
+        assert_eq!(
+            unit_action_args(Scope::User, &root, "demo.service", UnitAction::Disable),
+            vec![
+                "disable".to_string(),
+                "--global".to_string(),
+                "--".to_string(),
+                "demo.service".to_string(),
+            ]
+        );
+        assert_eq!(
+            unit_action_args(Scope::System, &root, "demo.service", UnitAction::Preset),
+            vec![
+                "preset".to_string(),
+                "--system".to_string(),
+                "--".to_string(),
+                "demo.service".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_machine_list_takes_first_column() {
+        let out = "web  container systemd-nspawn debian 12 10.0.0.2\ndb container systemd-nspawn - - -\n\n";
+        assert_eq!(parse_machine_list(out), vec!["web", "db"]);
+        assert!(parse_machine_list("").is_empty());
+    }
+
+    #[test]
+    fn unit_file_state_for_finds_matching_entry() {
+        let entries = vec![UnitFileEntry {
+            unit_file: "a.service".to_string(),
+            state: "enabled".to_string(),
+            preset: None,
+        }];
+        assert_eq!(
+            unit_file_state_for(&entries, "a.service").expect("state"),
+            "enabled"
+        );
+        let err = unit_file_state_for(&entries, "b.service").expect_err("missing unit");
+        assert_eq!(err.to_string(), "no unit file found for b.service");
+    }
+
+    #[test]
+    fn unit_file_preset_for_requires_a_vendor_preset() {
+        let entries = vec![
+            UnitFileEntry {
+                unit_file: "a.service".to_string(),
+                state: "disabled".to_string(),
+                preset: Some("enabled".to_string()),
+            },
+            UnitFileEntry {
+                unit_file: "static.service".to_string(),
+                state: "static".to_string(),
+                preset: None,
+            },
+        ];
+        assert_eq!(
+            unit_file_preset_for(&entries, "a.service").expect("preset"),
+            "enabled"
+        );
+        let err = unit_file_preset_for(&entries, "static.service").expect_err("no preset");
+        assert_eq!(err.to_string(), "unit static.service has no vendor preset");
+        assert!(unit_file_preset_for(&entries, "b.service").is_err());
+    }
+
+    #[test]
+    fn parse_unit_file_preset_requires_a_unit_file_and_a_preset() {
+        assert_eq!(
+            parse_unit_file_preset(
+                "UnitFileState=disabled\nUnitFilePreset=enabled\n",
+                "a.service"
+            )
+            .expect("preset"),
+            "enabled"
+        );
+        let err = parse_unit_file_preset("UnitFileState=static\nUnitFilePreset=\n", "s.service")
+            .expect_err("no preset");
+        assert_eq!(err.to_string(), "unit s.service has no vendor preset");
+        let err = parse_unit_file_preset("UnitFileState=\nUnitFilePreset=\n", "m.service")
+            .expect_err("no unit file");
+        assert_eq!(err.to_string(), "no unit file found for m.service");
+    }
+
+    #[test]
+    fn preset_drift_compares_only_enabled_and_disabled() {
+        assert!(preset_drift(Some("disabled"), Some("enabled")));
+        assert!(preset_drift(Some("enabled"), Some("disabled")));
+        assert!(!preset_drift(Some("enabled"), Some("enabled")));
+        assert!(!preset_drift(Some("static"), Some("enabled")));
+        assert!(!preset_drift(Some("masked"), Some("disabled")));
+        assert!(!preset_drift(Some("disabled"), None));
+        assert!(!preset_drift(None, Some("enabled")));
+    }
+
+    #[test]
+    fn unit_file_only_units_carry_unit_file_state() {
+        let units = unit_file_only_units(vec![UnitFileEntry {
+            unit_file: "a.service".to_string(),
+            state: "masked".to_string(),
+            preset: Some("enabled".to_string()),
+        }]);
+        assert_eq!(units[0].unit, "a.service");
+        assert_eq!(units[0].active, "-");
+        assert_eq!(units[0].unit_file_state.as_deref(), Some("masked"));
+        assert!(units[0].description.is_empty());
+    }
+
     #[test]
     fn should_fetch_all_only_false_for_default_running_filter_set() {
         let default_cfg = Config {
@@ -547,8 +1018,22 @@ mod tests {
             show_help: false,
             show_version: false,
             debug_tui: false,
-            scope: Scope::System,
+            target: Target::new(Scope::System),
+            both_scopes: false,
             sort_mode: SortMode::Name,
+            journal: JournalSource::Local,
+            root: UnitFileRoot::Live,
+            command_timeout: None,
+            detail_lines: 300,
+            refresh_interval: None,
+            key_bindings: Default::default(),
+            theme: Default::default(),
+            columns: crate::types::Column::DEFAULT.to_vec(),
+            preset_drift: false,
+            sort_reverse: false,
+            mark_changed: false,
+            split: crate::types::SplitLayout::Off,
+            boot: None,
         };
         assert!(!should_fetch_all(&default_cfg));
 
@@ -585,67 +1070,90 @@ mod tests {
 
     #[test]
     fn fetch_services_test_stub_returns_empty() {
-        let units = fetch_services(Scope::System, false).expect("stub should succeed");
+        let units =
+            fetch_services(&Target::new(Scope::System), false).expect("stub should succeed");
         assert!(units.is_empty());
     }
 
     #[test]
     fn fetch_services_test_stub_returns_row_for_show_all() {
-        let units = fetch_services(Scope::System, true).expect("stub should succeed");
+        let units = fetch_services(&Target::new(Scope::System), true).expect("stub should succeed");
         assert_eq!(units.len(), 2);
     }
 
     #[test]
     fn select_action_test_stubs_return_expected_values() {
         assert_eq!(
-            select_start_stop_action(Scope::System, "running.service").expect("start/stop action"),
+            select_start_stop_action(&Target::new(Scope::System), "running.service")
+                .expect("start/stop action"),
             UnitAction::Stop
         );
         assert_eq!(
-            select_start_stop_action(Scope::System, "refreshing.service")
+            select_start_stop_action(&Target::new(Scope::System), "refreshing.service")
                 .expect("refreshing start/stop action"),
             UnitAction::Stop
         );
         assert_eq!(
-            select_enable_disable_action(Scope::System, "enabled.service")
-                .expect("enable/disable action"),
+            select_enable_disable_action(
+                &Target::new(Scope::System),
+                &UnitFileRoot::Live,
+                "enabled.service"
+            )
+            .expect("enable/disable action"),
             UnitAction::Disable
         );
         assert_eq!(
-            select_enable_disable_action(Scope::System, "enabled-runtime.service")
-                .expect("runtime enable/disable action"),
+            select_enable_disable_action(
+                &Target::new(Scope::System),
+                &UnitFileRoot::Live,
+                "enabled-runtime.service"
+            )
+            .expect("runtime enable/disable action"),
             UnitAction::DisableRuntime
         );
         assert_eq!(
-            select_enable_disable_action(Scope::System, "disabled.service")
-                .expect("enable/disable action"),
+            select_enable_disable_action(
+                &Target::new(Scope::System),
+                &UnitFileRoot::Live,
+                "disabled.service"
+            )
+            .expect("enable/disable action"),
             UnitAction::Enable
         );
     }
 
     #[test]
     fn select_action_test_stubs_surface_errors() {
-        let start_stop = select_start_stop_action(Scope::System, "state-error.service")
-            .expect_err("start/stop error");
+        let start_stop =
+            select_start_stop_action(&Target::new(Scope::System), "state-error.service")
+                .expect_err("start/stop error");
         assert!(start_stop.to_string().contains("active state test error"));
 
-        let masked = select_start_stop_action(Scope::System, "masked.service")
+        let masked = select_start_stop_action(&Target::new(Scope::System), "masked.service")
             .expect_err("masked units should reject start");
         assert_eq!(
             masked.to_string(),
             "load state 'masked' does not support start"
         );
 
-        let enable_disable = select_enable_disable_action(Scope::System, "state-error.service")
-            .expect_err("enable/disable error");
+        let enable_disable = select_enable_disable_action(
+            &Target::new(Scope::System),
+            &UnitFileRoot::Live,
+            "state-error.service",
+        )
+        .expect_err("enable/disable error");
         assert!(
             enable_disable
                 .to_string()
                 .contains("unit file state test error")
         );
 
-        let unsupported = select_enable_disable_action(Scope::System, "static.service")
-            .expect_err("unsupported enable/disable");
+        let unsupported = select_enable_disable_action(
+            &Target::new(Scope::System),
+            &UnitFileRoot::Live,
+            "static.service",
+        )
+        .expect_err("unsupported enable/disable");
         assert!(
             unsupported
                 .to_string()
@@ -655,21 +1163,33 @@ mod tests {
 
     #[test]
     fn run_unit_action_test_stub_supports_success_and_error() {
-        run_unit_action(Scope::System, "demo.service", UnitAction::Start).expect("action ok");
-        let err = run_unit_action(Scope::System, "action-error.service", UnitAction::Stop)
-            .expect_err("action error");
+        run_unit_action(
+            &Target::new(Scope::System),
+            &UnitFileRoot::Live,
+            "demo.service",
+            UnitAction::Start,
+        )
+        .expect("action ok");
+        let err = run_unit_action(
+            &Target::new(Scope::System),
+            &UnitFileRoot::Live,
+            "action-error.service",
+            UnitAction::Stop,
+        )
+        .expect_err("action error");
         assert!(err.to_string().contains("unit action test error"));
     }
 
     #[test]
     fn fetch_services_test_stub_errors_for_user_scope() {
-        let err = fetch_services(Scope::User, false).expect_err("stub should fail");
+        let err = fetch_services(&Target::new(Scope::User), false).expect_err("stub should fail");
         assert!(err.to_string().contains("systemd test error"));
     }
 
     #[test]
     fn fetch_unit_files_test_stub_returns_entries() {
-        let entries = fetch_unit_files(Scope::System).expect("stub should succeed");
+        let entries = fetch_unit_files(&Target::new(Scope::System), &UnitFileRoot::Live)
+            .expect("stub should succeed");
         assert_eq!(entries.len(), 2);
         assert_eq!(entries[0].unit_file, "a.service");
         assert_eq!(entries[1].unit_file, "unloaded.service");
@@ -677,7 +1197,8 @@ mod tests {
 
     #[test]
     fn fetch_unit_files_test_stub_errors_for_user_scope() {
-        let err = fetch_unit_files(Scope::User).expect_err("stub should fail");
+        let err = fetch_unit_files(&Target::new(Scope::User), &UnitFileRoot::Live)
+            .expect_err("stub should fail");
         assert!(err.to_string().contains("unit-files test error"));
     }
 
@@ -689,6 +1210,8 @@ mod tests {
             active: "active".to_string(),
             sub: "running".to_string(),
             description: "A".to_string(),
+            unit_file_state: None,
+            preset: None,
         }];
         let unit_files = vec![
             UnitFileEntry {
@@ -706,7 +1229,11 @@ mod tests {
         assert_eq!(merged.len(), 2);
         assert_eq!(merged[0].unit, "a.service");
         assert_eq!(merged[0].load, "loaded");
+        assert_eq!(merged[0].unit_file_state.as_deref(), Some("enabled"));
+        assert_eq!(merged[0].preset.as_deref(), Some("enabled"));
         assert_eq!(merged[1].unit, "new.service");
+        assert_eq!(merged[1].unit_file_state.as_deref(), Some("disabled"));
+        assert_eq!(merged[1].preset, None);
         assert_eq!(merged[1].load, "stub");
         assert_eq!(merged[1].active, "inactive");
         assert_eq!(merged[1].sub, "dead");
@@ -734,12 +1261,59 @@ mod tests {
             active: "active".to_string(),
             sub: "running".to_string(),
             description: "A".to_string(),
+            unit_file_state: None,
+            preset: None,
         }];
         let merged = merge_unit_file_entries(existing, Vec::new());
         assert_eq!(merged.len(), 1);
         assert_eq!(merged[0].unit, "a.service");
     }
 
+    #[test]
+    fn journal_only_units_use_placeholder_states() {
+        let units = journal_only_units(vec!["a.service".to_string()]);
+        assert_eq!(units.len(), 1);
+        assert_eq!(units[0].unit, "a.service");
+        assert_eq!(units[0].load, "-");
+        assert_eq!(units[0].active, "-");
+        assert_eq!(units[0].sub, "-");
+        assert!(units[0].description.is_empty());
+    }
+
+    #[test]
+    fn parse_unit_properties_reads_blocks_and_skips_unset_values() {
+        let output = "Type=notify\nMainPID=812\nNRestarts=2\nMemoryCurrent=5242880\n\
+CPUUsageNSec=1500000000\nTasksCurrent=7\nActiveEnterTimestamp=Sat 2026-10-17 12:00:01 UTC\n\
+UnitFileState=enabled\nUnitFilePreset=disabled\nId=sshd.service\n\n\
+Type=oneshot\nMainPID=0\nNRestarts=0\nMemoryCurrent=[not set]\n\
+CPUUsageNSec=[not set]\nTasksCurrent=18446744073709551615\nActiveEnterTimestamp=\n\
+UnitFileState=static\nUnitFilePreset=\nId=setup.service\n";
+        let props = parse_unit_properties(output);
+        assert_eq!(
+            props["sshd.service"],
+            UnitProperties {
+                unit_file_state: Some("enabled".to_string()),
+                preset: Some("disabled".to_string()),
+                main_pid: Some(812),
+                memory_bytes: Some(5_242_880),
+                cpu_nsec: Some(1_500_000_000),
+                tasks: Some(7),
+                restarts: Some(2),
+                active_since: Some("2026-10-17 12:00:01".to_string()),
+                service_type: Some("notify".to_string()),
+            }
+        );
+        assert_eq!(
+            props["setup.service"],
+            UnitProperties {
+                unit_file_state: Some("static".to_string()),
+                restarts: Some(0),
+                service_type: Some("oneshot".to_string()),
+                ..UnitProperties::default()
+            }
+        );
+    }
+
     #[test]
     fn merge_unit_file_entries_handles_both_empty() {
         let merged = merge_unit_file_entries(Vec::new(), Vec::new());
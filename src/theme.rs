@@ -0,0 +1,336 @@
+/*
+   Copyright (C) 2026 l5yth
+
+   Licensed under the Apache License, Version 2.0 (the "License");
+   you may not use this file except in compliance with the License.
+   You may obtain a copy of the License at
+
+       http://www.apache.org/licenses/LICENSE-2.0
+
+   Unless required by applicable law or agreed to in writing, software
+   distributed under the License is distributed on an "AS IS" BASIS,
+   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+   See the License for the specific language governing permissions and
+   limitations under the License.
+*/
+
+//! Color themes for the terminal UI.
+
+use anyhow::{Context, Result, anyhow};
+use ratatui::prelude::{Color, Modifier, Style};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// Styles and state glyphs used when rendering.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    /// Marker style for active running units.
+    pub running: Style,
+    /// Marker style for active units in any other sub state.
+    pub active: Style,
+    /// Marker style for inactive units.
+    pub inactive: Style,
+    /// Marker style for failed units.
+    pub failed: Style,
+    /// Marker style for transitional and unknown states.
+    pub other: Style,
+    /// Style for table headers.
+    pub header: Style,
+    /// Style for the selected row.
+    pub selected: Style,
+    /// Style for footers and placeholder messages.
+    pub muted: Style,
+    /// Style for rows that changed at the latest refresh.
+    pub changed: Style,
+    /// Style for search matches in the detail logs.
+    pub search_match: Style,
+    /// Whether state markers use a distinct glyph per state instead of one dot.
+    pub glyphs: bool,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    /// Default theme for dark terminal backgrounds.
+    pub fn dark() -> Self {
+        Self {
+            running: Style::default().fg(Color::Green),
+            active: Style::default().fg(Color::Yellow),
+            inactive: Style::default().fg(Color::DarkGray),
+            failed: Style::default().fg(Color::Red),
+            other: Style::default().fg(Color::Blue),
+            header: Style::default().add_modifier(Modifier::BOLD),
+            selected: Style::default().add_modifier(Modifier::REVERSED),
+            muted: Style::default().fg(Color::DarkGray),
+            changed: Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+            search_match: Style::default().fg(Color::Black).bg(Color::Yellow),
+            glyphs: false,
+        }
+    }
+
+    /// Theme for light terminal backgrounds, avoiding yellow and light grays.
+    pub fn light() -> Self {
+        Self {
+            active: Style::default().fg(Color::Magenta),
+            inactive: Style::default().fg(Color::DarkGray),
+            changed: Style::default()
+                .fg(Color::Blue)
+                .add_modifier(Modifier::BOLD),
+            ..Self::dark()
+        }
+    }
+
+    /// Bright, bold colors combined with per-state glyphs.
+    pub fn high_contrast() -> Self {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        Self {
+            running: bold.fg(Color::LightGreen),
+            active: bold.fg(Color::LightYellow),
+            inactive: Style::default().fg(Color::White),
+            failed: bold.fg(Color::LightRed),
+            other: bold.fg(Color::LightCyan),
+            header: bold.add_modifier(Modifier::UNDERLINED),
+            selected: bold.add_modifier(Modifier::REVERSED),
+            muted: Style::default().fg(Color::White),
+            changed: bold.fg(Color::LightCyan).add_modifier(Modifier::ITALIC),
+            search_match: bold.fg(Color::Black).bg(Color::LightYellow),
+            glyphs: true,
+        }
+    }
+
+    /// No colors at all; unit state is told apart by glyphs.
+    pub fn monochrome() -> Self {
+        Self {
+            running: Style::default(),
+            active: Style::default(),
+            inactive: Style::default(),
+            failed: Style::default(),
+            other: Style::default(),
+            header: Style::default().add_modifier(Modifier::BOLD),
+            selected: Style::default().add_modifier(Modifier::REVERSED),
+            muted: Style::default(),
+            changed: Style::default().add_modifier(Modifier::BOLD),
+            search_match: Style::default().add_modifier(Modifier::UNDERLINED),
+            glyphs: true,
+        }
+    }
+
+    /// Return a built-in theme by name.
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high-contrast" => Some(Self::high_contrast()),
+            "monochrome" => Some(Self::monochrome()),
+            _ => None,
+        }
+    }
+
+    /// Return the color of the `idx`-th unit in a merged timeline, cycling through the state colors.
+    pub fn timeline_unit(&self, idx: usize) -> Style {
+        let styles = [
+            self.other,
+            self.running,
+            self.active,
+            self.changed,
+            self.failed,
+        ];
+        styles[idx % styles.len()]
+    }
+}
+
+/// Custom theme from a `[themes.<name>]` config table.
+///
+/// Unset entries keep the value of the `base` built-in theme (default `dark`).
+/// Colors use ratatui names (`green`, `lightred`, `darkgray`), indices (`208`),
+/// or hex (`#ffaa00`).
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ThemeSpec {
+    /// Built-in theme the custom theme starts from.
+    pub base: Option<String>,
+    /// Color for active running units.
+    pub running: Option<String>,
+    /// Color for active units in any other sub state.
+    pub active: Option<String>,
+    /// Color for inactive units.
+    pub inactive: Option<String>,
+    /// Color for failed units.
+    pub failed: Option<String>,
+    /// Color for transitional and unknown states.
+    pub other: Option<String>,
+    /// Color for footers and placeholder messages.
+    pub muted: Option<String>,
+    /// Color for rows that changed at the latest refresh.
+    pub changed: Option<String>,
+    /// Background color for search matches.
+    pub search_match: Option<String>,
+    /// Whether state markers use a distinct glyph per state.
+    pub glyphs: Option<bool>,
+}
+
+impl ThemeSpec {
+    fn build(&self) -> Result<Theme> {
+        let base = self.base.as_deref().unwrap_or("dark");
+        let mut theme =
+            Theme::builtin(base).ok_or_else(|| anyhow!("unknown base theme: {base}"))?;
+        for (value, style) in [
+            (&self.running, &mut theme.running),
+            (&self.active, &mut theme.active),
+            (&self.inactive, &mut theme.inactive),
+            (&self.failed, &mut theme.failed),
+            (&self.other, &mut theme.other),
+            (&self.muted, &mut theme.muted),
+            (&self.changed, &mut theme.changed),
+        ] {
+            if let Some(value) = value {
+                *style = style.fg(parse_color(value)?);
+            }
+        }
+        if let Some(value) = &self.search_match {
+            theme.search_match = theme.search_match.bg(parse_color(value)?);
+        }
+        if let Some(glyphs) = self.glyphs {
+            theme.glyphs = glyphs;
+        }
+        Ok(theme)
+    }
+}
+
+/// Parse a ratatui color name, index, or hex value.
+fn parse_color(value: &str) -> Result<Color> {
+    Color::from_str(value).map_err(|_| anyhow!("invalid color: {value}"))
+}
+
+/// Resolve the theme named `name` from built-ins and `custom` themes.
+///
+/// `no_color` (the `NO_COLOR` convention) overrides any choice with the
+/// monochrome theme.
+pub fn resolve_theme(
+    name: Option<&str>,
+    custom: &BTreeMap<String, ThemeSpec>,
+    no_color: bool,
+) -> Result<Theme> {
+    if no_color {
+        return Ok(Theme::monochrome());
+    }
+    let name = name.unwrap_or("dark");
+    if let Some(spec) = custom.get(name) {
+        return spec
+            .build()
+            .with_context(|| format!("invalid theme {name}"));
+    }
+    Theme::builtin(name).ok_or_else(|| {
+        anyhow!("unknown theme: {name}; allowed: dark, light, high-contrast, monochrome, or a [themes.<name>] table")
+    })
+}
+
+/// Return whether the `NO_COLOR` environment variable asks for colorless output.
+#[cfg(not(test))]
+pub fn no_color_requested() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+}
+
+/// Test stub: tests never inherit `NO_COLOR` from the environment.
+#[cfg(test)]
+pub fn no_color_requested() -> bool {
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_themes_differ_in_colors_and_glyphs() {
+        assert_eq!(Theme::default(), Theme::dark());
+        assert!(!Theme::dark().glyphs);
+        assert!(Theme::high_contrast().glyphs);
+        assert_eq!(
+            Theme::light().inactive,
+            Style::default().fg(Color::DarkGray)
+        );
+        assert_eq!(Theme::light().active, Style::default().fg(Color::Magenta));
+        let mono = Theme::monochrome();
+        assert!(mono.glyphs);
+        assert_eq!(mono.running, Style::default());
+        assert_eq!(mono.muted, Style::default());
+        assert_eq!(
+            Theme::builtin("high-contrast"),
+            Some(Theme::high_contrast())
+        );
+        assert_eq!(Theme::builtin("neon"), None);
+    }
+
+    #[test]
+    fn timeline_units_cycle_through_state_colors() {
+        let dark = Theme::dark();
+        assert_eq!(dark.timeline_unit(0), dark.other);
+        assert_eq!(dark.timeline_unit(1), dark.running);
+        assert_eq!(dark.timeline_unit(5), dark.timeline_unit(0));
+        assert_eq!(Theme::monochrome().timeline_unit(1), Style::default());
+    }
+
+    #[test]
+    fn resolve_theme_picks_builtin_custom_and_honors_no_color() {
+        let custom = BTreeMap::from([(
+            "mine".to_string(),
+            ThemeSpec {
+                base: Some("light".to_string()),
+                failed: Some("#ff8800".to_string()),
+                search_match: Some("magenta".to_string()),
+                glyphs: Some(true),
+                ..ThemeSpec::default()
+            },
+        )]);
+        assert_eq!(
+            resolve_theme(None, &custom, false).expect("dark"),
+            Theme::dark()
+        );
+        assert_eq!(
+            resolve_theme(Some("high-contrast"), &custom, false).expect("builtin"),
+            Theme::high_contrast()
+        );
+        let mine = resolve_theme(Some("mine"), &custom, false).expect("custom");
+        assert_eq!(
+            mine.failed,
+            Style::default().fg(Color::Rgb(0xff, 0x88, 0x00))
+        );
+        assert_eq!(mine.active, Theme::light().active);
+        assert_eq!(
+            mine.search_match,
+            Style::default().fg(Color::Black).bg(Color::Magenta)
+        );
+        assert!(mine.glyphs);
+        assert_eq!(
+            resolve_theme(Some("mine"), &custom, true).expect("no color"),
+            Theme::monochrome()
+        );
+    }
+
+    #[test]
+    fn resolve_theme_rejects_unknown_names_and_colors() {
+        let err = resolve_theme(Some("solarized"), &BTreeMap::new(), false).expect_err("unknown");
+        assert!(err.to_string().starts_with("unknown theme: solarized"));
+
+        let custom = BTreeMap::from([(
+            "bad".to_string(),
+            ThemeSpec {
+                running: Some("not-a-color".to_string()),
+                ..ThemeSpec::default()
+            },
+        )]);
+        let err = resolve_theme(Some("bad"), &custom, false).expect_err("bad color");
+        assert_eq!(
+            format!("{err:#}"),
+            "invalid theme bad: invalid color: not-a-color"
+        );
+    }
+}
@@ -16,8 +16,15 @@
 
 //! Shared domain and UI state types.
 
+use anyhow::{Result, anyhow};
 use ratatui::prelude::Style;
+use regex::{Regex, RegexBuilder};
 use serde::Deserialize;
+use std::ffi::OsString;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use unicode_width::UnicodeWidthStr;
 
 /// Row sort order for the list view.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -26,14 +33,109 @@ pub enum SortMode {
     Name,
     /// Rank by load→active→sub→name.
     Status,
+    /// Alphabetical by load state.
+    Load,
+    /// Alphabetical by active state.
+    Active,
+    /// Alphabetical by sub-state.
+    Sub,
+    /// Alphabetical by description.
+    Description,
+    /// Oldest to newest last log entry.
+    LastLog,
+    /// Smallest to largest current memory usage.
+    Memory,
+    /// Fewest to most automatic restarts.
+    Restarts,
+}
+
+impl SortMode {
+    /// Sort modes in the order the list view cycles through them.
+    pub const CYCLE: [SortMode; 9] = [
+        Self::Status,
+        Self::Name,
+        Self::Load,
+        Self::Active,
+        Self::Sub,
+        Self::Description,
+        Self::LastLog,
+        Self::Memory,
+        Self::Restarts,
+    ];
+
+    /// Name used by `--sort` and the config file.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::Status => "status",
+            Self::Load => "load",
+            Self::Active => "active",
+            Self::Sub => "sub",
+            Self::Description => "description",
+            Self::LastLog => "log",
+            Self::Memory => "memory",
+            Self::Restarts => "restarts",
+        }
+    }
+
+    /// Look up a sort mode by its `--sort` name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::CYCLE.into_iter().find(|mode| mode.name() == name)
+    }
+
+    /// Return the sort mode following this one in [`SortMode::CYCLE`].
+    pub fn next(self) -> Self {
+        let idx = Self::CYCLE
+            .iter()
+            .position(|mode| *mode == self)
+            .unwrap_or(0);
+        Self::CYCLE[(idx + 1) % Self::CYCLE.len()]
+    }
+
+    /// Return the list column this mode sorts by; `Status` combines several.
+    pub fn column(self) -> Option<Column> {
+        match self {
+            Self::Status => None,
+            Self::Name => Some(Column::Unit),
+            Self::Load => Some(Column::Load),
+            Self::Active => Some(Column::Active),
+            Self::Sub => Some(Column::Sub),
+            Self::Description => Some(Column::Description),
+            Self::LastLog => Some(Column::Log),
+            Self::Memory => Some(Column::Memory),
+            Self::Restarts => Some(Column::Restarts),
+        }
+    }
+
+    /// Return the sort mode for a list column, if the column is sortable.
+    pub fn for_column(column: Column) -> Option<Self> {
+        Self::CYCLE
+            .into_iter()
+            .find(|mode| mode.column() == Some(column))
+    }
+
+    /// Return the `systemctl show` property this mode sorts by, if any.
+    pub fn property(self) -> Option<&'static str> {
+        match self {
+            Self::Memory => Some("MemoryCurrent"),
+            Self::Restarts => Some("NRestarts"),
+            _ => None,
+        }
+    }
+
+    /// Return whether this mode sorts by properties from `systemctl show`.
+    pub fn needs_properties(self) -> bool {
+        self.property().is_some()
+    }
 }
 
 /// Systemd unit scope.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 pub enum Scope {
     /// User-manager scope (`systemctl --user` / `journalctl --user`).
     User,
     /// System-manager scope (`systemctl --system` / `journalctl --system`).
+    #[default]
     System,
 }
 
@@ -45,6 +147,160 @@ impl Scope {
             Self::User => "--user",
         }
     }
+
+    /// Return the short label shown next to rows when both scopes are listed.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::System => "system",
+            Self::User => "user",
+        }
+    }
+}
+
+/// Identity of a list row; the same unit name may exist in both scopes.
+pub type UnitKey = (Scope, String);
+
+/// Manager addressed by `systemctl`/`journalctl`: a scope on the local host, a container,
+/// or a remote host.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Target {
+    /// System or user manager.
+    pub scope: Scope,
+    /// Local container or VM registered with `systemd-machined` (`--machine`).
+    pub machine: Option<String>,
+    /// Remote `[user@]host` reached over ssh (`--host`).
+    pub host: Option<String>,
+}
+
+impl Target {
+    /// Target the given scope on the local host.
+    pub fn new(scope: Scope) -> Self {
+        Self {
+            scope,
+            machine: None,
+            host: None,
+        }
+    }
+
+    /// Return the extra `systemctl` arguments that route a command to this target's manager.
+    pub fn transport_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if let Some(machine) = self.manager_machine() {
+            args.extend(["--machine".to_string(), machine]);
+        }
+        if let Some(host) = &self.host {
+            args.extend(["--host".to_string(), host.clone()]);
+        }
+        args
+    }
+
+    /// Return the `systemctl --machine` value for this target's manager.
+    ///
+    /// A user manager inside a machine is addressed as `user@machine`, and an empty user
+    /// part means the calling user, so bare machine names get an `@` prefix in user scope.
+    fn manager_machine(&self) -> Option<String> {
+        let machine = self.machine.as_ref()?;
+        match self.scope {
+            Scope::User if !machine.contains('@') => Some(format!("@{machine}")),
+            _ => Some(machine.clone()),
+        }
+    }
+
+    /// Return the `--machine` arguments for `journalctl`, which takes the bare machine name.
+    pub fn machine_args(&self) -> Vec<String> {
+        match &self.machine {
+            Some(machine) => {
+                let name = machine.rsplit('@').next().unwrap_or(machine);
+                vec!["--machine".to_string(), name.to_string()]
+            }
+            None => Vec::new(),
+        }
+    }
+}
+
+/// Journal location read by `journalctl`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum JournalSource {
+    /// The journal of the running system.
+    #[default]
+    Local,
+    /// A journal directory, e.g. an unpacked `/var/log/journal` tarball (`journalctl -D`).
+    Directory(PathBuf),
+    /// Explicit journal files (`journalctl --file`).
+    Files(Vec<PathBuf>),
+    /// The journal below an OS root directory (`journalctl --root`).
+    Root(PathBuf),
+    /// The journal inside a disk image (`journalctl --image`).
+    Image(PathBuf),
+}
+
+impl JournalSource {
+    /// Return the extra `journalctl` arguments selecting this source.
+    pub fn as_journalctl_args(&self) -> Vec<OsString> {
+        match self {
+            Self::Local => Vec::new(),
+            Self::Directory(dir) => vec![OsString::from("-D"), dir.clone().into_os_string()],
+            Self::Files(files) => files
+                .iter()
+                .flat_map(|file| [OsString::from("--file"), file.clone().into_os_string()])
+                .collect(),
+            Self::Root(root) => vec![OsString::from("--root"), root.clone().into_os_string()],
+            Self::Image(image) => vec![OsString::from("--image"), image.clone().into_os_string()],
+        }
+    }
+
+    /// Return whether this source reads a journal other than the running system's.
+    pub fn is_offline(&self) -> bool {
+        !matches!(self, Self::Local)
+    }
+}
+
+/// Location of the unit files read and edited by `systemctl`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum UnitFileRoot {
+    /// The running service manager.
+    #[default]
+    Live,
+    /// An OS tree such as a chroot or mounted image (`systemctl --root`).
+    Directory(PathBuf),
+    /// A disk image (`systemctl --image`).
+    Image(PathBuf),
+}
+
+impl UnitFileRoot {
+    /// Return the extra `systemctl` arguments selecting this root.
+    pub fn as_systemctl_args(&self) -> Vec<OsString> {
+        match self {
+            Self::Live => Vec::new(),
+            Self::Directory(dir) => vec![OsString::from("--root"), dir.clone().into_os_string()],
+            Self::Image(image) => vec![OsString::from("--image"), image.clone().into_os_string()],
+        }
+    }
+
+    /// Return the scope flag to pass to `systemctl`.
+    ///
+    /// Offline roots have no user manager, so user scope maps to the global user
+    /// configuration (`--global`).
+    pub fn scope_arg(&self, scope: Scope) -> &'static str {
+        match (self, scope) {
+            (Self::Live, _) | (_, Scope::System) => scope.as_systemd_arg(),
+            (_, Scope::User) => "--global",
+        }
+    }
+
+    /// Return whether this root points at unit files outside the running system.
+    pub fn is_offline(&self) -> bool {
+        !matches!(self, Self::Live)
+    }
+
+    /// Return the matching journal source for this root, if it is offline.
+    pub fn journal_source(&self) -> Option<JournalSource> {
+        match self {
+            Self::Live => None,
+            Self::Directory(dir) => Some(JournalSource::Root(dir.clone())),
+            Self::Image(image) => Some(JournalSource::Image(image.clone())),
+        }
+    }
 }
 
 /// JSON row returned by `systemctl list-units --output=json`.
@@ -60,6 +316,12 @@ pub struct SystemctlUnit {
     pub sub: String,
     /// Human-readable unit description.
     pub description: String,
+    /// Unit file state merged in from `list-unit-files`, if known.
+    #[serde(skip)]
+    pub unit_file_state: Option<String>,
+    /// Vendor preset merged in from `list-unit-files`, if known.
+    #[serde(skip)]
+    pub preset: Option<String>,
 }
 
 /// JSON row returned by `systemctl list-unit-files --output=json`.
@@ -74,6 +336,231 @@ pub struct UnitFileEntry {
     pub preset: Option<String>,
 }
 
+/// Runtime and unit-file properties shown in optional list columns.
+///
+/// Fields stay `None` when the property is unknown or unset for the unit.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UnitProperties {
+    /// Unit file state (`UnitFileState`).
+    pub unit_file_state: Option<String>,
+    /// Vendor preset (`UnitFilePreset`).
+    pub preset: Option<String>,
+    /// Main process ID (`MainPID`).
+    pub main_pid: Option<u32>,
+    /// Current memory usage in bytes (`MemoryCurrent`).
+    pub memory_bytes: Option<u64>,
+    /// Consumed CPU time in nanoseconds (`CPUUsageNSec`).
+    pub cpu_nsec: Option<u64>,
+    /// Current number of tasks (`TasksCurrent`).
+    pub tasks: Option<u64>,
+    /// Number of automatic restarts (`NRestarts`).
+    pub restarts: Option<u32>,
+    /// Time the unit last entered the active state (`ActiveEnterTimestamp`).
+    pub active_since: Option<String>,
+    /// Service type such as `simple` or `notify` (`Type`).
+    pub service_type: Option<String>,
+}
+
+/// Placement of the log pane that shows the selected unit's logs next to the list.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SplitLayout {
+    /// The list fills the screen; logs open in the detail view.
+    #[default]
+    Off,
+    /// Logs below the list.
+    Below,
+    /// Logs right of the list.
+    Beside,
+}
+
+impl SplitLayout {
+    /// Layouts in the order the list view cycles through them.
+    pub const CYCLE: [SplitLayout; 3] = [Self::Off, Self::Below, Self::Beside];
+
+    /// Name used by `--split` and the config file.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Below => "below",
+            Self::Beside => "beside",
+        }
+    }
+
+    /// Look up a layout by its `--split` name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::CYCLE.into_iter().find(|layout| layout.name() == name)
+    }
+
+    /// Return the layout following this one in [`SplitLayout::CYCLE`].
+    pub fn next(self) -> Self {
+        let idx = Self::CYCLE
+            .iter()
+            .position(|layout| *layout == self)
+            .unwrap_or(0);
+        Self::CYCLE[(idx + 1) % Self::CYCLE.len()]
+    }
+
+    /// Return whether the log pane is shown.
+    pub fn is_split(self) -> bool {
+        self != Self::Off
+    }
+}
+
+/// Column of the list table; the state marker is always shown first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Column {
+    /// Unit name.
+    Unit,
+    /// Manager scope.
+    Scope,
+    /// Load state.
+    Load,
+    /// Active state.
+    Active,
+    /// Sub-state.
+    Sub,
+    /// Description text.
+    Description,
+    /// Last log line.
+    Log,
+    /// Unit file state.
+    UnitFileState,
+    /// Vendor preset.
+    Preset,
+    /// Main process ID.
+    MainPid,
+    /// Current memory usage.
+    Memory,
+    /// Consumed CPU time.
+    Cpu,
+    /// Current number of tasks.
+    Tasks,
+    /// Number of automatic restarts.
+    Restarts,
+    /// Time the unit last became active.
+    ActiveSince,
+    /// Service type.
+    Type,
+}
+
+impl Column {
+    /// Every column in its canonical order.
+    pub const ALL: [Column; 16] = [
+        Self::Unit,
+        Self::Scope,
+        Self::Load,
+        Self::Active,
+        Self::Sub,
+        Self::Description,
+        Self::Log,
+        Self::UnitFileState,
+        Self::Preset,
+        Self::MainPid,
+        Self::Memory,
+        Self::Cpu,
+        Self::Tasks,
+        Self::Restarts,
+        Self::ActiveSince,
+        Self::Type,
+    ];
+
+    /// Columns shown when none are configured.
+    pub const DEFAULT: [Column; 6] = [
+        Self::Unit,
+        Self::Load,
+        Self::Active,
+        Self::Sub,
+        Self::Description,
+        Self::Log,
+    ];
+
+    /// Name used by `--columns` and the config file.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Unit => "unit",
+            Self::Scope => "scope",
+            Self::Load => "load",
+            Self::Active => "active",
+            Self::Sub => "sub",
+            Self::Description => "description",
+            Self::Log => "log",
+            Self::UnitFileState => "unit-file-state",
+            Self::Preset => "preset",
+            Self::MainPid => "pid",
+            Self::Memory => "memory",
+            Self::Cpu => "cpu",
+            Self::Tasks => "tasks",
+            Self::Restarts => "restarts",
+            Self::ActiveSince => "active-since",
+            Self::Type => "type",
+        }
+    }
+
+    /// Look up a column by its [`Column::name`].
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|column| column.name() == name)
+    }
+
+    /// Table header text.
+    pub fn header(self) -> &'static str {
+        match self {
+            Self::Log => "log (last line)",
+            Self::UnitFileState => "file state",
+            Self::ActiveSince => "active since",
+            other => other.name(),
+        }
+    }
+
+    /// Return the `systemctl show` property the column displays, if any.
+    pub fn property(self) -> Option<&'static str> {
+        match self {
+            Self::Unit
+            | Self::Scope
+            | Self::Load
+            | Self::Active
+            | Self::Sub
+            | Self::Description
+            | Self::Log => None,
+            Self::UnitFileState => Some("UnitFileState"),
+            Self::Preset => Some("UnitFilePreset"),
+            Self::MainPid => Some("MainPID"),
+            Self::Memory => Some("MemoryCurrent"),
+            Self::Cpu => Some("CPUUsageNSec"),
+            Self::Tasks => Some("TasksCurrent"),
+            Self::Restarts => Some("NRestarts"),
+            Self::ActiveSince => Some("ActiveEnterTimestamp"),
+            Self::Type => Some("Type"),
+        }
+    }
+
+    /// Return whether the column needs a `systemctl show` property query.
+    pub fn needs_properties(self) -> bool {
+        self.property().is_some()
+    }
+
+    /// Rank used to hide columns that do not fit; lower ranks stay visible longest.
+    pub fn priority(self) -> u8 {
+        match self {
+            Self::Unit => 0,
+            Self::Active => 1,
+            Self::Sub => 2,
+            Self::Log => 3,
+            Self::Scope => 4,
+            Self::Description => 5,
+            Self::Memory => 6,
+            Self::Cpu => 7,
+            Self::Restarts => 8,
+            Self::UnitFileState => 9,
+            Self::Preset => 10,
+            Self::Load => 11,
+            Self::MainPid => 12,
+            Self::Tasks => 13,
+            Self::ActiveSince => 14,
+            Self::Type => 15,
+        }
+    }
+}
+
 /// Render-ready row for the list table.
 #[derive(Debug, Clone)]
 pub struct UnitRow {
@@ -83,6 +570,8 @@ pub struct UnitRow {
     pub dot_style: Style,
     /// Unit name.
     pub unit: String,
+    /// Manager scope the unit belongs to.
+    pub scope: Scope,
     /// Load state.
     pub load: String,
     /// Active state.
@@ -93,15 +582,198 @@ pub struct UnitRow {
     pub description: String,
     /// Last-known log preview line.
     pub last_log: String,
+    /// Journal timestamp of `last_log` in microseconds, if known.
+    pub last_log_usec: Option<u64>,
+    /// When a refresh last saw the state or log line change, for a brief highlight.
+    pub changed_at: Option<Instant>,
+    /// Whether the state or log line changed at any refresh since launch.
+    pub changed_since_launch: bool,
+    /// Whether the unit is marked for a merged log timeline.
+    pub marked: bool,
+    /// Properties for optional columns, filled only when such columns are shown.
+    pub props: UnitProperties,
+}
+
+impl UnitRow {
+    /// Return the key identifying this row across refreshes.
+    pub fn key(&self) -> UnitKey {
+        (self.scope, self.unit.clone())
+    }
+}
+
+/// Latest journal message of a unit, shown in the log column.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogPreview {
+    /// Message text.
+    pub message: String,
+    /// `__REALTIME_TIMESTAMP` of the entry in microseconds, if known.
+    pub usec: Option<u64>,
 }
 
 /// A single timestamped entry in the detail log view.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct DetailLogEntry {
     /// Timestamp value rendered in the detail view.
     pub time: String,
     /// Log message text.
     pub log: String,
+    /// ID of the boot the entry was logged in, if known.
+    pub boot: Option<String>,
+    /// Unit that logged the entry; set only in merged timelines.
+    pub unit: Option<String>,
+    /// Invocation ID of the unit run that logged the entry, if known.
+    pub invocation: Option<String>,
+    /// Exit result the entry records for its run, such as `code=exited, status=1`.
+    pub exit: Option<String>,
+    /// Journal cursor of the entry the line comes from, if known.
+    pub cursor: Option<String>,
+}
+
+/// One run of a unit in the detail logs: a section of entries sharing an invocation ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Invocation {
+    /// Invocation ID shared by the entries.
+    pub id: String,
+    /// Index of the newest (topmost) entry of the run.
+    pub first: usize,
+    /// Index of the oldest (bottom) entry of the run.
+    pub last: usize,
+    /// Time of the oldest loaded entry of the run.
+    pub start: String,
+    /// Exit results recorded by the run, oldest first.
+    pub result: Option<String>,
+}
+
+/// Split newest-first `logs` into runs of consecutive entries sharing an invocation ID.
+///
+/// Entries without an ID stay in the run above them; entries above the first
+/// identified entry belong to no run.
+pub fn invocation_sections(logs: &[DetailLogEntry]) -> Vec<Invocation> {
+    let mut sections: Vec<Invocation> = Vec::new();
+    let mut exits: Vec<Vec<&str>> = Vec::new();
+    for (idx, entry) in logs.iter().enumerate() {
+        match (&entry.invocation, sections.last_mut()) {
+            (Some(id), Some(section)) if *id == section.id => section.last = idx,
+            (Some(id), _) => {
+                sections.push(Invocation {
+                    id: id.clone(),
+                    first: idx,
+                    last: idx,
+                    start: String::new(),
+                    result: None,
+                });
+                exits.push(Vec::new());
+            }
+            (None, Some(section)) => section.last = idx,
+            (None, None) => continue,
+        }
+        if let (Some(exit), Some(found)) = (&entry.exit, exits.last_mut())
+            && !found.contains(&exit.as_str())
+        {
+            found.push(exit);
+        }
+    }
+    for (section, mut found) in sections.iter_mut().zip(exits) {
+        section.start = logs[section.last].time.clone();
+        found.reverse();
+        section.result = (!found.is_empty()).then(|| found.join(", "));
+    }
+    sections
+}
+
+/// Return the index of the section in `sections` that holds log entry `idx`.
+pub fn invocation_at(sections: &[Invocation], idx: usize) -> Option<usize> {
+    sections
+        .iter()
+        .position(|section| section.first <= idx && idx <= section.last)
+}
+
+/// Return the boot ID of `logs[idx]` when it was logged in another boot than the entry above it.
+pub fn boot_boundary(logs: &[DetailLogEntry], idx: usize) -> Option<&str> {
+    let above = logs.get(idx.checked_sub(1)?)?.boot.as_deref()?;
+    let boot = logs.get(idx)?.boot.as_deref()?;
+    (boot != above).then_some(boot)
+}
+
+/// State of the invocation picker overlay.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InvocationPickerState {
+    /// Runs of the detail unit, newest first.
+    pub entries: Vec<Invocation>,
+    /// Highlighted entry index.
+    pub selected: usize,
+}
+
+impl InvocationPickerState {
+    /// Open the picker on the runs of `detail`, highlighting the run at its scroll offset.
+    pub fn for_detail(detail: &DetailState) -> Self {
+        let entries = invocation_sections(&detail.logs);
+        let selected = invocation_at(&entries, detail.scroll).unwrap_or(0);
+        Self { entries, selected }
+    }
+
+    /// Move the highlight down by one entry.
+    pub fn move_down(&mut self) {
+        if !self.entries.is_empty() {
+            self.selected = std::cmp::min(self.selected + 1, self.entries.len() - 1);
+        }
+    }
+
+    /// Move the highlight up by one entry.
+    pub fn move_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Return the log entry index where the highlighted run starts at the top.
+    pub fn selected_first(&self) -> Option<usize> {
+        self.entries.get(self.selected).map(|section| section.first)
+    }
+}
+
+/// Every field of one journal entry, as shown by the entry inspector.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JournalEntry {
+    /// Field names and values, sorted by name.
+    pub fields: Vec<(String, String)>,
+    /// Catalog explanation for the entry's `MESSAGE_ID`, if any.
+    pub catalog: Option<String>,
+}
+
+/// State of the journal entry inspector popup.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InspectorState {
+    /// Time and message of the inspected entry.
+    pub title: String,
+    /// Loaded entry; `None` while loading or after an error.
+    pub entry: Option<JournalEntry>,
+    /// Lookup error, if any.
+    pub error: Option<String>,
+    /// Vertical scroll offset in the popup lines.
+    pub scroll: usize,
+}
+
+impl InspectorState {
+    /// Open the inspector for `entry` while its fields load.
+    pub fn loading(entry: &DetailLogEntry) -> Self {
+        Self {
+            title: format!("{} {}", entry.time, entry.log),
+            ..Self::default()
+        }
+    }
+
+    /// Show the loaded entry from its first field.
+    pub fn apply_loaded(&mut self, entry: JournalEntry) {
+        self.entry = Some(entry);
+        self.error = None;
+        self.scroll = 0;
+    }
+
+    /// Record a lookup error.
+    pub fn apply_error(&mut self, error: String) {
+        self.entry = None;
+        self.error = Some(error);
+        self.scroll = 0;
+    }
 }
 
 /// Background loading phase for the list view.
@@ -139,6 +811,8 @@ pub enum UnitAction {
     Disable,
     /// Disable the unit for the current boot only.
     DisableRuntime,
+    /// Reset the unit's enablement to its vendor preset.
+    Preset,
 }
 
 impl UnitAction {
@@ -150,6 +824,7 @@ impl UnitAction {
             Self::Stop => "stop",
             Self::Enable => "enable",
             Self::Disable | Self::DisableRuntime => "disable",
+            Self::Preset => "preset",
         }
     }
 
@@ -161,6 +836,7 @@ impl UnitAction {
             Self::Stop => "stopping",
             Self::Enable => "enabling",
             Self::Disable | Self::DisableRuntime => "disabling",
+            Self::Preset => "resetting to preset",
         }
     }
 
@@ -186,25 +862,35 @@ pub struct ConfirmationState {
     pub kind: ConfirmationKind,
     /// Target unit name.
     pub unit: String,
+    /// Scope of the target unit.
+    pub scope: Scope,
 }
 
 impl ConfirmationState {
-    /// Create a yes/no confirmation request for the given action and unit.
+    /// Create a yes/no confirmation request for the given action and system unit.
     pub fn confirm_action(action: UnitAction, unit: String) -> Self {
         Self {
             kind: ConfirmationKind::ConfirmAction(action),
             unit,
+            scope: Scope::System,
         }
     }
 
-    /// Create a restart-or-stop prompt for a running unit.
+    /// Create a restart-or-stop prompt for a running system unit.
     pub fn restart_or_stop(unit: String) -> Self {
         Self {
             kind: ConfirmationKind::RestartOrStop,
             unit,
+            scope: Scope::System,
         }
     }
 
+    /// Return the same prompt aimed at a unit of the given scope.
+    pub fn with_scope(mut self, scope: Scope) -> Self {
+        self.scope = scope;
+        self
+    }
+
     /// Return the action to execute when the prompt is a yes/no confirmation.
     pub fn confirmed_action(&self) -> Option<UnitAction> {
         match self.kind {
@@ -214,6 +900,270 @@ impl ConfirmationState {
     }
 }
 
+/// One boot recorded in the journal, as listed by `journalctl --list-boots`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Boot {
+    /// Boot offset relative to the current boot (`0`), older boots are negative.
+    pub index: i64,
+    /// 128-bit boot ID in hex.
+    pub id: String,
+    /// First and last entry times of the boot as printed by `journalctl`.
+    pub span: String,
+}
+
+/// State of the boot picker overlay.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BootPickerState {
+    /// Selectable boots, newest first; `None` reads every boot.
+    pub entries: Vec<Option<Boot>>,
+    /// Highlighted entry index.
+    pub selected: usize,
+    /// Whether the boot list is still loading.
+    pub loading: bool,
+    /// Listing error, if any.
+    pub error: Option<String>,
+    current: Option<String>,
+}
+
+impl BootPickerState {
+    /// Open the picker while boots are listed, remembering the active boot ID.
+    pub fn loading(current: Option<String>) -> Self {
+        Self {
+            loading: true,
+            current,
+            ..Self::default()
+        }
+    }
+
+    /// Fill the picker with "all boots" followed by the listed boots, newest first.
+    pub fn apply_loaded(&mut self, mut boots: Vec<Boot>) {
+        boots.sort_by_key(|boot| std::cmp::Reverse(boot.index));
+        self.entries = std::iter::once(None)
+            .chain(boots.into_iter().map(Some))
+            .collect();
+        self.selected = self
+            .entries
+            .iter()
+            .position(|entry| entry.as_ref().map(|boot| &boot.id) == self.current.as_ref())
+            .unwrap_or(0);
+        self.loading = false;
+        self.error = None;
+    }
+
+    /// Record a listing error.
+    pub fn apply_error(&mut self, error: String) {
+        self.loading = false;
+        self.error = Some(error);
+    }
+
+    /// Move the highlight down by one entry.
+    pub fn move_down(&mut self) {
+        if !self.entries.is_empty() {
+            self.selected = std::cmp::min(self.selected + 1, self.entries.len() - 1);
+        }
+    }
+
+    /// Move the highlight up by one entry.
+    pub fn move_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Return the highlighted boot ID, or `None` when nothing is listed yet.
+    pub fn selected_boot(&self) -> Option<Option<String>> {
+        self.entries
+            .get(self.selected)
+            .map(|entry| entry.as_ref().map(|boot| boot.id.clone()))
+    }
+}
+
+/// State of the machine picker overlay.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MachinePickerState {
+    /// Selectable targets; `None` is the local host.
+    pub entries: Vec<Option<String>>,
+    /// Highlighted entry index.
+    pub selected: usize,
+    /// Whether the machine list is still loading.
+    pub loading: bool,
+    /// Listing error, if any.
+    pub error: Option<String>,
+    current: Option<String>,
+}
+
+impl MachinePickerState {
+    /// Open the picker while machines are listed, remembering the active machine.
+    pub fn loading(current: Option<String>) -> Self {
+        Self {
+            loading: true,
+            current,
+            ..Self::default()
+        }
+    }
+
+    /// Fill the picker with the local host followed by the listed machines.
+    pub fn apply_loaded(&mut self, machines: Vec<String>) {
+        self.entries = std::iter::once(None)
+            .chain(machines.into_iter().map(Some))
+            .collect();
+        self.selected = self
+            .entries
+            .iter()
+            .position(|entry| *entry == self.current)
+            .unwrap_or(0);
+        self.loading = false;
+        self.error = None;
+    }
+
+    /// Record a listing error.
+    pub fn apply_error(&mut self, error: String) {
+        self.loading = false;
+        self.error = Some(error);
+    }
+
+    /// Move the highlight down by one entry.
+    pub fn move_down(&mut self) {
+        if !self.entries.is_empty() {
+            self.selected = std::cmp::min(self.selected + 1, self.entries.len() - 1);
+        }
+    }
+
+    /// Move the highlight up by one entry.
+    pub fn move_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Return the highlighted target machine, or `None` when nothing is listed yet.
+    pub fn selected_machine(&self) -> Option<Option<String>> {
+        self.entries.get(self.selected).cloned()
+    }
+}
+
+/// State of the `:` command palette shown in the footer.
+///
+/// The `/` search prompt of the detail view uses the same state.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PaletteState {
+    /// Whether the prompt takes a `/` log search instead of a `:` command.
+    pub search: bool,
+    /// Text typed after the `:` or `/`.
+    pub input: String,
+    /// Completion candidates or the error of the last command.
+    pub message: Option<String>,
+}
+
+impl PaletteState {
+    /// Open an empty `/` search prompt.
+    pub fn search() -> Self {
+        Self {
+            search: true,
+            ..Self::default()
+        }
+    }
+
+    /// Append a typed character, clearing any shown message.
+    pub fn push(&mut self, c: char) {
+        self.input.push(c);
+        self.message = None;
+    }
+
+    /// Delete the last character, clearing any shown message.
+    pub fn pop(&mut self) {
+        self.input.pop();
+        self.message = None;
+    }
+}
+
+/// Interval used when auto refresh is switched on without a configured interval.
+pub const DEFAULT_AUTO_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Longest wait between automatic refreshes while refreshes keep failing.
+const MAX_AUTO_REFRESH_BACKOFF: Duration = Duration::from_secs(300);
+
+/// Schedule of automatic list refreshes.
+///
+/// A refresh is due once the countdown runs out. While a refresh is running no
+/// countdown is armed, so ticks never stack up; consecutive failures double the
+/// wait up to [`MAX_AUTO_REFRESH_BACKOFF`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AutoRefreshState {
+    /// Configured interval; `None` until one is configured or toggled on.
+    pub interval: Option<Duration>,
+    /// Whether automatic refreshes are switched on.
+    pub enabled: bool,
+    /// Number of refreshes that failed in a row.
+    pub consecutive_errors: u32,
+    next_due: Option<Instant>,
+}
+
+impl AutoRefreshState {
+    /// Start with the configured interval; `None` leaves auto refresh off.
+    pub fn new(interval: Option<Duration>) -> Self {
+        Self {
+            interval,
+            enabled: interval.is_some(),
+            ..Self::default()
+        }
+    }
+
+    /// Switch auto refresh on or off, falling back to the default interval.
+    pub fn toggle(&mut self, now: Instant) {
+        self.enabled = !self.enabled;
+        self.consecutive_errors = 0;
+        if self.enabled {
+            let interval = *self.interval.get_or_insert(DEFAULT_AUTO_REFRESH_INTERVAL);
+            self.next_due = Some(now + interval);
+        } else {
+            self.next_due = None;
+        }
+    }
+
+    /// Return whether the countdown has run out.
+    pub fn is_due(&self, now: Instant) -> bool {
+        self.enabled && self.next_due.is_some_and(|due| due <= now)
+    }
+
+    /// Stop the countdown while a refresh runs.
+    pub fn begin_refresh(&mut self) {
+        self.next_due = None;
+    }
+
+    /// Restart the countdown after a successful refresh.
+    pub fn record_success(&mut self, now: Instant) {
+        self.consecutive_errors = 0;
+        self.schedule(now);
+    }
+
+    /// Restart the countdown with a longer wait after a failed refresh.
+    pub fn record_error(&mut self, now: Instant) {
+        self.consecutive_errors = self.consecutive_errors.saturating_add(1);
+        self.schedule(now);
+    }
+
+    /// Wait before the next refresh, doubled for every failure in a row.
+    pub fn current_wait(&self) -> Option<Duration> {
+        let interval = self.interval?;
+        let factor = 1u32 << self.consecutive_errors.min(8);
+        Some(std::cmp::min(
+            interval.saturating_mul(factor),
+            std::cmp::max(interval, MAX_AUTO_REFRESH_BACKOFF),
+        ))
+    }
+
+    /// Return the time left until the next refresh; `None` while off or refreshing.
+    pub fn remaining(&self, now: Instant) -> Option<Duration> {
+        if !self.enabled {
+            return None;
+        }
+        self.next_due.map(|due| due.saturating_duration_since(now))
+    }
+
+    fn schedule(&mut self, now: Instant) {
+        if self.enabled {
+            self.next_due = self.current_wait().map(|wait| now + wait);
+        }
+    }
+}
+
 /// A request to resolve which action prompt should be shown for a unit.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ActionResolutionRequest {
@@ -227,13 +1177,28 @@ pub enum ActionResolutionRequest {
         /// Target unit name.
         unit: String,
     },
+    /// Resolve the reset-to-preset workflow from the unit's vendor preset.
+    Preset {
+        /// Target unit name.
+        unit: String,
+    },
+    /// Check that an action named in the command palette can run on the unit.
+    Named {
+        /// Action typed in the palette.
+        action: UnitAction,
+        /// Target unit name.
+        unit: String,
+    },
 }
 
 impl ActionResolutionRequest {
     /// Return the target unit for this request.
     pub fn unit(&self) -> &str {
         match self {
-            Self::StartStop { unit, .. } | Self::EnableDisable { unit } => unit,
+            Self::StartStop { unit, .. }
+            | Self::EnableDisable { unit }
+            | Self::Preset { unit }
+            | Self::Named { unit, .. } => unit,
         }
     }
 }
@@ -241,12 +1206,26 @@ impl ActionResolutionRequest {
 /// Detail pane state used by async log loading.
 #[derive(Debug, Clone, Default)]
 pub struct DetailState {
-    /// Unit currently shown in detail view.
+    /// Unit currently shown in detail view; empty for a merged timeline.
     pub unit: String,
+    /// Units merged into a timeline; empty while `unit` alone is shown.
+    pub units: Vec<String>,
+    /// Marked units of the other scope left out of the timeline.
+    pub skipped_units: usize,
+    /// Scope of the unit shown in detail view.
+    pub scope: Scope,
     /// Loaded detail log entries.
     pub logs: Vec<DetailLogEntry>,
     /// Vertical scroll offset in `logs`.
     pub scroll: usize,
+    /// Highlighted entry in `logs`, the one the inspector opens.
+    pub cursor: usize,
+    /// Whether long log messages wrap over several lines.
+    pub wrap: bool,
+    /// Search over `logs`.
+    pub search: LogSearch,
+    /// Horizontal scroll offset in terminal cells while not wrapping.
+    pub hscroll: usize,
     /// Whether a detail fetch is in progress.
     pub loading: bool,
     /// Last detail fetch error, if any.
@@ -257,10 +1236,17 @@ pub struct DetailState {
 
 impl DetailState {
     /// Enter detail mode for a unit and start an async fetch request.
-    pub fn begin_for_unit(&mut self, unit: String) -> u64 {
+    pub fn begin_for_unit(&mut self, scope: Scope, unit: String) -> u64 {
         self.unit = unit;
+        self.units.clear();
+        self.skipped_units = 0;
+        self.scope = scope;
         self.logs.clear();
         self.scroll = 0;
+        self.cursor = 0;
+        self.hscroll = 0;
+        self.search.current = None;
+        self.search.matches.clear();
         self.loading = true;
         self.error = None;
         self.next_request_id = self.next_request_id.saturating_add(1);
@@ -268,9 +1254,24 @@ impl DetailState {
         self.next_request_id
     }
 
-    /// Trigger an async refresh for the current unit while keeping existing rows visible.
+    /// Enter detail mode for a merged timeline of `units` and start an async fetch request.
+    ///
+    /// `skipped` counts marked units that were left out for being in the other scope.
+    pub fn begin_for_timeline(&mut self, scope: Scope, units: Vec<String>, skipped: usize) -> u64 {
+        let request_id = self.begin_for_unit(scope, String::new());
+        self.units = units;
+        self.skipped_units = skipped;
+        request_id
+    }
+
+    /// Return whether the view merges the logs of several units.
+    pub fn is_timeline(&self) -> bool {
+        !self.units.is_empty()
+    }
+
+    /// Trigger an async refresh for the current unit or timeline while keeping existing rows visible.
     pub fn refresh(&mut self) -> Option<u64> {
-        if self.unit.is_empty() {
+        if self.unit.is_empty() && !self.is_timeline() {
             return None;
         }
         self.loading = true;
@@ -286,11 +1287,10 @@ impl DetailState {
             return false;
         }
         self.logs = logs;
-        if self.logs.is_empty() {
-            self.scroll = 0;
-        } else {
-            self.scroll = std::cmp::min(self.scroll, self.logs.len() - 1);
-        }
+        let last = self.logs.len().saturating_sub(1);
+        self.scroll = std::cmp::min(self.scroll, last);
+        self.cursor = std::cmp::min(self.cursor, last);
+        self.search.refresh(&self.logs);
         self.loading = false;
         self.error = None;
         true
@@ -305,11 +1305,319 @@ impl DetailState {
         self.error = Some(error);
         true
     }
+
+    /// Return the entry under the cursor.
+    pub fn entry_at_cursor(&self) -> Option<&DetailLogEntry> {
+        self.logs.get(self.cursor)
+    }
+
+    /// Move the cursor one entry down or up, scrolling just enough to keep it within
+    /// `page` screen lines, where `heights` holds the lines each entry takes.
+    pub fn move_cursor(&mut self, down: bool, heights: &[usize], page: usize) {
+        if self.logs.is_empty() {
+            return;
+        }
+        self.cursor = if down {
+            std::cmp::min(self.cursor + 1, self.logs.len() - 1)
+        } else {
+            self.cursor.saturating_sub(1)
+        };
+        if self.cursor < self.scroll {
+            self.scroll = self.cursor;
+        }
+        while self.scroll < self.cursor
+            && heights
+                .get(self.scroll..=self.cursor)
+                .map_or(0, |shown| shown.iter().sum::<usize>())
+                > page.max(1)
+        {
+            self.scroll += 1;
+        }
+    }
+
+    /// Scroll to the top of the next run further down and return whether there was one.
+    pub fn next_invocation(&mut self) -> bool {
+        if self.is_timeline() {
+            return false;
+        }
+        match invocation_sections(&self.logs)
+            .iter()
+            .find(|section| section.first > self.scroll)
+        {
+            Some(section) => {
+                self.scroll = section.first;
+                self.cursor = section.first;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Scroll to the top of the closest run further up and return whether there was one.
+    pub fn previous_invocation(&mut self) -> bool {
+        if self.is_timeline() {
+            return false;
+        }
+        match invocation_sections(&self.logs)
+            .iter()
+            .rev()
+            .find(|section| section.first < self.scroll)
+        {
+            Some(section) => {
+                self.scroll = section.first;
+                self.cursor = section.first;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Switch line wrapping on or off; wrapped messages need no horizontal scroll.
+    pub fn toggle_wrap(&mut self) {
+        self.wrap = !self.wrap;
+        self.hscroll = 0;
+    }
+
+    /// Scroll messages `step` cells to the right, stopping at the widest message.
+    pub fn scroll_right(&mut self, step: usize) {
+        if self.wrap {
+            return;
+        }
+        let widest = self
+            .logs
+            .iter()
+            .map(|entry| entry.log.width())
+            .max()
+            .unwrap_or(0);
+        self.hscroll = (self.hscroll + step).min(widest.saturating_sub(1));
+    }
+
+    /// Put the cursor on the current search match and scroll so it is visible in `page` screen lines of `width`-cell
+    /// messages, where `heights` holds the lines each entry takes.
+    pub fn reveal_search_match(&mut self, heights: &[usize], page: usize, width: usize) {
+        let Some((entry, range)) = self
+            .search
+            .current
+            .and_then(|current| self.search.matches.get(current))
+            .cloned()
+        else {
+            return;
+        };
+        let lines_to_match: usize = heights
+            .get(self.scroll..=entry)
+            .map_or(usize::MAX, |shown| shown.iter().sum());
+        if entry < self.scroll || lines_to_match > page.max(1) {
+            self.scroll = entry;
+        }
+        self.cursor = entry;
+        if !self.wrap
+            && let Some(log) = self.logs.get(entry).map(|entry| entry.log.as_str())
+        {
+            let start = log[..range.start].width();
+            let end = log[..range.end].width();
+            // One cell stays free for the ellipsis of cut messages.
+            if start < self.hscroll || end > self.hscroll + width.saturating_sub(1) {
+                self.hscroll = start.saturating_sub(width / 4);
+            }
+        }
+    }
+
+    /// Scroll messages `step` cells back to the left.
+    pub fn scroll_left(&mut self, step: usize) {
+        self.hscroll = self.hscroll.saturating_sub(step);
+    }
+}
+
+/// In-buffer search over the loaded detail logs.
+#[derive(Debug, Clone, Default)]
+pub struct LogSearch {
+    /// Last searched text.
+    pub query: String,
+    /// Whether the query is a regular expression instead of plain text.
+    pub regex: bool,
+    /// Whether matching ignores case.
+    pub ignore_case: bool,
+    /// Matches in log order as the entry index and byte range in its message.
+    pub matches: Vec<(usize, Range<usize>)>,
+    /// Index in `matches` of the current match.
+    pub current: Option<usize>,
+}
+
+impl LogSearch {
+    /// Compile `query` with the current modes; an empty query matches nothing.
+    pub fn pattern(&self, query: &str) -> Result<Option<Regex>> {
+        if query.is_empty() {
+            return Ok(None);
+        }
+        let source = if self.regex {
+            query.to_string()
+        } else {
+            regex::escape(query)
+        };
+        RegexBuilder::new(&source)
+            .case_insensitive(self.ignore_case)
+            .build()
+            .map(Some)
+            .map_err(|err| anyhow!("invalid pattern {query}: {err}"))
+    }
+
+    /// Search `logs` for `query` and select the first match at or after entry `from`.
+    pub fn start(&mut self, query: &str, logs: &[DetailLogEntry], from: usize) -> Result<()> {
+        let pattern = self.pattern(query)?;
+        self.query = query.to_string();
+        self.collect(pattern.as_ref(), logs);
+        self.current = self
+            .matches
+            .iter()
+            .position(|(entry, _)| *entry >= from)
+            .or((!self.matches.is_empty()).then_some(0));
+        Ok(())
+    }
+
+    /// Re-run the last search after `logs` changed, keeping the current entry if it still matches.
+    pub fn refresh(&mut self, logs: &[DetailLogEntry]) {
+        let entry = self.current_entry();
+        let pattern = self.pattern(&self.query).ok().flatten();
+        self.collect(pattern.as_ref(), logs);
+        self.current = match entry {
+            Some(entry) => self
+                .matches
+                .iter()
+                .position(|(idx, _)| *idx >= entry)
+                .or_else(|| self.matches.len().checked_sub(1)),
+            None => (!self.matches.is_empty()).then_some(0),
+        };
+    }
+
+    fn collect(&mut self, pattern: Option<&Regex>, logs: &[DetailLogEntry]) {
+        self.matches = match pattern {
+            Some(pattern) => logs
+                .iter()
+                .enumerate()
+                .flat_map(|(idx, entry)| {
+                    pattern
+                        .find_iter(&entry.log)
+                        .filter(|found| !found.is_empty())
+                        .map(move |found| (idx, found.range()))
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+    }
+
+    /// Clear the query and its matches, keeping the modes.
+    pub fn clear(&mut self) {
+        self.query.clear();
+        self.matches.clear();
+        self.current = None;
+    }
+
+    /// Move to the next match, wrapping around, and return its entry index.
+    pub fn next_match(&mut self) -> Option<usize> {
+        let len = self.matches.len();
+        if len == 0 {
+            return None;
+        }
+        self.current = Some(self.current.map_or(0, |current| (current + 1) % len));
+        self.current_entry()
+    }
+
+    /// Move to the previous match, wrapping around, and return its entry index.
+    pub fn previous_match(&mut self) -> Option<usize> {
+        let len = self.matches.len();
+        if len == 0 {
+            return None;
+        }
+        self.current = Some(
+            self.current
+                .map_or(len - 1, |current| (current + len - 1) % len),
+        );
+        self.current_entry()
+    }
+
+    /// Return the log entry index of the current match.
+    pub fn current_entry(&self) -> Option<usize> {
+        self.current
+            .and_then(|current| self.matches.get(current))
+            .map(|(entry, _)| *entry)
+    }
+
+    /// Return the footer text for an active search, e.g. `match 3/17`.
+    pub fn status_text(&self) -> Option<String> {
+        if self.query.is_empty() {
+            return None;
+        }
+        Some(match self.current {
+            Some(current) if !self.matches.is_empty() => {
+                format!("match {}/{}", current + 1, self.matches.len())
+            }
+            _ => format!("no matches for {}", self.query),
+        })
+    }
+
+    /// Return the active modes as shown next to the search prompt.
+    pub fn modes_text(&self) -> String {
+        let text = if self.regex { "regex" } else { "text" };
+        let case = if self.ignore_case {
+            "ignore case"
+        } else {
+            "match case"
+        };
+        format!("[{text}, {case}]")
+    }
+}
+
+/// Delay before the split-layout log pane loads the logs of a new selection.
+pub const LOG_PANE_FOLLOW_DELAY: Duration = Duration::from_millis(250);
+
+/// Debounce for the split-layout log pane following the list selection.
+///
+/// Moving quickly through the list only loads logs for the unit the selection
+/// settles on.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogPaneFollow {
+    observed: Option<UnitKey>,
+    due: Option<Instant>,
+}
+
+impl LogPaneFollow {
+    /// Note the selected unit, restarting the delay when the selection changed.
+    pub fn observe(&mut self, selected: Option<UnitKey>, now: Instant) {
+        if selected != self.observed {
+            self.due = selected.is_some().then(|| now + LOG_PANE_FOLLOW_DELAY);
+            self.observed = selected;
+        }
+    }
+
+    /// Reload the logs of the observed unit without waiting, e.g. after a list refresh.
+    pub fn reload_now(&mut self, now: Instant) {
+        if self.observed.is_some() {
+            self.due = Some(now);
+        }
+    }
+
+    /// Return the unit whose logs should be loaded once its delay ran out.
+    pub fn take_due(&mut self, now: Instant) -> Option<UnitKey> {
+        match self.due {
+            Some(due) if due <= now => {
+                self.due = None;
+                self.observed.clone()
+            }
+            _ => None,
+        }
+    }
+
+    /// Forget the observed unit, so the next observation loads its logs again.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
 }
 
 /// Messages sent from the background worker thread to the UI thread.
 #[derive(Debug)]
 pub enum WorkerMsg {
+    /// One of several listed scopes failed to load; the rows of the others follow.
+    ScopeError(String),
     /// Unit rows were loaded and should replace/initialize the table.
     UnitsLoaded(Vec<UnitRow>),
     /// A partial batch of list log updates is ready.
@@ -318,8 +1626,8 @@ pub enum WorkerMsg {
         done: usize,
         /// Total rows targeted for this refresh.
         total: usize,
-        /// `(unit, last_log)` pairs for this batch.
-        logs: Vec<(String, String)>,
+        /// `((scope, unit), preview)` pairs for this batch.
+        logs: Vec<(UnitKey, LogPreview)>,
     },
     /// Detail logs loaded for a request id/unit pair.
     DetailLogsLoaded {
@@ -353,6 +1661,18 @@ pub enum WorkerMsg {
         /// Error text to show in the UI.
         error: String,
     },
+    /// Machines registered with `systemd-machined` were listed.
+    MachinesLoaded(Vec<String>),
+    /// Listing machines failed.
+    MachinesError(String),
+    /// Boots recorded in the journal were listed.
+    BootsLoaded(Vec<Boot>),
+    /// Listing boots failed.
+    BootsError(String),
+    /// The journal entry opened in the inspector was loaded.
+    EntryLoaded(JournalEntry),
+    /// Loading the inspected journal entry failed.
+    EntryError(String),
     /// Refresh worker finished all tasks.
     Finished,
     /// Refresh worker failed with a terminal error.
@@ -367,9 +1687,50 @@ mod tests {
         DetailLogEntry {
             time: "t".to_string(),
             log: text.to_string(),
+            ..DetailLogEntry::default()
         }
     }
 
+    #[test]
+    fn auto_refresh_counts_down_and_backs_off_after_errors() {
+        let start = Instant::now();
+        let mut auto = AutoRefreshState::new(Some(Duration::from_secs(10)));
+        assert!(auto.enabled);
+        auto.record_success(start);
+        assert!(!auto.is_due(start + Duration::from_secs(9)));
+        assert!(auto.is_due(start + Duration::from_secs(10)));
+
+        auto.begin_refresh();
+        assert!(!auto.is_due(start + Duration::from_secs(60)));
+        assert_eq!(auto.remaining(start), None);
+
+        auto.record_error(start);
+        auto.record_error(start);
+        assert_eq!(auto.current_wait(), Some(Duration::from_secs(40)));
+        assert_eq!(auto.remaining(start), Some(Duration::from_secs(40)));
+        for _ in 0..20 {
+            auto.record_error(start);
+        }
+        assert_eq!(auto.current_wait(), Some(Duration::from_secs(300)));
+        auto.record_success(start);
+        assert_eq!(auto.current_wait(), Some(Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn auto_refresh_toggle_uses_default_interval() {
+        let start = Instant::now();
+        let mut auto = AutoRefreshState::new(None);
+        assert!(!auto.enabled);
+        auto.record_success(start);
+        assert_eq!(auto.remaining(start), None);
+
+        auto.toggle(start);
+        assert!(auto.enabled);
+        assert_eq!(auto.remaining(start), Some(DEFAULT_AUTO_REFRESH_INTERVAL));
+        auto.toggle(start);
+        assert!(!auto.is_due(start + Duration::from_secs(60)));
+    }
+
     #[test]
     fn parses_unit_file_entry_from_json() {
         let raw = r#"
@@ -403,18 +1764,213 @@ mod tests {
             scroll: 7,
             ..DetailState::default()
         };
-        let id = state.begin_for_unit("a.service".to_string());
+        let id = state.begin_for_unit(Scope::System, "a.service".to_string());
         assert_eq!(id, 1);
         assert!(state.loading);
         assert_eq!(state.scroll, 0);
         assert_eq!(state.unit, "a.service");
     }
 
+    #[test]
+    fn detail_state_scrolls_horizontally_only_without_wrap() {
+        let mut state = DetailState {
+            logs: vec![sample_log("0123456789")],
+            ..DetailState::default()
+        };
+        state.scroll_right(8);
+        assert_eq!(state.hscroll, 8);
+        state.scroll_right(8);
+        assert_eq!(state.hscroll, 9);
+        state.scroll_left(4);
+        assert_eq!(state.hscroll, 5);
+        state.toggle_wrap();
+        assert!(state.wrap);
+        assert_eq!(state.hscroll, 0);
+        state.scroll_right(8);
+        assert_eq!(state.hscroll, 0);
+        state.scroll_left(8);
+        state.toggle_wrap();
+        state.scroll_right(3);
+        state.begin_for_unit(Scope::System, "b.service".to_string());
+        assert_eq!(state.hscroll, 0);
+        assert!(!state.wrap);
+    }
+
+    #[test]
+    fn detail_state_cursor_moves_within_the_page_and_picks_the_entry() {
+        let mut state = DetailState {
+            unit: "a.service".to_string(),
+            logs: (0..6).map(|idx| sample_log(&idx.to_string())).collect(),
+            ..DetailState::default()
+        };
+        let heights = [1, 1, 2, 1, 1, 1];
+        assert_eq!(
+            state.entry_at_cursor().map(|entry| entry.log.as_str()),
+            Some("0")
+        );
+        state.move_cursor(true, &heights, 3);
+        assert_eq!((state.cursor, state.scroll), (1, 0));
+        // The wrapped entry no longer fits under the first two.
+        state.move_cursor(true, &heights, 3);
+        assert_eq!((state.cursor, state.scroll), (2, 1));
+        state.move_cursor(false, &heights, 3);
+        state.move_cursor(false, &heights, 3);
+        assert_eq!((state.cursor, state.scroll), (0, 0));
+        assert_eq!(
+            state.entry_at_cursor().map(|entry| entry.log.as_str()),
+            Some("0")
+        );
+        state.cursor = 5;
+        state.logs.truncate(2);
+        let id = state.refresh().expect("refresh");
+        let logs = state.logs.clone();
+        assert!(state.apply_loaded(id, "a.service", logs));
+        assert_eq!(state.cursor, 1);
+        state.logs.clear();
+        assert!(state.entry_at_cursor().is_none());
+    }
+
+    #[test]
+    fn invocation_sections_group_runs_and_collect_exit_results() {
+        let entry = |invocation: Option<&str>, time: &str, exit: Option<&str>| DetailLogEntry {
+            time: time.to_string(),
+            log: "line".to_string(),
+            invocation: invocation.map(str::to_string),
+            exit: exit.map(str::to_string),
+            ..DetailLogEntry::default()
+        };
+        let logs = vec![
+            entry(None, "t7", None),
+            entry(Some("c"), "t6", None),
+            entry(Some("b"), "t5", Some("result=exit-code")),
+            entry(Some("b"), "t4", Some("code=exited, status=1")),
+            entry(None, "t3", None),
+            entry(Some("b"), "t2", None),
+            entry(Some("a"), "t1", Some("result=success")),
+        ];
+        let sections = invocation_sections(&logs);
+        let summary: Vec<(&str, usize, usize, &str, Option<&str>)> = sections
+            .iter()
+            .map(|run| {
+                (
+                    run.id.as_str(),
+                    run.first,
+                    run.last,
+                    run.start.as_str(),
+                    run.result.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("c", 1, 1, "t6", None),
+                (
+                    "b",
+                    2,
+                    5,
+                    "t2",
+                    Some("code=exited, status=1, result=exit-code")
+                ),
+                ("a", 6, 6, "t1", Some("result=success")),
+            ]
+        );
+        assert_eq!(invocation_at(&sections, 0), None);
+        assert_eq!(invocation_at(&sections, 4), Some(1));
+
+        let mut state = DetailState {
+            logs,
+            ..DetailState::default()
+        };
+        assert!(state.next_invocation());
+        assert_eq!(state.scroll, 1);
+        assert!(state.next_invocation());
+        assert_eq!(state.scroll, 2);
+        state.scroll = 4;
+        assert!(state.previous_invocation());
+        assert_eq!(state.scroll, 2);
+        assert!(state.next_invocation());
+        assert!(!state.next_invocation());
+        assert_eq!(state.scroll, 6);
+        state.scroll = 1;
+        assert!(!state.previous_invocation());
+        assert_eq!(state.scroll, 1);
+    }
+
+    #[test]
+    fn log_search_finds_matches_in_text_regex_and_case_modes() {
+        let logs = vec![
+            sample_log("Started unit"),
+            sample_log("error: disk full, ERROR again"),
+            sample_log("all good"),
+            sample_log("error 42"),
+        ];
+        let mut search = LogSearch::default();
+        search.start("error", &logs, 2).expect("search");
+        assert_eq!(search.matches, vec![(1, 0..5), (3, 0..5)]);
+        assert_eq!(search.current_entry(), Some(3));
+        assert_eq!(search.status_text().as_deref(), Some("match 2/2"));
+        assert_eq!(search.next_match(), Some(1));
+        assert_eq!(search.previous_match(), Some(3));
+
+        search.ignore_case = true;
+        search.start("error", &logs, 0).expect("search");
+        assert_eq!(search.matches.len(), 3);
+        assert_eq!(search.status_text().as_deref(), Some("match 1/3"));
+
+        search.regex = true;
+        search.start(r"\d+", &logs, 0).expect("regex");
+        assert_eq!(search.matches, vec![(3, 6..8)]);
+        let err = search.start("(", &logs, 0).expect_err("invalid");
+        assert!(err.to_string().starts_with("invalid pattern ("));
+        assert_eq!(search.query, r"\d+");
+
+        search.regex = false;
+        search.start("(", &logs, 0).expect("plain text");
+        assert_eq!(search.status_text().as_deref(), Some("no matches for ("));
+        assert_eq!(search.next_match(), None);
+        search.clear();
+        assert_eq!(search.status_text(), None);
+    }
+
+    #[test]
+    fn detail_state_keeps_search_across_reloads_and_reveals_matches() {
+        let mut state = DetailState::default();
+        let id = state.begin_for_unit(Scope::System, "a.service".to_string());
+        let mut logs: Vec<DetailLogEntry> =
+            (0..20).map(|idx| sample_log(&idx.to_string())).collect();
+        logs[15].log = format!("{}needle", " ".repeat(40));
+        assert!(state.apply_loaded(id, "a.service", logs.clone()));
+        state
+            .search
+            .start("needle", &state.logs, 0)
+            .expect("search");
+        state.reveal_search_match(&[1; 20], 5, 20);
+        assert_eq!(state.scroll, 15);
+        assert_eq!(state.hscroll, 35);
+
+        // Wrapped entries above the match can push it below the page.
+        state.scroll = 13;
+        state.reveal_search_match(&[1; 20], 5, 20);
+        assert_eq!(state.scroll, 13);
+        let mut heights = [1; 20];
+        heights[13] = 3;
+        heights[14] = 2;
+        state.reveal_search_match(&heights, 5, 20);
+        assert_eq!(state.scroll, 15);
+
+        logs.insert(0, sample_log("needle first"));
+        let id = state.refresh().expect("refresh");
+        assert!(state.apply_loaded(id, "a.service", logs));
+        assert_eq!(state.search.matches.len(), 2);
+        assert_eq!(state.search.current_entry(), Some(16));
+    }
+
     #[test]
     fn detail_state_ignores_stale_async_responses() {
         let mut state = DetailState::default();
-        let id1 = state.begin_for_unit("a.service".to_string());
-        let id2 = state.begin_for_unit("b.service".to_string());
+        let id1 = state.begin_for_unit(Scope::System, "a.service".to_string());
+        let id2 = state.begin_for_unit(Scope::System, "b.service".to_string());
         assert_ne!(id1, id2);
         assert!(!state.apply_loaded(id1, "a.service", vec![sample_log("old")]));
         assert!(state.apply_loaded(id2, "b.service", vec![sample_log("new")]));
@@ -425,7 +1981,7 @@ mod tests {
     #[test]
     fn detail_state_refresh_keeps_logs_and_updates_loading() {
         let mut state = DetailState::default();
-        let first = state.begin_for_unit("a.service".to_string());
+        let first = state.begin_for_unit(Scope::System, "a.service".to_string());
         assert!(state.apply_loaded(first, "a.service", vec![sample_log("x"), sample_log("y")]));
         state.scroll = 1;
         let refresh_id = state.refresh().expect("refresh id");
@@ -446,7 +2002,7 @@ mod tests {
     #[test]
     fn detail_state_apply_error_sets_error_and_stops_loading() {
         let mut state = DetailState::default();
-        let id = state.begin_for_unit("a.service".to_string());
+        let id = state.begin_for_unit(Scope::System, "a.service".to_string());
         assert!(state.apply_error(id, "a.service", "boom".to_string()));
         assert!(!state.loading);
         assert_eq!(state.error.as_deref(), Some("boom"));
@@ -455,7 +2011,7 @@ mod tests {
     #[test]
     fn detail_state_apply_error_ignores_mismatched_request() {
         let mut state = DetailState::default();
-        let id = state.begin_for_unit("a.service".to_string());
+        let id = state.begin_for_unit(Scope::System, "a.service".to_string());
         assert!(!state.apply_error(id + 1, "a.service", "boom".to_string()));
         assert!(!state.apply_error(id, "b.service", "boom".to_string()));
     }
@@ -463,7 +2019,7 @@ mod tests {
     #[test]
     fn detail_state_apply_loaded_empty_logs_resets_scroll() {
         let mut state = DetailState::default();
-        let id = state.begin_for_unit("a.service".to_string());
+        let id = state.begin_for_unit(Scope::System, "a.service".to_string());
         state.scroll = 10;
         assert!(state.apply_loaded(id, "a.service", Vec::new()));
         assert_eq!(state.scroll, 0);
@@ -473,10 +2029,10 @@ mod tests {
     #[test]
     fn detail_state_switching_unit_clears_old_logs_immediately() {
         let mut state = DetailState::default();
-        let id = state.begin_for_unit("a.service".to_string());
+        let id = state.begin_for_unit(Scope::System, "a.service".to_string());
         assert!(state.apply_loaded(id, "a.service", vec![sample_log("old")]));
         assert_eq!(state.logs.len(), 1);
-        let _ = state.begin_for_unit("b.service".to_string());
+        let _ = state.begin_for_unit(Scope::System, "b.service".to_string());
         assert!(state.logs.is_empty());
         assert_eq!(state.scroll, 0);
         assert!(state.loading);
@@ -489,12 +2045,235 @@ mod tests {
         assert_ne!(SortMode::Name, SortMode::Status);
     }
 
+    #[test]
+    fn sort_mode_cycles_and_maps_to_columns() {
+        assert_eq!(SortMode::Status.next(), SortMode::Name);
+        assert_eq!(SortMode::Restarts.next(), SortMode::Status);
+        for mode in SortMode::CYCLE {
+            assert_eq!(SortMode::from_name(mode.name()), Some(mode));
+            if let Some(column) = mode.column() {
+                assert_eq!(SortMode::for_column(column), Some(mode));
+            }
+        }
+        assert_eq!(SortMode::Status.column(), None);
+        assert_eq!(SortMode::LastLog.column(), Some(Column::Log));
+        assert_eq!(SortMode::for_column(Column::Cpu), None);
+        assert!(SortMode::Memory.needs_properties());
+        assert!(!SortMode::LastLog.needs_properties());
+    }
+
     #[test]
     fn scope_maps_to_expected_systemd_args() {
         assert_eq!(Scope::System.as_systemd_arg(), "--system");
         assert_eq!(Scope::User.as_systemd_arg(), "--user");
     }
 
+    #[test]
+    fn target_adds_machine_transport_args() {
+        assert!(Target::new(Scope::System).transport_args().is_empty());
+        let target = Target {
+            scope: Scope::User,
+            machine: Some("web".to_string()),
+            host: None,
+        };
+        assert_eq!(target.transport_args(), vec!["--machine", "@web"]);
+        assert_eq!(target.machine_args(), vec!["--machine", "web"]);
+        let system = Target {
+            scope: Scope::System,
+            ..target.clone()
+        };
+        assert_eq!(system.transport_args(), vec!["--machine", "web"]);
+        let named_user = Target {
+            machine: Some("ops@web".to_string()),
+            ..target
+        };
+        assert_eq!(named_user.transport_args(), vec!["--machine", "ops@web"]);
+        assert_eq!(named_user.machine_args(), vec!["--machine", "web"]);
+
+        let remote = Target {
+            host: Some("ops@db1".to_string()),
+            ..Target::new(Scope::System)
+        };
+        assert_eq!(remote.transport_args(), vec!["--host", "ops@db1"]);
+        assert!(remote.machine_args().is_empty());
+    }
+
+    #[test]
+    fn split_layout_cycles_and_parses_names() {
+        assert_eq!(SplitLayout::default(), SplitLayout::Off);
+        assert_eq!(SplitLayout::Off.next(), SplitLayout::Below);
+        assert_eq!(SplitLayout::Beside.next(), SplitLayout::Off);
+        assert_eq!(SplitLayout::from_name("beside"), Some(SplitLayout::Beside));
+        assert_eq!(SplitLayout::from_name("left"), None);
+        assert!(SplitLayout::Below.is_split());
+        assert!(!SplitLayout::Off.is_split());
+    }
+
+    #[test]
+    fn log_pane_follow_debounces_selection_changes() {
+        let now = Instant::now();
+        let nginx = (Scope::System, "nginx.service".to_string());
+        let sshd = (Scope::System, "sshd.service".to_string());
+        let mut follow = LogPaneFollow::default();
+
+        follow.observe(Some(nginx.clone()), now);
+        assert_eq!(follow.take_due(now), None);
+        // Moving on before the delay ran out restarts it for the new unit.
+        follow.observe(Some(sshd.clone()), now + LOG_PANE_FOLLOW_DELAY / 2);
+        assert_eq!(follow.take_due(now + LOG_PANE_FOLLOW_DELAY), None);
+        let settled = now + LOG_PANE_FOLLOW_DELAY * 2;
+        follow.observe(Some(sshd.clone()), settled);
+        assert_eq!(follow.take_due(settled), Some(sshd.clone()));
+        assert_eq!(follow.take_due(settled), None);
+
+        follow.reload_now(settled);
+        assert_eq!(follow.take_due(settled), Some(sshd.clone()));
+
+        follow.reset();
+        follow.reload_now(settled);
+        assert_eq!(follow.take_due(settled), None);
+        follow.observe(None, settled);
+        assert_eq!(follow.take_due(settled + LOG_PANE_FOLLOW_DELAY), None);
+    }
+
+    #[test]
+    fn machine_picker_preselects_current_machine_and_clamps_moves() {
+        let mut picker = MachinePickerState::loading(Some("db".to_string()));
+        assert!(picker.loading);
+        assert_eq!(picker.selected_machine(), None);
+
+        picker.apply_loaded(vec!["web".to_string(), "db".to_string()]);
+        assert!(!picker.loading);
+        assert_eq!(picker.selected_machine(), Some(Some("db".to_string())));
+        picker.move_down();
+        assert_eq!(picker.selected, 2);
+        picker.move_up();
+        picker.move_up();
+        picker.move_up();
+        assert_eq!(picker.selected_machine(), Some(None));
+
+        picker.apply_error("boom".to_string());
+        assert_eq!(picker.error.as_deref(), Some("boom"));
+    }
+
+    #[test]
+    fn boot_boundary_marks_entries_whose_boot_differs_from_the_one_above() {
+        let logs: Vec<DetailLogEntry> = [Some("new"), Some("new"), None, Some("old"), Some("old")]
+            .into_iter()
+            .map(|boot| DetailLogEntry {
+                boot: boot.map(str::to_string),
+                ..DetailLogEntry::default()
+            })
+            .collect();
+        let boundaries: Vec<Option<&str>> = (0..=logs.len())
+            .map(|idx| boot_boundary(&logs, idx))
+            .collect();
+        assert_eq!(boundaries, vec![None, None, None, None, None, None]);
+
+        let logs: Vec<DetailLogEntry> = ["new", "old", "old"]
+            .into_iter()
+            .map(|boot| DetailLogEntry {
+                boot: Some(boot.to_string()),
+                ..DetailLogEntry::default()
+            })
+            .collect();
+        assert_eq!(boot_boundary(&logs, 1), Some("old"));
+        assert_eq!(boot_boundary(&logs, 2), None);
+    }
+
+    #[test]
+    fn boot_picker_lists_newest_first_and_preselects_current_boot() {
+        let boot = |index: i64, id: &str| Boot {
+            index,
+            id: id.to_string(),
+            span: String::new(),
+        };
+        let mut picker = BootPickerState::loading(Some("old".to_string()));
+        assert_eq!(picker.selected_boot(), None);
+
+        picker.apply_loaded(vec![boot(-1, "old"), boot(0, "now")]);
+        assert!(!picker.loading);
+        let ids: Vec<Option<&str>> = picker
+            .entries
+            .iter()
+            .map(|entry| entry.as_ref().map(|boot| boot.id.as_str()))
+            .collect();
+        assert_eq!(ids, vec![None, Some("now"), Some("old")]);
+        assert_eq!(picker.selected_boot(), Some(Some("old".to_string())));
+        picker.move_down();
+        assert_eq!(picker.selected, 2);
+        picker.move_up();
+        picker.move_up();
+        assert_eq!(picker.selected_boot(), Some(None));
+    }
+
+    #[test]
+    fn journal_source_maps_to_expected_journalctl_args() {
+        assert!(JournalSource::Local.as_journalctl_args().is_empty());
+        assert!(!JournalSource::Local.is_offline());
+
+        let dir = JournalSource::Directory(PathBuf::from("/tmp/journal"));
+        assert_eq!(
+            dir.as_journalctl_args(),
+            vec![OsString::from("-D"), OsString::from("/tmp/journal")]
+        );
+        assert!(dir.is_offline());
+
+        let files =
+            JournalSource::Files(vec![PathBuf::from("a.journal"), PathBuf::from("b.journal")]);
+        assert_eq!(
+            files.as_journalctl_args(),
+            vec![
+                OsString::from("--file"),
+                OsString::from("a.journal"),
+                OsString::from("--file"),
+                OsString::from("b.journal"),
+            ]
+        );
+        assert!(files.is_offline());
+
+        assert_eq!(
+            JournalSource::Root(PathBuf::from("/mnt")).as_journalctl_args(),
+            vec![OsString::from("--root"), OsString::from("/mnt")]
+        );
+        assert_eq!(
+            JournalSource::Image(PathBuf::from("os.raw")).as_journalctl_args(),
+            vec![OsString::from("--image"), OsString::from("os.raw")]
+        );
+    }
+
+    #[test]
+    fn unit_file_root_maps_to_systemctl_args_and_journal() {
+        let live = UnitFileRoot::Live;
+        assert!(live.as_systemctl_args().is_empty());
+        assert!(!live.is_offline());
+        assert_eq!(live.scope_arg(Scope::User), "--user");
+        assert_eq!(live.journal_source(), None);
+
+        let dir = UnitFileRoot::Directory(PathBuf::from("/mnt"));
+        assert_eq!(
+            dir.as_systemctl_args(),
+            vec![OsString::from("--root"), OsString::from("/mnt")]
+        );
+        assert!(dir.is_offline());
+        assert_eq!(dir.scope_arg(Scope::System), "--system");
+        assert_eq!(dir.scope_arg(Scope::User), "--global");
+        assert_eq!(
+            dir.journal_source(),
+            Some(JournalSource::Root(PathBuf::from("/mnt")))
+        );
+
+        let image = UnitFileRoot::Image(PathBuf::from("os.raw"));
+        assert_eq!(
+            image.as_systemctl_args(),
+            vec![OsString::from("--image"), OsString::from("os.raw")]
+        );
+        assert_eq!(
+            image.journal_source(),
+            Some(JournalSource::Image(PathBuf::from("os.raw")))
+        );
+    }
+
     #[test]
     fn all_view_and_load_phase_variants_are_constructible() {
         let list_mode = ViewMode::List;
@@ -516,6 +2295,8 @@ mod tests {
         assert_eq!(UnitAction::Stop.prompt_verb(), "stopping");
         assert_eq!(UnitAction::DisableRuntime.as_systemctl_arg(), "disable");
         assert_eq!(UnitAction::DisableRuntime.prompt_verb(), "disabling");
+        assert_eq!(UnitAction::Preset.as_systemctl_arg(), "preset");
+        assert!(!UnitAction::Preset.uses_runtime_flag());
         assert!(UnitAction::DisableRuntime.uses_runtime_flag());
         assert!(!UnitAction::Disable.uses_runtime_flag());
     }
@@ -546,5 +2327,11 @@ mod tests {
             unit: "other.service".to_string(),
         };
         assert_eq!(enable_disable.unit(), "other.service");
+
+        let named = ActionResolutionRequest::Named {
+            action: UnitAction::Restart,
+            unit: "named.service".to_string(),
+        };
+        assert_eq!(named.unit(), "named.service");
     }
 }